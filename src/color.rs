@@ -0,0 +1,187 @@
+//! Colored output is welcome in terminal, but turns into garbage of escape sequences when piped to files.
+//! This module contains [ColorChoice] deciding whether the colors should be outputted at all: either forced
+//! by crate-level override, or detected from the `NO_COLOR` environment variable and whether the standard
+//! output is terminal (requires `std` feature, colors are always outputted without it).
+//!
+//! Usage example:
+//! ```
+//! use cubob::ColorChoice;
+//!
+//! // Typically done once on application start, e.g. according to `--color` command-line option.
+//! ColorChoice::set_global(ColorChoice::Never);
+//! assert!(!ColorChoice::enabled());
+//! ```
+//!
+//! Output of the builders is styled by [Theme] given to them: either one of [AnsiTheme] presets,
+//! or any custom type implementing the trait.
+
+use core::{
+    fmt::{Result as FmtResult, Write},
+    format_args,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+static CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+#[cfg(feature = "std")]
+static DETECTED: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+#[cfg(feature = "std")]
+const UNKNOWN: u8 = 0;
+#[cfg(feature = "std")]
+const PLAIN: u8 = 1;
+#[cfg(feature = "std")]
+const COLORED: u8 = 2;
+
+/// Crate-level choice of whether the colored output is allowed.
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Colors are outputted unless `NO_COLOR` environment variable is set to non-empty value
+    /// or the standard output is not terminal.
+    #[default]
+    Auto,
+    /// Colors are always outputted.
+    Always,
+    /// Colors are never outputted.
+    Never,
+}
+
+impl ColorChoice {
+    /// Makes the specified choice used by every colored output afterwards.
+    pub fn set_global(choice: Self) {
+        CHOICE.store(choice as u8, Ordering::Relaxed);
+    }
+
+    /// Returns the choice used by every colored output.
+    pub fn global() -> Self {
+        match CHOICE.load(Ordering::Relaxed) {
+            x if x == Self::Always as u8 => Self::Always,
+            x if x == Self::Never as u8 => Self::Never,
+            _ => Self::Auto,
+        }
+    }
+
+    /// Returns whether the colors should be outputted according to the global choice.
+    /// The environment is checked only once, the result is reused afterwards.
+    pub fn enabled() -> bool {
+        match Self::global() {
+            Self::Auto => detected(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn detected() -> bool {
+    use std::io::IsTerminal;
+
+    match DETECTED.load(Ordering::Relaxed) {
+        UNKNOWN => {
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            let colored = !no_color && std::io::stdout().is_terminal();
+            DETECTED.store(
+                match colored {
+                    true => COLORED,
+                    false => PLAIN,
+                },
+                Ordering::Relaxed,
+            );
+            colored
+        }
+        detected => detected == COLORED,
+    }
+}
+
+#[cfg(not(feature = "std"))]
+fn detected() -> bool {
+    true
+}
+
+/// Element of the builders output which can be styled by [Theme].
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Element {
+    /// Keys of the struct entries.
+    Key,
+    /// Values of the struct entries, list items and tree nodes labels.
+    Value,
+    /// Brackets, commas and tree branches.
+    Bracket,
+}
+
+/// Styling of the builders output elements, applied uniformly by [StructShow][crate::StructShow],
+/// [ListShow][crate::ListShow] and [TreeShow][crate::TreeShow] (tables are colored by their own colors set).
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub trait Theme {
+    /// Returns parameters of SGR escape sequence styling the specified element (like `1;34` for bold blue),
+    /// if it should be styled.
+    fn style(&self, element: Element) -> Option<&'static str>;
+
+    /// Outputs whatever the given function outputs, styled as the specified element.
+    /// Surrounds the output with escape sequences setting and resetting the style returned by [Theme::style] by default.
+    fn paint(
+        &self,
+        w: &mut dyn Write,
+        element: Element,
+        write: &mut dyn FnMut(&mut dyn Write) -> FmtResult,
+    ) -> FmtResult {
+        match self.style(element) {
+            Some(style) => {
+                w.write_fmt(format_args!("\x1b[{}m", style))?;
+                write(w)?;
+                w.write_str("\x1b[0m")
+            }
+            None => write(w),
+        }
+    }
+}
+
+/// [Theme] made of SGR escape sequence parameters for every element.
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnsiTheme {
+    /// Style of the keys.
+    pub key: Option<&'static str>,
+    /// Style of the values.
+    pub value: Option<&'static str>,
+    /// Style of the brackets, commas and tree branches.
+    pub bracket: Option<&'static str>,
+}
+
+impl AnsiTheme {
+    /// Bold keys and dimmed brackets, no colors at all.
+    pub const MONOCHROME: Self = Self {
+        key: Some("1"),
+        value: None,
+        bracket: Some("2"),
+    };
+    /// Bright colors for terminals with dark background.
+    pub const DARK: Self = Self {
+        key: Some("1;94"),
+        value: Some("37"),
+        bracket: Some("90"),
+    };
+    /// Deep colors for terminals with light background.
+    pub const LIGHT: Self = Self {
+        key: Some("1;34"),
+        value: Some("30"),
+        bracket: Some("90"),
+    };
+    /// Bold bright colors distinguishable at any background.
+    pub const HIGH_CONTRAST: Self = Self {
+        key: Some("1;93"),
+        value: Some("1;97"),
+        bracket: Some("1;96"),
+    };
+}
+
+impl Theme for AnsiTheme {
+    fn style(&self, element: Element) -> Option<&'static str> {
+        match element {
+            Element::Key => self.key,
+            Element::Value => self.value,
+            Element::Bracket => self.bracket,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::{relative::write_relative, Params};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// [Params] implementation outputting `chrono` date and time values either with strftime-like pattern,
+/// or relatively to some moment, like `5 minutes ago`.
+/// Pattern containing invalid specifiers (or offset specifiers used for [NaiveDateTime]) makes the output fail.
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChronoFormat {
+    /// Outputs value with the specified pattern, see [chrono::format::strftime] for the specifiers.
+    Pattern(&'static str),
+    /// Outputs value relatively to the specified moment, like `5 minutes ago` or `in 2 hours`.
+    RelativeTo(DateTime<Utc>),
+}
+
+impl ChronoFormat {
+    /// RFC 3339 format with as many fractional digits as needed, like `2024-05-01T12:30:00.25+02:00`.
+    pub const RFC3339: Self = Self::Pattern("%Y-%m-%dT%H:%M:%S%.f%:z");
+    /// RFC 3339 format with whole seconds, like `2024-05-01T12:30:00+02:00`.
+    pub const RFC3339_SECONDS: Self = Self::Pattern("%Y-%m-%dT%H:%M:%S%:z");
+    /// RFC 3339 format with milliseconds, like `2024-05-01T12:30:00.250+02:00`.
+    pub const RFC3339_MILLIS: Self = Self::Pattern("%Y-%m-%dT%H:%M:%S%.3f%:z");
+    /// Date and time without offset, suitable for [NaiveDateTime], like `2024-05-01 12:30:00`.
+    pub const NAIVE: Self = Self::Pattern("%Y-%m-%d %H:%M:%S");
+
+    /// Creates one [ChronoFormat] examplar outputting values relatively to the moment of its creation.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "chrono", feature = "std"))))]
+    pub fn relative() -> Self {
+        Self::RelativeTo(Utc::now())
+    }
+}
+
+impl Default for ChronoFormat {
+    fn default() -> Self {
+        Self::RFC3339
+    }
+}
+
+impl<Tz: TimeZone> Params<DateTime<Tz>> for ChronoFormat
+where
+    Tz::Offset: Display,
+{
+    fn fmt(&self, val: &DateTime<Tz>, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Pattern(pattern) => f.write_fmt(format_args!("{}", val.format(pattern))),
+            Self::RelativeTo(moment) => {
+                write_relative(f, moment.signed_duration_since(val).num_seconds())
+            }
+        }
+    }
+}
+
+impl Params<NaiveDateTime> for ChronoFormat {
+    fn fmt(&self, val: &NaiveDateTime, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Pattern(pattern) => f.write_fmt(format_args!("{}", val.format(pattern))),
+            Self::RelativeTo(moment) => write_relative(
+                f,
+                moment.naive_utc().signed_duration_since(*val).num_seconds(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use super::{number::write_grouped, Params};
+use core::{
+    fmt::{Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// [Params] implementation outputting integer amounts of minor currency units (like cents) as money, like `$1,234.56`.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Currency {
+    /// Currency symbol to put before the amount.
+    pub symbol: &'static str,
+    /// Amount of minor units digits, i.e. 2 for cents.
+    pub decimals: u32,
+    /// Separator used to group thousands of major units, if needed.
+    pub thousands: Option<char>,
+}
+
+impl Currency {
+    /// US dollars with cents and comma-separated thousands, like `$1,234.56`.
+    pub const USD: Self = Self {
+        symbol: "$",
+        decimals: 2,
+        thousands: Some(','),
+    };
+
+    /// Creates one [Currency] examplar with the specified symbol and amount of minor units digits.
+    pub fn new(symbol: &'static str, decimals: u32) -> Self {
+        Self {
+            symbol,
+            decimals,
+            thousands: None,
+        }
+    }
+
+    /// Makes current [Currency] examplar group thousands using the specified separator.
+    pub fn thousands(self, separator: char) -> Self {
+        Self {
+            thousands: Some(separator),
+            ..self
+        }
+    }
+}
+
+impl<T: Copy + Into<i128>> Params<T> for Currency {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        let value: i128 = (*val).into();
+        if value < 0 {
+            f.write_char('-')?;
+        }
+        f.write_str(self.symbol)?;
+        let amount = value.unsigned_abs();
+        // Any amount is less than the scale which does not fit u128, so it is all minor units then.
+        let (major, minor) = match 10u128.checked_pow(self.decimals) {
+            Some(scale) => (amount / scale, amount % scale),
+            None => (0, amount),
+        };
+        write_grouped(f, major, self.thousands)?;
+        match self.decimals {
+            0 => Ok(()),
+            width => f.write_fmt(format_args!(".{:01$}", minor, width as usize)),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use super::{Params, PathLike};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// One segment of [KeyPath].
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Named field, outputted as is.
+    Key(&'a str),
+    /// Numeric index, outputted in square brackets right after the previous segment.
+    Index(usize),
+}
+
+impl<'a> Display for Segment<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Key(key) => f.write_str(key),
+            Self::Index(index) => f.write_fmt(format_args!("[{}]", index)),
+        }
+    }
+}
+
+/// Lets to reference nested fields in error messages and diffs, like `config.servers[2].host`.
+/// Every [KeyPath] examplar borrows its parent instead of storing all the segments, so paths can be accumulated
+/// on stack while descending into the nested data. Displayed in [PathLike::DOTTED] style by default,
+/// other styles can be applied with [Custom][crate::Custom]:
+/// ```
+/// use cubob::{Custom, KeyPath, PathLike};
+///
+/// let config = KeyPath::new("config");
+/// let servers = config.key("servers");
+/// assert_eq!("config.servers[2].host", &format!("{}", servers.index(2).key("host")));
+/// assert_eq!("config::servers[2]", &format!("{}", Custom::new(&servers.index(2), PathLike::RUST)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug)]
+pub struct KeyPath<'a> {
+    parent: Option<&'a KeyPath<'a>>,
+    segment: Segment<'a>,
+}
+
+impl<'a> KeyPath<'a> {
+    /// Creates one [KeyPath] examplar consisting of the single named segment.
+    pub fn new(key: &'a str) -> Self {
+        Self {
+            parent: None,
+            segment: Segment::Key(key),
+        }
+    }
+
+    /// Creates one [KeyPath] examplar consisting of current path and the named segment.
+    pub fn key<'b>(&'b self, key: &'b str) -> KeyPath<'b> {
+        KeyPath {
+            parent: Some(self),
+            segment: Segment::Key(key),
+        }
+    }
+
+    /// Creates one [KeyPath] examplar consisting of current path and the numeric index segment.
+    pub fn index(&self, index: usize) -> KeyPath<'_> {
+        KeyPath {
+            parent: Some(self),
+            segment: Segment::Index(index),
+        }
+    }
+
+    /// Returns the last segment of current path.
+    pub fn segment(&self) -> Segment<'a> {
+        self.segment
+    }
+
+    /// Returns the number of segments in current path.
+    pub fn depth(&self) -> usize {
+        self.parent.map_or(0, |parent| parent.depth()) + 1
+    }
+
+    // Returns segment at the specified position counting from the path start.
+    fn segment_at(&self, position: usize) -> Segment<'a> {
+        let mut path = self;
+        for _ in position + 1..self.depth() {
+            path = path.parent.expect("position is less than depth");
+        }
+        path.segment
+    }
+}
+
+impl<'a> Display for KeyPath<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        PathLike::DOTTED.fmt(self, f)
+    }
+}
+
+/// Index segments are outputted together with the preceding named segment, so they never get delimited.
+impl<'a> Params<KeyPath<'a>> for PathLike {
+    fn fmt(&self, val: &KeyPath<'a>, f: &mut Formatter<'_>) -> FmtResult {
+        let components = Components {
+            path: val,
+            position: 0,
+            depth: val.depth(),
+        };
+        self.write_components(components, false, f)
+    }
+}
+
+// Lets to iterate over named segments of the path along with their trailing index segments.
+#[derive(Clone)]
+struct Components<'a, 'b> {
+    path: &'b KeyPath<'a>,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a, 'b> Iterator for Components<'a, 'b> {
+    type Item = Component<'a, 'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.depth {
+            return None;
+        }
+        let start = self.position;
+        self.position += 1;
+        while self.position < self.depth {
+            match self.path.segment_at(self.position) {
+                Segment::Index(_) => self.position += 1,
+                Segment::Key(_) => break,
+            }
+        }
+        Some(Component {
+            path: self.path,
+            start,
+            end: self.position,
+        })
+    }
+}
+
+// Named segment of the path along with its trailing index segments.
+struct Component<'a, 'b> {
+    path: &'b KeyPath<'a>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, 'b> Display for Component<'a, 'b> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for position in self.start..self.end {
+            Display::fmt(&self.path.segment_at(position), f)?;
+        }
+        Ok(())
+    }
+}
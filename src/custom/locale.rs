@@ -0,0 +1,90 @@
+use super::{
+    number::{IntegerDigitsCounter, LocalizingWriter},
+    Params,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Small descriptor of the locale-dependent number formatting rules.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// Character separating integer and fractional parts of number.
+    pub decimal: char,
+    /// Character separating groups of three digits in integer part of number, if needed.
+    pub grouping: Option<char>,
+}
+
+impl Locale {
+    /// English-like rules, like `1,234.56`.
+    pub const EN: Self = Self {
+        decimal: '.',
+        grouping: Some(','),
+    };
+    /// German-like rules (used in many other European locales too), like `1.234,56`.
+    pub const DE: Self = Self {
+        decimal: ',',
+        grouping: Some('.'),
+    };
+    /// French-like rules, like `1 234,56` (with narrow no-break space).
+    pub const FR: Self = Self {
+        decimal: ',',
+        grouping: Some('\u{202F}'),
+    };
+    /// Swiss-like rules, like `1'234.56`.
+    pub const CH: Self = Self {
+        decimal: '.',
+        grouping: Some('\''),
+    };
+}
+
+/// [Params] implementation outputting numbers accordingly to the specified [Locale].
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Localized {
+    /// Rules of number formatting.
+    pub locale: Locale,
+    /// Amount of digits after the decimal point; as many as needed if not specified.
+    pub precision: Option<usize>,
+}
+
+impl Localized {
+    /// Creates one [Localized] examplar with the specified locale and as many fractional digits as needed.
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            precision: None,
+        }
+    }
+
+    /// Makes current [Localized] examplar output the specified amount of fractional digits.
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+impl<T: Display> Params<T> for Localized {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        let mut counter = IntegerDigitsCounter::default();
+        match self.precision {
+            Some(precision) => counter.write_fmt(format_args!("{:.*}", precision, val))?,
+            None => counter.write_fmt(format_args!("{}", val))?,
+        }
+        let mut writer = LocalizingWriter {
+            inner: f,
+            decimal: self.locale.decimal,
+            grouping: self.locale.grouping,
+            digits_left: counter.digits,
+            fraction: false,
+        };
+        match self.precision {
+            Some(precision) => writer.write_fmt(format_args!("{:.*}", precision, val)),
+            None => writer.write_fmt(format_args!("{}", val)),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::Params;
+use crate::measure::char_width;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// The way masked characters are replaced in [Masked] output.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mask {
+    /// Every masked character is replaced with the specified one.
+    Char(char),
+    /// The whole masked part is replaced with the specified token.
+    Token(&'static str),
+}
+
+/// [Params] implementation hiding all but the first and/or last few characters of the value output,
+/// so passwords, tokens, emails and so on can be outputted safely.
+/// When the output is too short to keep specified characters, it is masked entirely.
+/// Characters are counted as they are, regardless of the terminal cells they take, but every masked one
+/// is replaced with as many mask characters as cells it takes, so the masked output stays as wide.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Masked {
+    /// Amount of leading characters left as is.
+    pub keep_start: usize,
+    /// Amount of trailing characters left as is.
+    pub keep_end: usize,
+    /// Replacement for the masked characters.
+    pub mask: Mask,
+}
+
+impl Masked {
+    /// Masks every character with `*`.
+    pub const ALL: Self = Self {
+        keep_start: 0,
+        keep_end: 0,
+        mask: Mask::Char('*'),
+    };
+
+    /// Creates one [Masked] examplar keeping the specified amount of leading and trailing characters and using `*` as mask.
+    pub fn new(keep_start: usize, keep_end: usize) -> Self {
+        Self {
+            keep_start,
+            keep_end,
+            mask: Mask::Char('*'),
+        }
+    }
+
+    /// Makes current [Masked] examplar use the specified mask.
+    pub fn mask(self, mask: Mask) -> Self {
+        Self { mask, ..self }
+    }
+}
+
+impl Default for Masked {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl<T: Display> Params<T> for Masked {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let mut counter = CharCounter::default();
+        // CharCounter never fails by itself, so any error is caused by the value Display implementation,
+        // which the output below fails with as well.
+        let _ = match alternate {
+            true => counter.write_fmt(format_args!("{:#}", val)),
+            false => counter.write_fmt(format_args!("{}", val)),
+        };
+        let total = counter.0;
+        let (keep_start, keep_end) = match self.keep_start + self.keep_end < total {
+            true => (self.keep_start, self.keep_end),
+            false => (0, 0),
+        };
+        let mut writer = MaskingWriter {
+            inner: f,
+            mask: self.mask,
+            index: 0,
+            mask_start: keep_start,
+            mask_end: total - keep_end,
+        };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", val)),
+            false => writer.write_fmt(format_args!("{}", val)),
+        }
+    }
+}
+
+// Counts characters of the output written into it, discarding the output itself.
+#[derive(Default)]
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+struct MaskingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    mask: Mask,
+    index: usize,
+    mask_start: usize,
+    mask_end: usize,
+}
+
+impl<'a, 'b> Write for MaskingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match (self.mask_start..self.mask_end).contains(&self.index) {
+                false => self.inner.write_char(c)?,
+                true => match self.mask {
+                    Mask::Char(mask) => {
+                        for _ in 0..char_width(c) {
+                            self.inner.write_char(mask)?;
+                        }
+                    }
+                    Mask::Token(token) if self.index == self.mask_start => {
+                        self.inner.write_str(token)?
+                    }
+                    Mask::Token(_) => (),
+                },
+            }
+            self.index += 1;
+        }
+        Ok(())
+    }
+}
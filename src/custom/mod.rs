@@ -0,0 +1,68 @@
+//! Sometimes the [Display][core::fmt::Display] implementation of some type doesn't suit the current output needs:
+//! float should be outputted in scientific notation, integer should be treated as amount of money and so on.
+//! This module contains [Params] trait letting to define such custom output rules and [Custom] struct
+//! which applies them to the specified value, along with some ready-to-use [Params] implementations.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{Custom, Scientific, StructShow};
+//!
+//! struct Measurement {
+//!     distance: f64,
+//!     mass: f64,
+//! }
+//!
+//! impl Display for Measurement {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         let mut show = StructShow::inherit(f);
+//!         show
+//!             .field(&"distance", &Custom::new(&self.distance, Scientific::new(2)))
+//!             // any suitable closure can be used as params too
+//!             .field(&"mass", &Custom::new(&self.mass, |v: &f64, f: &mut Formatter<'_>| {
+//!                 f.write_fmt(format_args!("{} kg", v))
+//!             }));
+//!         show.finish()
+//!     }
+//! }
+//!
+//! let measurement = Measurement { distance: 1234567.0, mass: 0.5 };
+//! assert_eq!("{distance: 1.23e+06, mass: 0.5 kg}", &format!("{}", measurement));
+//! ```
+
+#[cfg(feature = "chrono")]
+mod chrono;
+mod currency;
+mod key_path;
+mod locale;
+mod masked;
+mod number;
+mod or_else;
+mod params;
+mod path;
+mod percent;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod relative;
+mod result;
+mod scientific;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "chrono")]
+pub use self::chrono::*;
+#[cfg(feature = "time")]
+pub use self::time::*;
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
+pub use currency::*;
+pub use key_path::*;
+pub use locale::*;
+pub use masked::*;
+pub use or_else::*;
+pub use params::*;
+pub use path::*;
+pub use percent::*;
+pub use result::*;
+pub use scientific::*;
@@ -0,0 +1,80 @@
+use core::{
+    fmt::{Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+// Outputs given unsigned value with its digits grouped by three using the specified separator (if any).
+pub(crate) fn write_grouped(
+    f: &mut Formatter<'_>,
+    value: u128,
+    separator: Option<char>,
+) -> FmtResult {
+    let separator = match separator {
+        Some(separator) => separator,
+        None => return f.write_fmt(format_args!("{}", value)),
+    };
+    let mut divisor = 1u128;
+    while value / divisor >= 1000 {
+        divisor *= 1000;
+    }
+    f.write_fmt(format_args!("{}", value / divisor))?;
+    while divisor > 1 {
+        divisor /= 1000;
+        f.write_char(separator)?;
+        f.write_fmt(format_args!("{:03}", value / divisor % 1000))?;
+    }
+    Ok(())
+}
+
+// Counts digits of the integer part of some outputted number.
+#[derive(Default)]
+pub(crate) struct IntegerDigitsCounter {
+    pub(crate) digits: usize,
+    fraction: bool,
+}
+
+impl Write for IntegerDigitsCounter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '.' => self.fraction = true,
+                '0'..='9' if !self.fraction => self.digits += 1,
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+// Passes some outputted number through replacing its decimal point and grouping digits of its integer part.
+pub(crate) struct LocalizingWriter<'a, 'b> {
+    pub(crate) inner: &'a mut Formatter<'b>,
+    pub(crate) decimal: char,
+    pub(crate) grouping: Option<char>,
+    pub(crate) digits_left: usize,
+    pub(crate) fraction: bool,
+}
+
+impl<'a, 'b> Write for LocalizingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '.' if !self.fraction => {
+                    self.fraction = true;
+                    self.inner.write_char(self.decimal)?;
+                }
+                '0'..='9' if !self.fraction => {
+                    self.inner.write_char(c)?;
+                    self.digits_left = self.digits_left.saturating_sub(1);
+                    if let Some(grouping) = self.grouping {
+                        if self.digits_left > 0 && self.digits_left % 3 == 0 {
+                            self.inner.write_char(grouping)?;
+                        }
+                    }
+                }
+                _ => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use super::Params;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// [Params] implementation outputting optional values: actual value if it matches Some(_), or the specified token otherwise.
+/// Complements `field_opt` method of [StructShow][crate::StructShow] which skips None values entirely.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrElse<D>(pub D);
+
+impl<T: Display, D: Display> Params<Option<T>> for OrElse<D> {
+    fn fmt(&self, val: &Option<T>, f: &mut Formatter<'_>) -> FmtResult {
+        match (val, f.alternate()) {
+            (Some(actual_value), true) => f.write_fmt(format_args!("{:#}", actual_value)),
+            (Some(actual_value), false) => f.write_fmt(format_args!("{}", actual_value)),
+            (None, true) => f.write_fmt(format_args!("{:#}", self.0)),
+            (None, false) => f.write_fmt(format_args!("{}", self.0)),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Trait defining some custom way to output values of the specified type.
+/// Trait is not sealed, so any user can define own [Params] implementation.
+/// It is also implemented for every suitable closure, so ad-hoc output rules need no separate type.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+pub trait Params<T: ?Sized> {
+    /// Outputs given value into the specified formatter accordingly to the params.
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<T: ?Sized, F: Fn(&T, &mut Formatter<'_>) -> FmtResult> Params<T> for F {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        self(val, f)
+    }
+}
+
+/// Lets to output some value using the specified [Params] instead of its own [Display] implementation (if any).
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+pub struct Custom<'a, T: ?Sized, P> {
+    val: &'a T,
+    params: P,
+}
+
+impl<'a, T: ?Sized, P: Params<T>> Custom<'a, T, P> {
+    /// Creates one [Custom] examplar ready to be outputted.
+    pub fn new(val: &'a T, params: P) -> Self {
+        Self { val, params }
+    }
+}
+
+impl<'a, T: ?Sized, P: Params<T>> Display for Custom<'a, T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.params.fmt(self.val, f)
+    }
+}
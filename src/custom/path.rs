@@ -0,0 +1,296 @@
+use super::Params;
+use crate::measure::measure;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Way to escape [PathLike] components so they don't get mixed with the delimiter.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Escape {
+    /// Put the specified character before every component character which is present in the delimiter (and before itself).
+    Char(char),
+    /// Percent-encode every component character except the unreserved ones (letters, digits, `-`, `.`, `_` and `~`), like in URLs.
+    Percent,
+}
+
+/// [Params] implementation outputting sequences of displayable items (slices, arrays and, with `std` feature, vectors)
+/// or filesystem paths as path, like `a/b/c`, `/usr/lib/` or `crate::module::Type`.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PathLike {
+    /// Text to put before the whole path, like `\\` of UNC paths.
+    pub prefix: &'static str,
+    /// Delimiter to put between path components.
+    pub delimiter: &'static str,
+    /// Whether delimiter should be put before the first component too (like in absolute paths).
+    pub prepend: bool,
+    /// Whether delimiter should be put after the last component too (like in directory paths).
+    pub append: bool,
+    /// Way to escape components, so the output stays unambiguous. No escaping is done if None.
+    pub escape: Option<Escape>,
+    /// Numbers of the first and the last components to output when the path is longer than their sum,
+    /// with the rest replaced by single `…` component. Path is outputted entirely if None.
+    pub max_components: Option<(usize, usize)>,
+    /// Whether components with empty output should be skipped along with their delimiters, so no `a//b` appears.
+    pub skip_empty: bool,
+}
+
+impl PathLike {
+    /// Relative filesystem path, like `a/b/c`.
+    pub const FS: Self = Self::new("/");
+    /// Absolute filesystem path, like `/a/b/c`.
+    pub const FS_ABSOLUTE: Self = Self::new("/").prepended();
+    /// Rust item path, like `crate::module::Type`.
+    pub const RUST: Self = Self::new("::");
+    /// Dotted path, like `config.server.host`.
+    pub const DOTTED: Self = Self::new(".");
+    /// Windows filesystem path, like `C:\Users\user` when the drive is given as the first component.
+    pub const FS_WINDOWS: Self = Self::new("\\");
+    /// Windows UNC path, like `\\server\share\file`.
+    pub const FS_UNC: Self = Self::new("\\").with_prefix("\\\\");
+    /// URL path with percent-encoded components, like `a%20b/c`. See [PathLike::url] to get scheme and authority prepended.
+    pub const URL: Self = Self::new("/").percent_encoded();
+
+    /// Creates one [PathLike] examplar outputting URL with the specified scheme and authority,
+    /// like `https://example.com/a%20b/c` for base `https://example.com`.
+    pub const fn url(base: &'static str) -> Self {
+        Self::URL.with_prefix(base).prepended()
+    }
+
+    /// Creates one [PathLike] examplar with the specified delimiter put only between components.
+    pub const fn new(delimiter: &'static str) -> Self {
+        Self {
+            prefix: "",
+            delimiter,
+            prepend: false,
+            append: false,
+            escape: None,
+            max_components: None,
+            skip_empty: false,
+        }
+    }
+
+    /// Makes current [PathLike] examplar put the specified text before the whole path.
+    pub const fn with_prefix(self, prefix: &'static str) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Makes current [PathLike] examplar put delimiter before the first component.
+    pub const fn prepended(self) -> Self {
+        Self {
+            prepend: true,
+            ..self
+        }
+    }
+
+    /// Makes current [PathLike] examplar put delimiter after the last component.
+    pub const fn appended(self) -> Self {
+        Self {
+            append: true,
+            ..self
+        }
+    }
+
+    /// Makes current [PathLike] examplar escape delimiter characters inside components with the specified character,
+    /// like `a/b\/c/d` for components `a`, `b/c` and `d` escaped with backslash.
+    pub const fn escaped(self, escape: char) -> Self {
+        Self {
+            escape: Some(Escape::Char(escape)),
+            ..self
+        }
+    }
+
+    /// Makes current [PathLike] examplar output only `front` first and `back` last components of long paths,
+    /// like `a/b/…/y/z` for `max_components(2, 2)`.
+    pub const fn max_components(self, front: usize, back: usize) -> Self {
+        Self {
+            max_components: Some((front, back)),
+            ..self
+        }
+    }
+
+    /// Makes current [PathLike] examplar skip components with empty output, like `a/b` for components `a`, `""` and `b`.
+    pub const fn skip_empty(self) -> Self {
+        Self {
+            skip_empty: true,
+            ..self
+        }
+    }
+
+    /// Makes current [PathLike] examplar percent-encode components, like in URLs.
+    pub const fn percent_encoded(self) -> Self {
+        Self {
+            escape: Some(Escape::Percent),
+            ..self
+        }
+    }
+}
+
+impl PathLike {
+    // Outputs the given components, with leading delimiter also put if the path is rooted.
+    pub(super) fn write_components<I>(
+        &self,
+        components: I,
+        rooted: bool,
+        f: &mut Formatter<'_>,
+    ) -> FmtResult
+    where
+        I: Iterator + Clone,
+        I::Item: Display,
+    {
+        let alternate = f.alternate();
+        match self.skip_empty {
+            true => self.write_all_components(
+                components.filter(|component| measure(component, alternate) > 0),
+                rooted,
+                f,
+            ),
+            false => self.write_all_components(components, rooted, f),
+        }
+    }
+
+    fn write_all_components<I>(
+        &self,
+        components: I,
+        rooted: bool,
+        f: &mut Formatter<'_>,
+    ) -> FmtResult
+    where
+        I: Iterator + Clone,
+        I::Item: Display,
+    {
+        let alternate = f.alternate();
+        let prepend = self.prepend || rooted;
+        let skipped = match self.max_components {
+            Some((front, back)) => {
+                let count = components.clone().count();
+                match count > front + back {
+                    true => front..count - back,
+                    false => 0..0,
+                }
+            }
+            None => 0..0,
+        };
+        let mut empty = true;
+        f.write_str(self.prefix)?;
+        for (index, component) in components.enumerate() {
+            if skipped.contains(&index) && index > skipped.start {
+                continue;
+            }
+            if index > 0 || prepend {
+                f.write_str(self.delimiter)?;
+            }
+            if skipped.contains(&index) {
+                f.write_char('…')?;
+                continue;
+            }
+            match (self.escape, alternate) {
+                (None, true) => f.write_fmt(format_args!("{:#}", component))?,
+                (None, false) => f.write_fmt(format_args!("{}", component))?,
+                (Some(escape), true) => EscapingWriter::new(f, self.delimiter, escape)
+                    .write_fmt(format_args!("{:#}", component))?,
+                (Some(escape), false) => EscapingWriter::new(f, self.delimiter, escape)
+                    .write_fmt(format_args!("{}", component))?,
+            }
+            empty = false;
+        }
+        // Empty path with either leading or trailing delimiter is outputted as single delimiter, like root directory.
+        match self.append || (empty && prepend) {
+            true => f.write_str(self.delimiter),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T: Display> Params<[T]> for PathLike {
+    fn fmt(&self, val: &[T], f: &mut Formatter<'_>) -> FmtResult {
+        self.write_components(val.iter(), false, f)
+    }
+}
+
+impl<T: Display, const N: usize> Params<[T; N]> for PathLike {
+    fn fmt(&self, val: &[T; N], f: &mut Formatter<'_>) -> FmtResult {
+        self.write_components(val.iter(), false, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "custom", feature = "alloc"))))]
+impl<T: Display> Params<alloc::vec::Vec<T>> for PathLike {
+    fn fmt(&self, val: &alloc::vec::Vec<T>, f: &mut Formatter<'_>) -> FmtResult {
+        self.write_components(val.iter(), false, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "custom", feature = "alloc"))))]
+impl<T: Display> Params<alloc::collections::VecDeque<T>> for PathLike {
+    fn fmt(&self, val: &alloc::collections::VecDeque<T>, f: &mut Formatter<'_>) -> FmtResult {
+        self.write_components(val.iter(), false, f)
+    }
+}
+
+/// Root directory of the path is outputted as the leading delimiter, other components are converted to strings lossily.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "custom", feature = "std"))))]
+impl Params<std::path::Path> for PathLike {
+    fn fmt(&self, val: &std::path::Path, f: &mut Formatter<'_>) -> FmtResult {
+        use std::path::Component;
+
+        let rooted = val.has_root();
+        let components = val
+            .components()
+            .filter(|component| component != &Component::RootDir)
+            .map(|component| component.as_os_str().to_string_lossy());
+        self.write_components(components, rooted, f)
+    }
+}
+
+/// Same as for [Path][std::path::Path].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "custom", feature = "std"))))]
+impl Params<std::path::PathBuf> for PathLike {
+    fn fmt(&self, val: &std::path::PathBuf, f: &mut Formatter<'_>) -> FmtResult {
+        Params::<std::path::Path>::fmt(self, val, f)
+    }
+}
+
+// Lets to escape path component characters while writing it.
+struct EscapingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    delimiter: &'static str,
+    escape: Escape,
+}
+
+impl<'a, 'b> EscapingWriter<'a, 'b> {
+    fn new(inner: &'a mut Formatter<'b>, delimiter: &'static str, escape: Escape) -> Self {
+        Self {
+            inner,
+            delimiter,
+            escape,
+        }
+    }
+}
+
+impl<'a, 'b> Write for EscapingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match self.escape {
+                Escape::Char(escape) if c == escape || self.delimiter.contains(c) => {
+                    self.inner.write_char(escape)?;
+                    self.inner.write_char(c)?;
+                }
+                Escape::Percent if !(c.is_ascii_alphanumeric() || "-._~".contains(c)) => {
+                    let mut buf = [0u8; 4];
+                    for byte in c.encode_utf8(&mut buf).bytes() {
+                        self.inner.write_fmt(format_args!("%{:02X}", byte))?;
+                    }
+                }
+                _ => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
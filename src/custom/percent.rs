@@ -0,0 +1,58 @@
+use super::Params;
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// [Params] implementation outputting numbers as percents, like `42.5%`.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Percent {
+    /// Amount of digits after the decimal point.
+    pub precision: usize,
+    /// Whether the value is a ratio (so `1.0` means `100%`) or a raw percent value (so `100.0` means `100%`).
+    pub ratio: bool,
+    /// Whether the resulting percent value should be clamped into `0..=100` range.
+    pub clamp: bool,
+}
+
+impl Percent {
+    /// Creates one [Percent] examplar treating values as ratios (`0.0..=1.0`).
+    pub fn ratio(precision: usize) -> Self {
+        Self {
+            precision,
+            ratio: true,
+            clamp: false,
+        }
+    }
+
+    /// Creates one [Percent] examplar treating values as raw percent values (`0.0..=100.0`).
+    pub fn raw(precision: usize) -> Self {
+        Self {
+            precision,
+            ratio: false,
+            clamp: false,
+        }
+    }
+
+    /// Makes current [Percent] examplar clamp the outputted value into `0..=100` range.
+    pub fn clamped(self) -> Self {
+        Self {
+            clamp: true,
+            ..self
+        }
+    }
+}
+
+impl<T: Copy + Into<f64>> Params<T> for Percent {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        let mut value: f64 = (*val).into();
+        if self.ratio {
+            value *= 100.0;
+        }
+        if self.clamp {
+            value = value.clamp(0.0, 100.0);
+        }
+        f.write_fmt(format_args!("{:.*}%", self.precision, value))
+    }
+}
@@ -0,0 +1,32 @@
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    format_args,
+};
+
+// Units the relative time is expressed in, from the largest one, with their length in seconds.
+const UNITS: [(&str, i64); 6] = [
+    ("year", 365 * 24 * 60 * 60),
+    ("month", 30 * 24 * 60 * 60),
+    ("day", 24 * 60 * 60),
+    ("hour", 60 * 60),
+    ("minute", 60),
+    ("second", 1),
+];
+
+// Outputs the specified amount of seconds passed since some moment (negative if it is yet to come)
+// in the largest fitting unit, like `5 minutes ago` or `in 2 hours`.
+pub(crate) fn write_relative(f: &mut Formatter<'_>, seconds: i64) -> FmtResult {
+    let magnitude = seconds.unsigned_abs();
+    let (unit, amount) = match UNITS.iter().find(|(_, length)| magnitude >= *length as u64) {
+        Some((unit, length)) => (*unit, magnitude / *length as u64),
+        None => return f.write_str("just now"),
+    };
+    let plural = match amount {
+        1 => "",
+        _ => "s",
+    };
+    match seconds > 0 {
+        true => f.write_fmt(format_args!("{} {}{} ago", amount, unit, plural)),
+        false => f.write_fmt(format_args!("in {} {}{}", amount, unit, plural)),
+    }
+}
@@ -0,0 +1,48 @@
+use super::Params;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// [Params] implementation outputting [Result] values uniformly: contained value or error is prefixed
+/// with the related decoration, like `✓ value` or `✗ error`.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResultStyle {
+    /// Prefix to put before Ok(_) value (including separating space if needed).
+    pub ok_prefix: &'static str,
+    /// Prefix to put before Err(_) value (including separating space if needed).
+    pub err_prefix: &'static str,
+}
+
+impl ResultStyle {
+    /// Decoration with check and cross marks, like `✓ value` or `✗ error`.
+    pub const MARKS: Self = Self {
+        ok_prefix: "✓ ",
+        err_prefix: "✗ ",
+    };
+    /// Decoration with plain words, like `ok: value` or `error: error`.
+    pub const WORDS: Self = Self {
+        ok_prefix: "ok: ",
+        err_prefix: "error: ",
+    };
+}
+
+impl Default for ResultStyle {
+    fn default() -> Self {
+        Self::MARKS
+    }
+}
+
+impl<T: Display, E: Display> Params<Result<T, E>> for ResultStyle {
+    fn fmt(&self, val: &Result<T, E>, f: &mut Formatter<'_>) -> FmtResult {
+        let (prefix, value): (_, &dyn Display) = match val {
+            Ok(value) => (self.ok_prefix, value),
+            Err(error) => (self.err_prefix, error),
+        };
+        match f.alternate() {
+            true => f.write_fmt(format_args!("{}{:#}", prefix, value)),
+            false => f.write_fmt(format_args!("{}{}", prefix, value)),
+        }
+    }
+}
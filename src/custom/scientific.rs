@@ -0,0 +1,77 @@
+use super::Params;
+use core::{
+    fmt::{Formatter, LowerExp, Result as FmtResult, Write},
+    format_args,
+};
+
+/// [Params] implementation outputting numbers in scientific notation with signed two-digit exponent, like `1.23e+06`.
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scientific {
+    /// Amount of digits after the decimal point of mantissa; as many as needed if not specified.
+    pub precision: Option<usize>,
+}
+
+impl Scientific {
+    /// Creates one [Scientific] examplar with the specified mantissa precision.
+    pub fn new(precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+        }
+    }
+}
+
+impl<T: LowerExp> Params<T> for Scientific {
+    fn fmt(&self, val: &T, f: &mut Formatter<'_>) -> FmtResult {
+        let mut writer = ExponentWriter {
+            inner: f,
+            exponent: None,
+        };
+        match self.precision {
+            Some(precision) => writer.write_fmt(format_args!("{:.*e}", precision, val))?,
+            None => writer.write_fmt(format_args!("{:e}", val))?,
+        }
+        writer.finish()
+    }
+}
+
+// Passes mantissa through as is, but collects exponent to output it in sign-aware two-digit form.
+struct ExponentWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    exponent: Option<(bool, u32)>,
+}
+
+impl<'a, 'b> ExponentWriter<'a, 'b> {
+    fn finish(self) -> FmtResult {
+        match self.exponent {
+            Some((true, value)) => self.inner.write_fmt(format_args!("e-{:02}", value)),
+            Some((false, value)) => self.inner.write_fmt(format_args!("e+{:02}", value)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, 'b> Write for ExponentWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let tail = match self.exponent {
+            Some(_) => s,
+            None => match s.find('e') {
+                Some(index) => {
+                    self.inner.write_str(&s[..index])?;
+                    self.exponent = Some((false, 0));
+                    &s[index + 1..]
+                }
+                None => return self.inner.write_str(s),
+            },
+        };
+        if let Some((negative, value)) = self.exponent.as_mut() {
+            for c in tail.chars() {
+                match c.to_digit(10) {
+                    Some(digit) => *value = *value * 10 + digit,
+                    None => *negative = c == '-',
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use super::{relative::write_relative, Params};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use time::{
+    format_description::{
+        well_known::{Rfc2822, Rfc3339},
+        BorrowedFormatItem,
+    },
+    Duration, OffsetDateTime,
+};
+
+/// [Params] implementation outputting `time` date and time values either with format description,
+/// or relatively to some moment, like `5 minutes ago`. Durations are outputted as time passed
+/// in relative mode, and with their own [Display] implementation otherwise.
+/// Value which can not be described with the specified format makes the output fail.
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// Outputs value with the specified format description, like the one made with `time::macros::format_description`.
+    Description(&'static [BorrowedFormatItem<'static>]),
+    /// Outputs value in RFC 3339 format, like `2024-05-01T12:30:00.25+02:00`.
+    #[default]
+    Rfc3339,
+    /// Outputs value in RFC 2822 format, like `Wed, 01 May 2024 12:30:00 +0200`.
+    Rfc2822,
+    /// Outputs value relatively to the specified moment, like `5 minutes ago` or `in 2 hours`.
+    RelativeTo(OffsetDateTime),
+}
+
+impl TimeFormat {
+    /// Creates one [TimeFormat] examplar outputting values relatively to the moment of its creation.
+    pub fn relative() -> Self {
+        Self::RelativeTo(OffsetDateTime::now_utc())
+    }
+}
+
+impl Params<OffsetDateTime> for TimeFormat {
+    fn fmt(&self, val: &OffsetDateTime, f: &mut Formatter<'_>) -> FmtResult {
+        let formatted = match self {
+            Self::Description(description) => val.format(description),
+            Self::Rfc3339 => val.format(&Rfc3339),
+            Self::Rfc2822 => val.format(&Rfc2822),
+            Self::RelativeTo(moment) => return write_relative(f, (*moment - *val).whole_seconds()),
+        };
+        f.write_str(&formatted.map_err(|_| core::fmt::Error)?)
+    }
+}
+
+impl Params<Duration> for TimeFormat {
+    fn fmt(&self, val: &Duration, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::RelativeTo(_) => write_relative(f, val.whole_seconds()),
+            _ => Display::fmt(val, f),
+        }
+    }
+}
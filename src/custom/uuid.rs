@@ -0,0 +1,43 @@
+use super::Params;
+use core::{
+    fmt::{Formatter, Result as FmtResult, Write},
+    format_args,
+};
+use uuid::{fmt::Simple, Uuid};
+
+/// [Params] implementation outputting [Uuid] values in full, simple or shortened form,
+/// so the structures with lots of identifiers are less noisy.
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UuidFormat {
+    /// Hyphenated form, like `a1b2c3d4-e5f6-4789-abcd-ef0123456789`.
+    #[default]
+    Full,
+    /// Form without dashes, like `a1b2c3d4e5f64789abcdef0123456789`.
+    Simple,
+    /// The specified amount of leading hex digits followed by ellipsis, like `a1b2c3d4…`.
+    /// Value is outputted in simple form if the amount covers all the digits.
+    Short(usize),
+}
+
+impl UuidFormat {
+    /// Shortened form with 8 leading hex digits, like `a1b2c3d4…`.
+    pub const SHORT: Self = Self::Short(8);
+}
+
+impl Params<Uuid> for UuidFormat {
+    fn fmt(&self, val: &Uuid, f: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            Self::Full => f.write_fmt(format_args!("{}", val.hyphenated())),
+            Self::Simple => f.write_fmt(format_args!("{}", val.simple())),
+            Self::Short(digits) if digits >= Simple::LENGTH => {
+                f.write_fmt(format_args!("{}", val.simple()))
+            }
+            Self::Short(digits) => {
+                let mut buffer = Uuid::encode_buffer();
+                f.write_str(&val.simple().encode_lower(&mut buffer)[..digits])?;
+                f.write_char('…')
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Comparing two states of some value is easier when only the difference between them is outputted.
+//! This module contains [DiffShow] outputting field-by-field difference between two values sharing
+//! [EmbedStruct] implementation, like `{count: 1 -> 2}`, which suits test failure messages and state change logs,
+//! and [ListDiffShow] outputting items added to and removed from some sequence, like `[…, - 2, + 3]`.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{DiffShow, EmbedStruct, StructShow};
+//!
+//! struct Session {
+//!     user: &'static str,
+//!     requests: u32,
+//!     token: Option<&'static str>,
+//! }
+//!
+//! impl EmbedStruct for Session {
+//!     fn embed(&self, show: &mut StructShow) {
+//!         show.field(&"user", &self.user)
+//!             .field(&"requests", &self.requests)
+//!             .field_opt(&"token", &self.token);
+//!     }
+//! }
+//!
+//! let before = Session { user: "admin", requests: 1, token: None };
+//! let after = Session { user: "admin", requests: 5, token: Some("abc") };
+//! assert_eq!("{requests: 1 -> 5, token: ∅ -> abc}", &format!("{}", DiffShow::new(&before, &after)));
+//! assert_eq!(
+//!     "{\n    user: admin,\n    requests: 1 -> 5,\n    token: ∅ -> abc,\n}",
+//!     &format!("{:#}", DiffShow::new(&before, &after).with_unchanged(true))
+//! );
+//! ```
+
+use crate::{
+    forward::try_render, measure::WriteCounter, Alternate, EmbedList, EmbedStruct, ListShow,
+    StructShow,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    cell::RefCell,
+    fmt::{Display, Error, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+// Keys and values of the fields, rendered in one line, in the order of their output.
+type Rendered = Vec<(String, String)>;
+
+// Lets to collect the fields of the value instead of outputting them.
+struct Collecting<'e, E> {
+    val: &'e E,
+    fields: RefCell<Rendered>,
+}
+
+impl<'e, E: EmbedStruct> Display for Collecting<'e, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut fields = self.fields.borrow_mut();
+        // Failure of any field output is kept to be returned after the collection.
+        let mut result = Ok(());
+        let mut collector = |key: Option<&dyn Display>, val: &dyn Display| {
+            let field = key
+                .map_or_else(
+                    || Ok(String::new()),
+                    |key| try_render(key, Alternate::OneLine),
+                )
+                .and_then(|key| Ok((key, try_render(val, Alternate::OneLine)?)));
+            match (result, field) {
+                (Ok(()), Ok(field)) => fields.push(field),
+                (Ok(()), Err(error)) => result = Err(error),
+                (Err(_), _) => (),
+            }
+        };
+        let mut show = StructShow::new(f, Alternate::OneLine);
+        show.collect_into(&mut collector);
+        show.embed(self.val);
+        show.finish().and(result)
+    }
+}
+
+// Returns the fields of the value rendered in one line, or error if output of any of them failed.
+fn collect_fields<E: EmbedStruct>(val: &E) -> Result<Rendered, Error> {
+    let collecting = Collecting {
+        val,
+        fields: RefCell::new(Vec::new()),
+    };
+    // Only the brackets are outputted while the fields are collected, so the output is discarded.
+    WriteCounter::default().write_fmt(format_args!("{}", collecting))?;
+    Ok(collecting.fields.into_inner())
+}
+
+// Lets to collect the items of the value instead of outputting them.
+struct CollectingList<'e, E> {
+    val: &'e E,
+    items: RefCell<Vec<String>>,
+}
+
+impl<'e, E: EmbedList> Display for CollectingList<'e, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut items = self.items.borrow_mut();
+        // Failure of any item output is kept to be returned after the collection.
+        let mut result = Ok(());
+        let mut collector = |_: Option<&dyn Display>, val: &dyn Display| match (
+            result,
+            try_render(val, Alternate::OneLine),
+        ) {
+            (Ok(()), Ok(item)) => items.push(item),
+            (Ok(()), Err(error)) => result = Err(error),
+            (Err(_), _) => (),
+        };
+        let mut show = ListShow::new(f, Alternate::OneLine);
+        show.collect_into(&mut collector);
+        show.embed(self.val);
+        show.finish().and(result)
+    }
+}
+
+// Returns the items of the value rendered in one line, or error if output of any of them failed.
+fn collect_items<E: EmbedList>(val: &E) -> Result<Vec<String>, Error> {
+    let collecting = CollectingList {
+        val,
+        items: RefCell::new(Vec::new()),
+    };
+    // Only the brackets are outputted while the items are collected, so the output is discarded.
+    WriteCounter::default().write_fmt(format_args!("{}", collecting))?;
+    Ok(collecting.items.into_inner())
+}
+
+// Change of one field: its old value (if the field was there) and its new one (if the field is there).
+struct Change<'r> {
+    old: Option<&'r str>,
+    new: Option<&'r str>,
+}
+
+impl<'r> Display for Change<'r> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.old.unwrap_or("∅"))?;
+        f.write_str(" -> ")?;
+        f.write_str(self.new.unwrap_or("∅"))
+    }
+}
+
+/// Lets to output the fields which differ between two values sharing [EmbedStruct] implementation
+/// as struct, regarding the propagated value of output alternativeness. Every changed field is outputted
+/// like `key: old -> new`, fields present in only one of the values have `∅` instead of the missing value.
+/// Fields are matched by their keys and compared by their one-line output, unchanged ones are skipped.
+/// If output of any field fails, so does the output of [DiffShow].
+#[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+#[derive(Clone, Copy, Debug)]
+pub struct DiffShow<'e, E> {
+    old: &'e E,
+    new: &'e E,
+    unchanged: bool,
+}
+
+impl<'e, E: EmbedStruct> DiffShow<'e, E> {
+    /// Creates one [DiffShow] examplar outputting the difference between the specified values.
+    pub fn new(old: &'e E, new: &'e E) -> Self {
+        Self {
+            old,
+            new,
+            unchanged: false,
+        }
+    }
+
+    /// Makes current [DiffShow] examplar output the unchanged fields too, as they are.
+    pub fn with_unchanged(self, unchanged: bool) -> Self {
+        Self { unchanged, ..self }
+    }
+}
+
+impl<'e, E: EmbedStruct> Display for DiffShow<'e, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let old = collect_fields(self.old)?;
+        let new = collect_fields(self.new)?;
+        let mut matched = alloc::vec![false; old.len()];
+        let mut show = StructShow::inherit(f);
+        for (key, val) in &new {
+            let found = (0..old.len()).find(|&index| !matched[index] && old[index].0 == *key);
+            let old_val = match found {
+                Some(index) => {
+                    matched[index] = true;
+                    Some(old[index].1.as_str())
+                }
+                None => None,
+            };
+            match old_val {
+                Some(old_val) if old_val == val => {
+                    if self.unchanged {
+                        show.field(key, val);
+                    }
+                }
+                old_val => {
+                    show.field(
+                        key,
+                        &Change {
+                            old: old_val,
+                            new: Some(val),
+                        },
+                    );
+                }
+            }
+        }
+        for ((key, val), _) in old.iter().zip(&matched).filter(|(_, matched)| !**matched) {
+            show.field(
+                key,
+                &Change {
+                    old: Some(val),
+                    new: None,
+                },
+            );
+        }
+        show.finish()
+    }
+}
+
+/// Performs the whole difference output routine, with no need to create [DiffShow] examplar.
+/// Always inherits alternate mode.
+#[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+pub fn display_diff<E: EmbedStruct>(f: &mut Formatter<'_>, old: &E, new: &E) -> FmtResult {
+    Display::fmt(&DiffShow::new(old, new), f)
+}
+
+// One step of turning the old sequence into the new one.
+#[derive(Clone, Copy)]
+enum Edit {
+    Kept(usize),
+    Removed(usize),
+    Added(usize),
+}
+
+// Returns the shortest sequence of edits turning the old items into the new ones,
+// found through their longest common subsequence.
+fn edits(old: &[String], new: &[String]) -> Vec<Edit> {
+    let width = new.len() + 1;
+    // Lengths of the longest common subsequences of all the suffixes of the old and new items.
+    let mut common = alloc::vec![0usize; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i * width + j] = match old[i] == new[j] {
+                true => common[(i + 1) * width + j + 1] + 1,
+                false => common[(i + 1) * width + j].max(common[i * width + j + 1]),
+            };
+        }
+    }
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push(Edit::Kept(i));
+            i += 1;
+            j += 1;
+        } else if i < old.len()
+            && (j == new.len() || common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            edits.push(Edit::Removed(i));
+            i += 1;
+        } else {
+            edits.push(Edit::Added(j));
+            j += 1;
+        }
+    }
+    edits
+}
+
+// Item of the sequence marked as added (`+`), removed (`-`) or kept (no marker, padded in prettified format
+// to stay aligned with the marked ones).
+struct Marked<'r> {
+    marker: Option<char>,
+    val: &'r str,
+}
+
+impl<'r> Display for Marked<'r> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.marker, f.alternate()) {
+            (Some(marker), _) => f.write_fmt(format_args!("{} {}", marker, self.val)),
+            (None, true) => f.write_fmt(format_args!("  {}", self.val)),
+            (None, false) => f.write_str(self.val),
+        }
+    }
+}
+
+/// Lets to output the difference between two sequences of items as list, regarding the propagated value
+/// of output alternativeness. Added items are marked with `+`, removed ones with `-`, and every run of kept items
+/// is replaced with `…` unless they are asked to be outputted too. Items are compared by their one-line output,
+/// which is rendered right away, so the sequences are not borrowed. Comparison takes time and memory
+/// proportional to the product of the sequences lengths. If output of any item fails, so does the output
+/// of [ListDiffShow].
+#[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListDiffShow {
+    old: Vec<String>,
+    new: Vec<String>,
+    unchanged: bool,
+    // Failure of any item output, returned instead of the difference.
+    error: Option<Error>,
+}
+
+impl ListDiffShow {
+    /// Creates one [ListDiffShow] examplar outputting the difference between the items of the specified values
+    /// sharing [EmbedList] implementation.
+    pub fn new<E: EmbedList>(old: &E, new: &E) -> Self {
+        Self::rendered(collect_items(old), collect_items(new))
+    }
+
+    /// Creates one [ListDiffShow] examplar outputting the difference between the items of the specified iterables.
+    pub fn from_iters<O, N>(old: O, new: N) -> Self
+    where
+        O: IntoIterator,
+        O::Item: Display,
+        N: IntoIterator,
+        N::Item: Display,
+    {
+        let rendered = |val: &dyn Display| try_render(val, Alternate::OneLine);
+        Self::rendered(
+            old.into_iter().map(|val| rendered(&val)).collect(),
+            new.into_iter().map(|val| rendered(&val)).collect(),
+        )
+    }
+
+    fn rendered(old: Result<Vec<String>, Error>, new: Result<Vec<String>, Error>) -> Self {
+        match (old, new) {
+            (Ok(old), Ok(new)) => Self {
+                old,
+                new,
+                ..Self::default()
+            },
+            (Err(error), _) | (_, Err(error)) => Self {
+                error: Some(error),
+                ..Self::default()
+            },
+        }
+    }
+
+    /// Makes current [ListDiffShow] examplar output the kept items too, instead of replacing them with `…`.
+    pub fn with_unchanged(self, unchanged: bool) -> Self {
+        Self { unchanged, ..self }
+    }
+}
+
+impl Display for ListDiffShow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let mut show = ListShow::inherit(f);
+        let mut elided = false;
+        for edit in edits(&self.old, &self.new) {
+            let (marker, val) = match edit {
+                Edit::Kept(_) if !self.unchanged => {
+                    if !elided {
+                        show.item(&"…");
+                    }
+                    elided = true;
+                    continue;
+                }
+                Edit::Kept(index) => (None, &self.old[index]),
+                Edit::Removed(index) => (Some('-'), &self.old[index]),
+                Edit::Added(index) => (Some('+'), &self.new[index]),
+            };
+            elided = false;
+            show.item(&Marked { marker, val });
+        }
+        show.finish()
+    }
+}
+
+/// Performs the whole sequence difference output routine, with no need to create [ListDiffShow] examplar.
+/// Always inherits alternate mode.
+#[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+pub fn display_list_diff<E: EmbedList>(f: &mut Formatter<'_>, old: &E, new: &E) -> FmtResult {
+    Display::fmt(&ListDiffShow::new(old, new), f)
+}
@@ -27,7 +27,9 @@ mod list {
         formatter: &mut Formatter<'_>,
         alternate: Alternate,
     ) -> FmtResult {
-        ListShow::new(formatter, alternate).embed(this).finish()
+        let mut show = ListShow::new(formatter, alternate);
+        show.embed(this);
+        show.finish()
     }
 }
 
@@ -60,7 +62,49 @@ mod r#struct {
         formatter: &mut Formatter<'_>,
         alternate: Alternate,
     ) -> FmtResult {
-        StructShow::new(formatter, alternate).embed(this).finish()
+        let mut show = StructShow::new(formatter, alternate);
+        show.embed(this);
+        show.finish()
+    }
+}
+
+#[cfg(feature = "tree")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tree")))]
+mod tree {
+    use crate::{Alternate, TreeShow};
+    use core::fmt::{Display, Formatter, Result as FmtResult};
+
+    /// Trait letting to define recursive type as tree, so its examplars can be embedded into [TreeShow] output
+    /// along with all their descendants.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "embed", feature = "tree"))))]
+    pub trait EmbedTree {
+        /// Returns label of the node, outputted on its own line in prettified format.
+        fn label(&self) -> impl Display;
+
+        /// Returns direct children of the node.
+        fn children(&self) -> impl Iterator<Item = &Self>;
+    }
+
+    impl<'a, 'b> TreeShow<'a, 'b> {
+        /// Embeds given [EmbedTree] implementing type examplar into current output as node, along with its descendants.
+        pub fn embed<E: EmbedTree>(&mut self, embedding: &E) -> &mut Self {
+            self.node(&embedding.label(), |tree| {
+                for child in embedding.children() {
+                    tree.embed(child);
+                }
+            })
+        }
+    }
+
+    /// Routine to simplify [Display] implementation for type which already implements [EmbedTree].
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "embed", feature = "tree"))))]
+    #[inline]
+    pub fn display_tree_from_embed<E: EmbedTree>(
+        this: &E,
+        formatter: &mut Formatter<'_>,
+        alternate: Alternate,
+    ) -> FmtResult {
+        TreeShow::new(formatter, alternate).embed(this).finish()
     }
 }
 
@@ -69,3 +113,6 @@ pub use list::*;
 
 #[cfg(feature = "struct")]
 pub use r#struct::*;
+
+#[cfg(feature = "tree")]
+pub use tree::*;
@@ -0,0 +1,138 @@
+//! Error reports are most useful when they contain the whole chain of the error causes, not only the topmost error.
+//! This module contains [ErrorChain] outputting the error along with all its sources: in one line
+//! like `a: b: c`, or as list with every cause on its own line in alternate mode.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::ErrorChain;
+//! use std::error::Error;
+//!
+//! #[derive(Debug)]
+//! struct Missing;
+//!
+//! impl Display for Missing {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         f.write_str("file is missing")
+//!     }
+//! }
+//!
+//! impl Error for Missing {}
+//!
+//! #[derive(Debug)]
+//! struct Config(Missing);
+//!
+//! impl Display for Config {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         f.write_str("failed to load config")
+//!     }
+//! }
+//!
+//! impl Error for Config {
+//!     fn source(&self) -> Option<&(dyn Error + 'static)> {
+//!         Some(&self.0)
+//!     }
+//! }
+//!
+//! let error = Config(Missing);
+//! assert_eq!("failed to load config: file is missing", &format!("{}", ErrorChain(&error)));
+//! assert_eq!(
+//!     "[\n    failed to load config,\n    file is missing,\n]",
+//!     &format!("{:#}", ErrorChain(&error))
+//! );
+//! ```
+//!
+//! With `anyhow` or `eyre` feature enabled, [ErrorChain] can be created from `anyhow::Error` or `eyre::Report`,
+//! and these types implement [EmbedStruct][crate::EmbedStruct] embedding the `message` and `causes` fields
+//! into the struct output, so the error can be a part of larger structured status output.
+
+use crate::{Alternate, ListShow};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+    iter::successors,
+};
+
+/// Lets to output error along with the chain of its sources, walked with [Error::source].
+/// Outputs the chain in one line with causes separated by `: `, or as list of the causes in alternate mode.
+/// Every cause is outputted in one-line mode regardless.
+#[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+#[derive(Clone, Copy)]
+pub struct ErrorChain<'a>(pub &'a dyn Error);
+
+impl<'a> ErrorChain<'a> {
+    /// Returns iterator over the error and all its sources, starting from the error itself.
+    pub fn iter(&self) -> impl Iterator<Item = &'a dyn Error> {
+        successors(Some(self.0), |&error: &&'a dyn Error| error.source())
+    }
+}
+
+impl<'a> Display for ErrorChain<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            let mut show = ListShow::new(f, Alternate::OneLine);
+            show.items_from_iter(self.iter());
+            return show.finish();
+        }
+        for (index, error) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(": ")?;
+            }
+            f.write_fmt(format_args!("{}", error))?;
+        }
+        Ok(())
+    }
+}
+
+// Outputs the sources of the error as list, each of them in one-line mode.
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+struct Causes<'a>(&'a dyn Error);
+
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+impl<'a> Display for Causes<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut show = ListShow::new(f, Alternate::OneLine);
+        show.items_from_iter(ErrorChain(self.0).iter().skip(1));
+        show.finish()
+    }
+}
+
+// Embeds the error message and its sources into the struct output.
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+fn embed_error(error: &dyn Error, show: &mut crate::StructShow) {
+    show.field_override(&"message", &error, Alternate::OneLine)
+        .field(&"causes", &Causes(error));
+}
+
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+impl<'a> From<&'a anyhow::Error> for ErrorChain<'a> {
+    fn from(error: &'a anyhow::Error) -> Self {
+        Self(error.as_ref())
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+impl crate::EmbedStruct for anyhow::Error {
+    fn embed(&self, show: &mut crate::StructShow) {
+        embed_error(self.as_ref(), show);
+    }
+}
+
+#[cfg(feature = "eyre")]
+#[cfg_attr(docsrs, doc(cfg(feature = "eyre")))]
+impl<'a> From<&'a eyre::Report> for ErrorChain<'a> {
+    fn from(error: &'a eyre::Report) -> Self {
+        Self(error.as_ref())
+    }
+}
+
+#[cfg(feature = "eyre")]
+#[cfg_attr(docsrs, doc(cfg(feature = "eyre")))]
+impl crate::EmbedStruct for eyre::Report {
+    fn embed(&self, show: &mut crate::StructShow) {
+        embed_error(self.as_ref(), show);
+    }
+}
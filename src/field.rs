@@ -1,36 +1,211 @@
+use crate::{
+    forward::{write_forwarded, Specifiers},
+    measure::measure,
+};
 use core::{
-    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
     format_args,
 };
 
+// Lets to measure and output keys in debug mode the same way as in display mode.
+struct DebugKey<'a, K: ?Sized>(&'a K);
+
+impl<'a, K: Debug + ?Sized> Display for DebugKey<'a, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+// Outputs key padded with spaces up to the specified width, then the separator.
+fn write_key(w: &mut dyn Write, key: &dyn Display, width: usize, separator: &str) -> FmtResult {
+    w.write_fmt(format_args!("{}", key))?;
+    for _ in measure(key, false)..width {
+        w.write_char(' ')?;
+    }
+    w.write_str(separator)
+}
+
 /// Lets to output key-value pair regarding the propagated value of output alternativeness.
+/// Width, fill and alignment specified for the [Field] output are applied to the whole output,
+/// while precision is applied to the value only if it looks like a number.
 #[cfg_attr(docsrs, doc(cfg(feature = "field")))]
 pub struct Field<'a, K: ?Sized, V: ?Sized> {
     key: &'a K,
     val: &'a V,
+    separator: &'a str,
+    key_width: usize,
 }
 
 impl<'a, K: ?Sized, V: ?Sized> Field<'a, K, V> {
+    /// Separator used between key and value by default.
+    pub const DEFAULT_SEPARATOR: &'static str = ": ";
+
     /// Creates one [Field] examplar ready to be outputted.
     pub fn new(key: &'a K, val: &'a V) -> Self {
-        Self { key, val }
+        Self {
+            key,
+            val,
+            separator: Self::DEFAULT_SEPARATOR,
+            key_width: 0,
+        }
+    }
+
+    /// Creates one [Field] examplar from the left and right parts of the specified [DisplayPair][crate::DisplayPair].
+    #[cfg(feature = "struct")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "field", feature = "struct"))))]
+    pub fn from_pair<P>(pair: &'a P) -> Self
+    where
+        P: crate::DisplayPair<Left = K, Right = V>,
+        K: Sized,
+        V: Sized,
+    {
+        Self::new(pair.left(), pair.right())
+    }
+
+    /// Makes current [Field] examplar use the specified separator between key and value instead of the default one.
+    pub fn with_separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Makes current [Field] examplar pad its key with spaces up to the specified width (in characters) before the separator,
+    /// so several fields outputted line by line form aligned columns.
+    pub fn aligned(self, key_width: usize) -> Self {
+        Self { key_width, ..self }
+    }
+}
+
+impl<'a, K: ?Sized, V: ?Sized> From<(&'a K, &'a V)> for Field<'a, K, V> {
+    fn from((key, val): (&'a K, &'a V)) -> Self {
+        Self::new(key, val)
+    }
+}
+
+impl<'a, K, V> From<&'a (K, V)> for Field<'a, K, V> {
+    fn from((key, val): &'a (K, V)) -> Self {
+        Self::new(key, val)
     }
 }
 
 impl<'a, K: Display + ?Sized, V: Display + ?Sized> Display for Field<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match f.alternate() {
-            true => f.write_fmt(format_args!("{}: {:#}", self.key, self.val)),
-            false => f.write_fmt(format_args!("{}: {}", self.key, self.val)),
-        }
+        let (alternate, precision) = (f.alternate(), f.precision());
+        Specifiers::of(f).write_padded(f, &mut |w| {
+            write_key(w, &self.key, self.key_width, self.separator)?;
+            write_forwarded(&self.val, alternate, precision, w)
+        })
     }
 }
 
 impl<'a, K: Debug + ?Sized, V: Debug + ?Sized> Debug for Field<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_key(f, &DebugKey(self.key), self.key_width, self.separator)?;
         match f.alternate() {
-            true => f.write_fmt(format_args!("{:?}: {:#?}", self.key, self.val)),
-            false => f.write_fmt(format_args!("{:?}: {:?}", self.key, self.val)),
+            true => f.write_fmt(format_args!("{:#?}", self.val)),
+            false => f.write_fmt(format_args!("{:?}", self.val)),
+        }
+    }
+}
+
+/// Lets to output optional key-value pair: it is outputted like [Field] when value matches Some(_),
+/// and either is skipped entirely or outputted with placeholder instead of value otherwise.
+#[cfg_attr(docsrs, doc(cfg(feature = "field")))]
+pub struct FieldOpt<'a, K: ?Sized, V> {
+    key: &'a K,
+    val: &'a Option<V>,
+    separator: &'a str,
+    key_width: usize,
+    placeholder: Option<&'a str>,
+}
+
+impl<'a, K: ?Sized, V> FieldOpt<'a, K, V> {
+    /// Creates one [FieldOpt] examplar ready to be outputted, which outputs nothing if the value is None.
+    pub fn new(key: &'a K, val: &'a Option<V>) -> Self {
+        Self {
+            key,
+            val,
+            separator: Field::<K, V>::DEFAULT_SEPARATOR,
+            key_width: 0,
+            placeholder: None,
+        }
+    }
+
+    /// Makes current [FieldOpt] examplar use the specified separator between key and value instead of the default one.
+    pub fn with_separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Makes current [FieldOpt] examplar pad its key with spaces up to the specified width (in characters) before the separator.
+    pub fn aligned(self, key_width: usize) -> Self {
+        Self { key_width, ..self }
+    }
+
+    /// Makes current [FieldOpt] examplar output the specified placeholder instead of value if it is None.
+    pub fn with_placeholder(self, placeholder: &'a str) -> Self {
+        Self {
+            placeholder: Some(placeholder),
+            ..self
         }
     }
 }
+
+impl<'a, K: ?Sized, V> FieldOpt<'a, K, V> {
+    fn field<T: ?Sized>(&self, val: &'a T) -> Field<'a, K, T> {
+        Field::new(self.key, val)
+            .with_separator(self.separator)
+            .aligned(self.key_width)
+    }
+}
+
+impl<'a, K: Display + ?Sized, V: Display> Display for FieldOpt<'a, K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.val, self.placeholder) {
+            (Some(val), _) => Display::fmt(&self.field(val), f),
+            (None, Some(placeholder)) => Display::fmt(&self.field(placeholder), f),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Lets to output several key-value pairs inline, separated with the specified separator and without any braces,
+/// like `k1: v1, k2: v2`, so they can be composed into larger messages.
+/// Width, fill and alignment specified for the [Fields] output are applied to the whole output,
+/// while precision is applied to the values looking like numbers.
+#[cfg_attr(docsrs, doc(cfg(feature = "field")))]
+pub struct Fields<'a> {
+    fields: &'a [(&'a dyn Display, &'a dyn Display)],
+    separator: &'a str,
+}
+
+impl<'a> Fields<'a> {
+    /// Separator used between key-value pairs by default.
+    pub const DEFAULT_SEPARATOR: &'static str = ", ";
+
+    /// Creates one [Fields] examplar ready to be outputted.
+    pub fn new(fields: &'a [(&'a dyn Display, &'a dyn Display)]) -> Self {
+        Self {
+            fields,
+            separator: Self::DEFAULT_SEPARATOR,
+        }
+    }
+
+    /// Makes current [Fields] examplar use the specified separator between key-value pairs instead of the default one.
+    pub fn with_separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+}
+
+impl<'a> Display for Fields<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (alternate, precision) = (f.alternate(), f.precision());
+        Specifiers::of(f).write_padded(f, &mut |w| {
+            for (index, (key, val)) in self.fields.iter().enumerate() {
+                if index > 0 {
+                    w.write_str(self.separator)?;
+                }
+                write_key(w, *key, 0, Field::<(), ()>::DEFAULT_SEPARATOR)?;
+                write_forwarded(*val, alternate, precision, w)?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(feature = "field")]
+use crate::measure::WriteCounter;
+#[cfg(any(feature = "alloc", feature = "field"))]
+use core::fmt::{Alignment, Formatter};
+use core::{
+    fmt::{Display, Result as FmtResult, Write},
+    format_args,
+};
+
+// Checks whether the output written into it looks like a number, like `-12`, `3.5` or `1e-9`,
+// discarding the output itself.
+#[derive(Default)]
+struct NumberChecker {
+    digits: bool,
+    invalid: bool,
+}
+
+impl Write for NumberChecker {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '0'..='9' => self.digits = true,
+                '+' | '-' | '.' | 'e' | 'E' => (),
+                _ => self.invalid = true,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Returns whether the one-line output of the value looks like a number.
+fn is_number(val: &dyn Display) -> bool {
+    let mut checker = NumberChecker::default();
+    // NumberChecker never fails by itself, so any error is caused by the value output, which fails later anyway.
+    let _ = checker.write_fmt(format_args!("{}", val));
+    checker.digits && !checker.invalid
+}
+
+// Format specifiers of the formatter to be applied to the whole output, captured before the formatter
+// is borrowed by some writer.
+#[cfg(any(feature = "alloc", feature = "field"))]
+#[derive(Clone, Copy)]
+pub(crate) struct Specifiers {
+    pub(crate) width: Option<usize>,
+    fill: char,
+    align: Alignment,
+}
+
+#[cfg(any(feature = "alloc", feature = "field"))]
+impl Specifiers {
+    // Captures the specifiers the way Formatter::pad regards them, so zero flag is ignored.
+    pub(crate) fn of(f: &Formatter<'_>) -> Self {
+        Self {
+            width: f.width(),
+            fill: f.fill(),
+            align: f.align().unwrap_or(Alignment::Left),
+        }
+    }
+
+    // Outputs whatever the given function outputs, padded with fill up to the width (if any) as the alignment requires.
+    // The function is called twice if the width is specified: once to measure the output and once to make it.
+    #[cfg(feature = "field")]
+    pub(crate) fn write_padded(
+        &self,
+        w: &mut dyn Write,
+        write: &mut dyn FnMut(&mut dyn Write) -> FmtResult,
+    ) -> FmtResult {
+        let width = match self.width {
+            Some(width) => width,
+            None => return write(w),
+        };
+        let mut counter = WriteCounter::default();
+        // WriteCounter never fails by itself, so any error is caused by the output, which fails later anyway.
+        let _ = write(&mut counter);
+        let (before, after) = self.spread(width.saturating_sub(counter.chars));
+        self.write_fill(w, before)?;
+        write(w)?;
+        self.write_fill(w, after)
+    }
+
+    // Returns whether the output may need fill before it, so it can not be written till its width is known.
+    #[cfg(all(feature = "alloc", any(feature = "list", feature = "struct")))]
+    pub(crate) fn fills_before(&self) -> bool {
+        self.align != Alignment::Left
+    }
+
+    // Splits the amount of fill characters lacking into the ones before and after the output, as the alignment requires.
+    pub(crate) fn spread(&self, lack: usize) -> (usize, usize) {
+        match self.align {
+            Alignment::Left => (0, lack),
+            Alignment::Right => (lack, 0),
+            Alignment::Center => (lack / 2, lack - lack / 2),
+        }
+    }
+
+    // Outputs the specified amount of fill characters.
+    pub(crate) fn write_fill(&self, w: &mut dyn Write, count: usize) -> FmtResult {
+        for _ in 0..count {
+            w.write_char(self.fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outputs given value in the specified alternate mode into the given writer, applying the specified precision to it
+/// if its output looks like a number, so precision of floating point values is kept while texts are not cut.
+pub(crate) fn write_forwarded(
+    val: &dyn Display,
+    alternate: bool,
+    precision: Option<usize>,
+    w: &mut dyn Write,
+) -> FmtResult {
+    match precision.filter(|_| is_number(val)) {
+        Some(precision) => match alternate {
+            true => w.write_fmt(format_args!("{:#.*}", precision, val)),
+            false => w.write_fmt(format_args!("{:.*}", precision, val)),
+        },
+        None => write_alternated(val, alternate, w),
+    }
+}
+
+fn write_alternated(val: &dyn Display, alternate: bool, w: &mut dyn Write) -> FmtResult {
+    match alternate {
+        true => w.write_fmt(format_args!("{:#}", val)),
+        false => w.write_fmt(format_args!("{}", val)),
+    }
+}
+
+/// Outputs given value into the given writer, in prettified format if [Alternate::Pretty][crate::Alternate::Pretty] is specified.
+#[cfg(all(feature = "alloc", any(feature = "list", feature = "struct")))]
+pub(crate) fn write_rendered(
+    val: &dyn Display,
+    alternate: crate::Alternate,
+    w: &mut dyn Write,
+) -> FmtResult {
+    write_alternated(val, alternate == crate::Alternate::Pretty, w)
+}
+
+/// Outputs given value into new string, in prettified format if [Alternate::Pretty][crate::Alternate::Pretty] is specified.
+#[cfg(all(feature = "alloc", any(feature = "list", feature = "struct")))]
+pub(crate) fn render(val: &dyn Display, alternate: crate::Alternate) -> alloc::string::String {
+    // Writing into string never fails by itself, so only failed value output gets here, which `format!` panics on too.
+    try_render(val, alternate).expect("a Display implementation returned an error unexpectedly")
+}
+
+/// Outputs given value into new string like [render] does, returning error if the value output failed.
+#[cfg(all(feature = "alloc", any(feature = "list", feature = "struct")))]
+pub(crate) fn try_render(
+    val: &dyn Display,
+    alternate: crate::Alternate,
+) -> Result<alloc::string::String, core::fmt::Error> {
+    let mut output = alloc::string::String::new();
+    write_rendered(val, alternate, &mut output)?;
+    Ok(output)
+}
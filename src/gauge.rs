@@ -0,0 +1,67 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Lets to output progress of some work as gauge bar followed by percentage, like `[#####-----] 50%`,
+/// when outputted in alternate mode, or as percentage only otherwise, so status structures carrying progress
+/// stay compact in one line. Value exceeding the maximal one is outputted as the maximal one,
+/// and the work with zero maximal value is regarded as done.
+///
+/// Usage example:
+/// ```
+/// use cubob::Gauge;
+///
+/// let gauge = Gauge { value: 3, max: 4, width: 8 };
+/// assert_eq!("[######--] 75%", &format!("{:#}", gauge));
+/// assert_eq!("75%", &format!("{}", gauge));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "gauge")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gauge {
+    /// Amount of the work done.
+    pub value: u64,
+    /// Amount of the whole work.
+    pub max: u64,
+    /// Amount of characters between the brackets of the bar.
+    pub width: usize,
+}
+
+impl Gauge {
+    /// Creates one [Gauge] examplar with the bar 10 characters wide.
+    pub fn new(value: u64, max: u64) -> Self {
+        Self {
+            value,
+            max,
+            width: 10,
+        }
+    }
+
+    /// Makes current [Gauge] examplar output the bar of the specified width.
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    // Returns the part of the work done, scaled to the specified amount of units.
+    fn scaled(&self, units: usize) -> usize {
+        match self.max {
+            0 => units,
+            max => (u128::from(self.value.min(max)) * units as u128 / u128::from(max)) as usize,
+        }
+    }
+}
+
+impl Display for Gauge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let percent = self.scaled(100);
+        if !f.alternate() {
+            return f.write_fmt(format_args!("{}%", percent));
+        }
+        let filled = self.scaled(self.width);
+        f.write_char('[')?;
+        for index in 0..self.width {
+            f.write_char(if index < filled { '#' } else { '-' })?;
+        }
+        f.write_fmt(format_args!("] {}%", percent))
+    }
+}
@@ -0,0 +1,112 @@
+//! Distribution of some values is easier to grasp when seen as bars than as bare numbers.
+//! This module contains [Histogram] outputting key-count pairs as aligned labels followed by bars
+//! proportional to the counts in alternate mode, like `apples ████▌ 9`, or just the pairs themselves otherwise.
+//!
+//! Usage example:
+//! ```
+//! use cubob::Histogram;
+//!
+//! let fruits = [("apples", 8), ("kiwis", 3), ("pears", 4)];
+//! let histogram = Histogram::new(fruits.iter().map(|(fruit, count)| (fruit, count))).max_width(16);
+//! assert_eq!(
+//!     "apples ███████ 8\nkiwis  ██▋ 3\npears  ███▌ 4",
+//!     &format!("{:#}", histogram)
+//! );
+//! assert_eq!("{apples: 8, kiwis: 3, pears: 4}", &format!("{}", histogram));
+//! ```
+
+use crate::measure::measure;
+use core::{
+    borrow::Borrow,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+// Characters of the bar ends, taking the amount of eighths of the full block equal to their index.
+const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// Lets to output key-count pairs as histogram when outputted in alternate mode: every pair takes its own line
+/// with the key aligned to the left, the bar proportional to the count and the count itself, and the longest bar
+/// is as wide as the lines fit the width budget. Otherwise outputs the pairs in one line, like `{apples: 8, kiwis: 3}`.
+/// Pairs are iterated twice, so the iterator is cloned.
+#[cfg_attr(docsrs, doc(cfg(feature = "histogram")))]
+#[derive(Clone, Debug)]
+pub struct Histogram<I> {
+    pairs: I,
+    width: usize,
+}
+
+impl<I, K, C> Histogram<I>
+where
+    I: IntoIterator<Item = (K, C)> + Clone,
+    K: Display,
+    C: Borrow<usize>,
+{
+    /// Creates one [Histogram] examplar with width budget of 80 characters per line.
+    pub fn new(pairs: I) -> Self {
+        Self { pairs, width: 80 }
+    }
+
+    /// Makes current [Histogram] examplar fit its lines into the specified width, unless it is too small
+    /// to keep even one-character bar after the labels and the counts.
+    pub fn max_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    // Writes the bar of the specified length (in eighths of the full block).
+    fn write_bar(f: &mut Formatter<'_>, eighths: usize) -> FmtResult {
+        for _ in 0..eighths / 8 {
+            f.write_char('█')?;
+        }
+        f.write_str(EIGHTHS[eighths % 8])
+    }
+}
+
+impl<I, K, C> Display for Histogram<I>
+where
+    I: IntoIterator<Item = (K, C)> + Clone,
+    K: Display,
+    C: Borrow<usize>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !f.alternate() {
+            f.write_char('{')?;
+            for (index, (key, count)) in self.pairs.clone().into_iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                f.write_fmt(format_args!("{}: {}", key, count.borrow()))?;
+            }
+            return f.write_char('}');
+        }
+        let (label, max) =
+            self.pairs
+                .clone()
+                .into_iter()
+                .fold((0, 0), |(label, max), (key, count)| {
+                    (label.max(measure(&key, false)), max.max(*count.borrow()))
+                });
+        // Every line takes the label, the bar and the count separated with spaces.
+        let bar = self
+            .width
+            .saturating_sub(label + measure(&max, false) + 2)
+            .max(1);
+        for (index, (key, count)) in self.pairs.clone().into_iter().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            let count = *count.borrow();
+            f.write_fmt(format_args!("{}", key))?;
+            for _ in measure(&key, false)..=label {
+                f.write_char(' ')?;
+            }
+            // Counts are all zero if the maximal one is, so no bars are outputted then.
+            // Product is computed in u128 to not overflow, while the quotient is never greater than the bar width.
+            if let Some(eighths) = (count as u128 * bar as u128 * 8).checked_div(max as u128) {
+                Self::write_bar(f, eighths as usize)?;
+            }
+            f.write_fmt(format_args!(" {}", count))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,900 @@
+#[cfg(feature = "snapshot")]
+use crate::Snapshot;
+use crate::{forward::write_forwarded, measure::measure, Alternate};
+#[cfg(feature = "alloc")]
+use crate::{forward::Specifiers, measure::width};
+#[cfg(feature = "color")]
+use crate::{ColorChoice, Element, Theme};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+#[cfg(feature = "std")]
+use core::cell::Cell;
+#[cfg(feature = "alloc")]
+use core::fmt::Error;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static GUIDES: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // Settings passed to the builders created while outputting the current entry.
+    static NESTING: Cell<Nesting> = const { Cell::new(Nesting::TOP) };
+    // Settings forced for the builders by the innermost alive override guard.
+    static FORCED: Cell<Overrides> = const { Cell::new(Overrides::NONE) };
+}
+
+// Returns the settings forced for the builders created now.
+#[cfg(feature = "std")]
+fn forced() -> Overrides {
+    FORCED.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+fn forced() -> Overrides {
+    Overrides::NONE
+}
+
+// Returns the settings passed to the builder being created.
+#[cfg(feature = "std")]
+fn nesting() -> Nesting {
+    NESTING.with(Cell::get)
+}
+
+// Makes the specified settings passed to the builders created afterwards, returning the replaced ones.
+#[cfg(feature = "std")]
+fn replace_nesting(nesting: Nesting) -> Nesting {
+    NESTING.with(|current| current.replace(nesting))
+}
+
+// Without thread-local storage every builder starts like the top-level one, so depth limits, policies,
+// fill widths and themes are applied to the builder they are specified for only.
+#[cfg(not(feature = "std"))]
+fn nesting() -> Nesting {
+    Nesting::TOP
+}
+
+#[cfg(not(feature = "std"))]
+fn replace_nesting(_: Nesting) -> Nesting {
+    Nesting::TOP
+}
+
+// Width of the indentation put before every entry in prettified format.
+const INDENT_WIDTH: usize = 4;
+
+#[derive(Clone, Copy)]
+struct Nesting {
+    // Amount of nesting levels allowed, if limited.
+    levels: Option<usize>,
+    // Line width available for filling, if builder lays out its entries that way.
+    width: Option<usize>,
+    // Depth of the nested builders relative to the one which started following the policy.
+    depth: usize,
+    // Policy of choosing alternate mode depending on the depth, if followed.
+    policy: Option<AlternatePolicy>,
+    // Precision applied to the values looking like numbers, if specified for some outer builder.
+    precision: Option<usize>,
+    painter: Painter,
+}
+
+impl Nesting {
+    // Settings of the builder which is not nested into another one.
+    const TOP: Self = Self {
+        levels: None,
+        width: None,
+        depth: 0,
+        policy: None,
+        precision: None,
+        painter: Painter {
+            #[cfg(feature = "color")]
+            theme: None,
+        },
+    };
+}
+
+// Part of the output which can be styled according to the theme.
+#[derive(Clone, Copy)]
+enum Part {
+    Key,
+    Value,
+    Bracket,
+}
+
+// Styles the parts of the output according to the theme, if any.
+#[derive(Clone, Copy)]
+struct Painter {
+    #[cfg(feature = "color")]
+    theme: Option<&'static dyn Theme>,
+}
+
+impl Painter {
+    #[cfg(feature = "color")]
+    fn paint(
+        self,
+        w: &mut dyn Write,
+        part: Part,
+        write: &mut dyn FnMut(&mut dyn Write) -> FmtResult,
+    ) -> FmtResult {
+        let element = match part {
+            Part::Key => Element::Key,
+            Part::Value => Element::Value,
+            Part::Bracket => Element::Bracket,
+        };
+        match self.theme {
+            Some(theme) => theme.paint(w, element, write),
+            None => write(w),
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn paint(
+        self,
+        w: &mut dyn Write,
+        _part: Part,
+        write: &mut dyn FnMut(&mut dyn Write) -> FmtResult,
+    ) -> FmtResult {
+        write(w)
+    }
+
+    fn write_char(self, w: &mut dyn Write, c: char) -> FmtResult {
+        self.paint(w, Part::Bracket, &mut |w| w.write_char(c))
+    }
+}
+
+/// Policy of choosing [Alternate] mode for the values outputted by [StructShow][crate::StructShow]
+/// and [ListShow][crate::ListShow], either the same one for every nesting level or depending on the depth.
+#[cfg_attr(docsrs, doc(cfg(any(feature = "list", feature = "struct"))))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlternatePolicy {
+    /// The same mode for every value, while the nested builders choose their modes on their own.
+    Uniform(Alternate),
+    /// Mode depending on the nesting depth: builder created with this policy has zero depth, and the nested builders
+    /// created with [Alternate::Inherit] follow the policy too instead of inheriting the mode of the formatter.
+    ByDepth {
+        /// Mode used by the builders at the top levels.
+        top: Alternate,
+        /// Amount of the top levels.
+        levels: usize,
+        /// Mode used by the builders nested deeper than top levels.
+        below: Alternate,
+    },
+}
+
+impl AlternatePolicy {
+    /// Policy outputting values in prettified format at the specified amount of top levels and in one line below them.
+    pub const fn pretty_top(levels: usize) -> Self {
+        Self::ByDepth {
+            top: Alternate::Pretty,
+            levels,
+            below: Alternate::OneLine,
+        }
+    }
+
+    /// Returns mode used by the builder at the specified nesting depth.
+    pub fn at(&self, depth: usize) -> Alternate {
+        match *self {
+            Self::Uniform(alternate) => alternate,
+            Self::ByDepth { top, levels, .. } if depth < levels => top,
+            Self::ByDepth { below, .. } => below,
+        }
+    }
+}
+
+impl From<Alternate> for AlternatePolicy {
+    fn from(alternate: Alternate) -> Self {
+        Self::Uniform(alternate)
+    }
+}
+
+/// Indentation of the entries in prettified output of [StructShow][crate::StructShow] and [ListShow][crate::ListShow].
+#[cfg_attr(docsrs, doc(cfg(any(feature = "list", feature = "struct"))))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Indent {
+    /// Entries are indented with four spaces at every nesting level, like in prettified [Debug][core::fmt::Debug] output.
+    #[default]
+    Spaces,
+    /// Entries are indented with vertical guide `│   ` at every nesting level, so deeply nested output is easier to scan.
+    Guides,
+}
+
+impl Indent {
+    /// Makes the specified indentation used by every builder created afterwards, unless overridden for the particular one.
+    /// Since nested values create their own builders, this is the way to get the guides at every nesting level.
+    pub fn set_global(indent: Self) {
+        GUIDES.store(indent == Self::Guides, Ordering::Relaxed);
+    }
+
+    /// Returns indentation used by every newly created builder.
+    pub fn global() -> Self {
+        match GUIDES.load(Ordering::Relaxed) {
+            false => Self::Spaces,
+            true => Self::Guides,
+        }
+    }
+
+    fn text(self) -> &'static str {
+        match self {
+            Self::Spaces => "    ",
+            Self::Guides => "│   ",
+        }
+    }
+}
+
+/// Settings forced for all the [StructShow][crate::StructShow] and [ListShow][crate::ListShow] builders created
+/// on the current thread while the guard returned by [Overrides::apply] is alive, so the whole application output
+/// can follow some choice (like command line flag) with no need to pass it to every [Display] implementation.
+/// Without `std` feature nothing can be overridden, since there is no thread-local storage to keep the overrides.
+#[cfg_attr(docsrs, doc(cfg(any(feature = "list", feature = "struct"))))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    /// Alternate mode used by every builder instead of the one it is created with. Brackets are laid out
+    /// according to the forced mode too, so even the outermost struct outputted with `{}` is prettified
+    /// if [Alternate::Pretty] is forced. Modes specified for the particular entries are still regarded.
+    pub alternate: Option<Alternate>,
+    /// Indentation used by every builder instead of the global one (see [Indent::set_global]),
+    /// unless specified for the particular builder.
+    pub indent: Option<Indent>,
+}
+
+impl Overrides {
+    /// Overrides forcing nothing.
+    pub const NONE: Self = Self {
+        alternate: None,
+        indent: None,
+    };
+
+    /// Returns the overrides currently in effect on this thread.
+    pub fn current() -> Self {
+        forced()
+    }
+
+    /// Makes current [Overrides] examplar in effect on this thread until the returned guard is dropped.
+    /// Settings not specified are kept as they were forced before, if they were.
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "std", any(feature = "list", feature = "struct"))))
+    )]
+    pub fn apply(self) -> OverridesGuard {
+        let outer = forced();
+        let merged = Self {
+            alternate: self.alternate.or(outer.alternate),
+            indent: self.indent.or(outer.indent),
+        };
+        FORCED.with(|current| current.set(merged));
+        OverridesGuard {
+            outer,
+            thread_bound: PhantomData,
+        }
+    }
+}
+
+/// Guard keeping [Overrides] in effect, restoring the ones which were in effect before when dropped.
+#[cfg(feature = "std")]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "std", any(feature = "list", feature = "struct"))))
+)]
+#[must_use = "overrides are in effect only while the guard is alive"]
+#[derive(Debug)]
+pub struct OverridesGuard {
+    outer: Overrides,
+    // Overrides are kept in thread-local storage, so the guard must be dropped on the same thread.
+    thread_bound: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for OverridesGuard {
+    fn drop(&mut self) {
+        FORCED.with(|current| current.set(self.outer));
+    }
+}
+
+/// Layout of the entries in prettified output of [StructShow][crate::StructShow] and [ListShow][crate::ListShow].
+#[cfg_attr(docsrs, doc(cfg(any(feature = "list", feature = "struct"))))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Every entry is put on its own line, values are outputted in the mode chosen with [Alternate][crate::Alternate].
+    #[default]
+    Break,
+    /// Every entry is put on its own line, but its value is kept in one line if the whole entry fits the line width.
+    /// Values too wide to fit are outputted in prettified format, and the nested structs and lists among them
+    /// lay out their entries the same way within the width left after indentation.
+    Fill {
+        /// Line width (in characters) the entries should fit, including indentation.
+        width: usize,
+    },
+}
+
+// Way the builder outputs its entries, chosen once on its creation instead of being looked up for every entry.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mode {
+    OneLine,
+    Pretty,
+    Auto,
+}
+
+impl Mode {
+    pub(crate) fn of(alternate: Alternate, inherited_value: bool) -> Self {
+        match alternate {
+            Alternate::OneLine => Self::OneLine,
+            Alternate::Pretty => Self::Pretty,
+            Alternate::Inherit if inherited_value => Self::Pretty,
+            Alternate::Inherit => Self::OneLine,
+            Alternate::Auto { .. } => Self::Auto,
+        }
+    }
+}
+
+// Receiver of the entries (optional key and value) taking them instead of the output.
+#[cfg(feature = "diff")]
+pub(crate) type Collector<'a> = &'a mut dyn FnMut(Option<&dyn Display>, &dyn Display);
+
+// Outputs the entries between the brackets, either separated in one line or put on their own indented lines
+// (the same way core DebugSet and DebugList do), depending on the alternate mode of the formatter.
+pub(crate) struct Entries<'a, 'b> {
+    formatter: &'a mut Formatter<'b>,
+    open: char,
+    close: char,
+    // Whether the entries are put on their own lines, as the formatter or the forced alternate mode requires.
+    broken: bool,
+    forced: Option<Alternate>,
+    indent: Indent,
+    // Amount of nesting levels allowed inside the entries, if limited.
+    levels: Option<usize>,
+    // Line width the entries should fit, if they are laid out with filling.
+    width: Option<usize>,
+    // Whether the builder is nested deeper than allowed, so it outputs only ellipsis.
+    elided: bool,
+    // Width budget for one-line output of the values, used by builders in automatic alternate mode.
+    max_width: usize,
+    depth: usize,
+    policy: Option<AlternatePolicy>,
+    // Precision applied to the values looking like numbers, if specified.
+    precision: Option<usize>,
+    // Width, fill and alignment applied to the whole output, if the width is specified.
+    #[cfg(feature = "alloc")]
+    padding: Option<Padding>,
+    painter: Painter,
+    empty: bool,
+    result: FmtResult,
+    // Receiver of the entries taking them instead of the output, if they are collected.
+    #[cfg(feature = "diff")]
+    collector: Option<Collector<'a>>,
+    // Settings of the snapshot mode, if the entries are outputted deterministically.
+    #[cfg(feature = "snapshot")]
+    snapshot: Option<Snapshot>,
+    // Whether the entries are outputted right away even in snapshot mode, so they are not sorted.
+    #[cfg(feature = "snapshot")]
+    streamed: bool,
+    // Width budget for the whole one-line output, if the builder chooses its layout by it in automatic alternate mode.
+    #[cfg(feature = "alloc")]
+    auto: Option<usize>,
+    // Width budget for the one-line output of the value of the entry being added, if it is fitted into it.
+    #[cfg(feature = "alloc")]
+    fitting: Option<usize>,
+    // Entries rendered in snapshot or automatic alternate mode, output of which is postponed till finish.
+    #[cfg(feature = "alloc")]
+    rendered: Vec<Rendered>,
+}
+
+impl<'a, 'b> Entries<'a, 'b> {
+    pub(crate) fn new(formatter: &'a mut Formatter<'b>, open: char, close: char) -> Self {
+        let Nesting {
+            levels,
+            width,
+            depth,
+            policy,
+            precision,
+            painter,
+        } = nesting();
+        let forced = forced();
+        let broken = match forced.alternate {
+            Some(Alternate::OneLine) => false,
+            Some(Alternate::Pretty) => true,
+            _ => formatter.alternate(),
+        };
+        let precision = formatter.precision().or(precision);
+        #[cfg(feature = "alloc")]
+        let padding = Padding::of(formatter);
+        Self {
+            formatter,
+            open,
+            close,
+            broken,
+            forced: forced.alternate,
+            indent: forced.indent.unwrap_or_else(Indent::global),
+            levels: levels.map(|levels| levels.saturating_sub(1)),
+            width,
+            elided: levels == Some(0),
+            max_width: usize::MAX,
+            depth,
+            policy,
+            precision,
+            #[cfg(feature = "alloc")]
+            padding,
+            painter,
+            empty: true,
+            result: Ok(()),
+            #[cfg(feature = "diff")]
+            collector: None,
+            #[cfg(feature = "snapshot")]
+            snapshot: Snapshot::current(),
+            #[cfg(feature = "snapshot")]
+            streamed: false,
+            #[cfg(feature = "alloc")]
+            auto: None,
+            #[cfg(feature = "alloc")]
+            fitting: None,
+            #[cfg(feature = "alloc")]
+            rendered: Vec::new(),
+        }
+    }
+
+    // Makes the entries passed to the specified collector instead of being outputted.
+    #[cfg(feature = "diff")]
+    pub(crate) fn collect_into(&mut self, collector: Collector<'a>) {
+        self.collector = Some(collector);
+    }
+
+    // Returns mode the values should be outputted in, following the specified policy or the one inherited from outer builder.
+    // Inherited alternate mode is resolved regarding the formatter, forced alternate mode replaces the specified policy.
+    pub(crate) fn resolve(&mut self, policy: AlternatePolicy) -> Alternate {
+        let policy = self.forced.map_or(policy, AlternatePolicy::from);
+        let alternate = match (policy, self.policy) {
+            (AlternatePolicy::Uniform(Alternate::Inherit), Some(inherited)) => {
+                inherited.at(self.depth)
+            }
+            (AlternatePolicy::Uniform(alternate), _) => alternate,
+            (policy, _) => {
+                self.policy = Some(policy);
+                self.depth = 0;
+                policy.at(0)
+            }
+        };
+        if let Alternate::Auto { max_width } = alternate {
+            self.max_width = max_width;
+            #[cfg(feature = "alloc")]
+            {
+                self.auto = Some(max_width);
+            }
+        }
+        alternate.resolve(self.formatter)
+    }
+
+    #[cfg(feature = "color")]
+    pub(crate) fn theme(&mut self, theme: &'static dyn Theme) {
+        self.painter.theme = match ColorChoice::enabled() {
+            true => Some(theme),
+            false => None,
+        };
+    }
+
+    pub(crate) fn indent(&mut self, indent: Indent) {
+        self.indent = indent;
+    }
+
+    pub(crate) fn layout(&mut self, layout: Layout) {
+        self.width = match layout {
+            Layout::Break => None,
+            Layout::Fill { width } => Some(width),
+        };
+    }
+
+    pub(crate) fn max_depth(&mut self, depth: usize) {
+        self.levels = Some(self.levels.map_or(depth, |levels| levels.min(depth)));
+    }
+
+    // Outputs one entry the way specified by the mode.
+    pub(crate) fn entry_in(&mut self, mode: Mode, key: Option<&dyn Display>, val: &dyn Display) {
+        match mode {
+            Mode::OneLine => self.entry(key, val, false),
+            Mode::Pretty => self.entry(key, val, true),
+            Mode::Auto => self.entry_auto(key, val),
+        }
+    }
+
+    // Outputs one entry in one line if its value fits the width budget of the builder, or in prettified format otherwise.
+    pub(crate) fn entry_auto(&mut self, key: Option<&dyn Display>, val: &dyn Display) {
+        self.entry_fitting(key, val, self.max_width);
+    }
+
+    // Outputs one entry in one line if its value fits the specified width, or in prettified format otherwise.
+    pub(crate) fn entry_fitting(
+        &mut self,
+        key: Option<&dyn Display>,
+        val: &dyn Display,
+        max_width: usize,
+    ) {
+        #[cfg(feature = "alloc")]
+        if self.auto.is_some() {
+            // Value is measured while rendered, since the builder buffers its entries anyway.
+            self.fitting = Some(max_width);
+            self.entry(key, val, false);
+            self.fitting = None;
+            return;
+        }
+        if self.result.is_ok() && !self.elided {
+            let alternate = measure(val, false) > max_width;
+            self.entry(key, val, alternate);
+        }
+    }
+
+    // Outputs one entry: value preceded with the key (if any), forwarding the precision to the value if it is a number.
+    pub(crate) fn entry(&mut self, key: Option<&dyn Display>, val: &dyn Display, alternate: bool) {
+        #[cfg(feature = "diff")]
+        if let Some(collector) = self.collector.as_mut() {
+            collector(key, val);
+            return;
+        }
+        if self.result.is_ok() && !self.elided {
+            let (alternate, width) = match (self.width, self.broken) {
+                (None, _) => (alternate, None),
+                (Some(_), false) => (false, None),
+                (Some(width), true) => match fits(key, val, width) {
+                    true => (false, None),
+                    false => (true, Some(width.saturating_sub(INDENT_WIDTH))),
+                },
+            };
+            let nesting = Nesting {
+                levels: self.levels,
+                width,
+                depth: self.depth + 1,
+                policy: self.policy,
+                precision: self.precision,
+                painter: self.painter,
+            };
+            let outer = replace_nesting(nesting);
+            self.result = self.write_entry(key, val, alternate);
+            replace_nesting(outer);
+            self.empty = false;
+        }
+    }
+
+    // Returns result of the output made so far, which is failed since the first failed entry.
+    pub(crate) fn result(&self) -> FmtResult {
+        self.result
+    }
+
+    // Returns whether the entries are still outputted, which is not so after output failure or if the builder
+    // is nested deeper than allowed.
+    pub(crate) fn accepts(&self) -> bool {
+        self.result.is_ok() && !self.elided
+    }
+
+    // Returns the writer receiving the output, which passes it through the padding (if any) to the formatter.
+    fn sink(&mut self) -> Sink<'_, 'b> {
+        Sink {
+            formatter: self.formatter,
+            #[cfg(feature = "alloc")]
+            padding: self.padding.as_mut(),
+        }
+    }
+
+    // Outputs the closing bracket (preceded with the opening one if there were no entries), or only ellipsis
+    // if the builder is nested deeper than allowed, then the fill the whole output is padded with (if any).
+    pub(crate) fn finish(mut self) -> FmtResult {
+        self.result?;
+        #[cfg(feature = "alloc")]
+        self.flush()?;
+        let (painter, open, close) = (self.painter, self.open, self.close);
+        let (elided, empty) = (self.elided, self.empty);
+        let w = &mut self.sink();
+        match (elided, empty) {
+            (true, _) => painter.write_char(w, '…')?,
+            (false, true) => {
+                painter.write_char(w, open)?;
+                painter.write_char(w, close)?;
+            }
+            (false, false) => painter.write_char(w, close)?,
+        }
+        #[cfg(feature = "alloc")]
+        if let Some(padding) = self.padding.take() {
+            padding.finish(self.formatter)?;
+        }
+        Ok(())
+    }
+
+    fn write_entry(
+        &mut self,
+        key: Option<&dyn Display>,
+        val: &dyn Display,
+        alternate: bool,
+    ) -> FmtResult {
+        #[cfg(feature = "alloc")]
+        if self.buffers() {
+            let entry = self.render_entry(key, val, alternate)?;
+            self.rendered.push(entry);
+            return Ok(());
+        }
+        // Streamed entries are rendered in snapshot mode one by one and outputted right away.
+        #[cfg(feature = "snapshot")]
+        if self.snapshot.is_some() {
+            let entry = self.render_entry(key, val, alternate)?;
+            return self.write_rendered(&entry);
+        }
+        self.write_entry_with(key, val, alternate, self.precision)
+    }
+
+    // Makes the entries outputted right away instead of being rendered ahead: in snapshot mode they are not sorted,
+    // and in automatic alternate mode only their values are measured.
+    pub(crate) fn stream(&mut self) {
+        #[cfg(feature = "snapshot")]
+        {
+            self.streamed = true;
+        }
+        #[cfg(feature = "alloc")]
+        {
+            self.auto = None;
+        }
+    }
+
+    // Returns whether the entries are rendered ahead and outputted on finish: sorted in snapshot mode,
+    // or laid out after the whole one-line output is measured in automatic alternate mode.
+    #[cfg(feature = "alloc")]
+    fn buffers(&self) -> bool {
+        #[cfg(feature = "snapshot")]
+        if self.snapshot.is_some() {
+            return !self.streamed;
+        }
+        self.auto.is_some()
+    }
+
+    // Renders one entry ahead of its output.
+    #[cfg(feature = "alloc")]
+    fn render_entry(
+        &self,
+        key: Option<&dyn Display>,
+        val: &dyn Display,
+        alternate: bool,
+    ) -> Result<Rendered, Error> {
+        let key = key.map(|key| format!("{}", key));
+        #[cfg(feature = "snapshot")]
+        if let Some(snapshot) = self.snapshot {
+            if key.as_deref().is_some_and(|key| snapshot.is_volatile(key)) {
+                let val = String::from(snapshot.placeholder());
+                return Ok(Rendered {
+                    key,
+                    val,
+                    one_line: None,
+                });
+            }
+        }
+        let (val, one_line) = match self.fitting {
+            // Value is prettified if it does not fit the budget itself, but is still kept in one line
+            // if the builder is laid out so.
+            Some(max_width) => {
+                let one_line = self.render(val, false)?;
+                match width(&one_line) > max_width {
+                    true => (self.render(val, true)?, Some(one_line)),
+                    false => (one_line, None),
+                }
+            }
+            None => (self.render(val, alternate)?, None),
+        };
+        Ok(Rendered { key, val, one_line })
+    }
+
+    // Returns the output of the value, with the precision (and the one of snapshot mode, if any) applied.
+    #[cfg(feature = "alloc")]
+    fn render(&self, val: &dyn Display, alternate: bool) -> Result<String, Error> {
+        let mut text = String::new();
+        write_forwarded(val, alternate, self.precision, &mut text)?;
+        #[cfg(feature = "snapshot")]
+        if let Some(snapshot) = self.snapshot {
+            return Ok(snapshot.fixed(&text).unwrap_or(text));
+        }
+        Ok(text)
+    }
+
+    // Outputs the entries rendered ahead, sorted by their keys if snapshot mode requires so,
+    // and put on their own lines in automatic alternate mode if their one-line output exceeds the width budget.
+    #[cfg(feature = "alloc")]
+    fn flush(&mut self) -> FmtResult {
+        if !self.buffers() {
+            return Ok(());
+        }
+        #[cfg(feature = "snapshot")]
+        if self
+            .snapshot
+            .take()
+            .is_some_and(|snapshot| snapshot.sorted())
+        {
+            self.rendered.sort_by(|one, other| one.key.cmp(&other.key));
+        }
+        let rendered = core::mem::take(&mut self.rendered);
+        if let Some(max_width) = self.auto.take() {
+            self.broken = !fits_line(&rendered, max_width);
+        }
+        self.empty = true;
+        for entry in &rendered {
+            self.write_rendered(entry)?;
+            self.empty = false;
+        }
+        Ok(())
+    }
+
+    // Outputs the entry rendered ahead, with its value kept in one line if it was prettified only for the broken layout.
+    #[cfg(feature = "alloc")]
+    fn write_rendered(&mut self, entry: &Rendered) -> FmtResult {
+        let val = match (self.broken, &entry.one_line) {
+            (false, Some(one_line)) => one_line,
+            _ => &entry.val,
+        };
+        // Values are rendered with the precision applied already.
+        let key = entry.key.as_ref().map(|key| key as &dyn Display);
+        self.write_entry_with(key, val, false, None)
+    }
+
+    fn write_entry_with(
+        &mut self,
+        key: Option<&dyn Display>,
+        val: &dyn Display,
+        alternate: bool,
+        precision: Option<usize>,
+    ) -> FmtResult {
+        let (painter, open, indent) = (self.painter, self.open, self.indent.text());
+        let (empty, broken) = (self.empty, self.broken);
+        let w = &mut self.sink();
+        match empty {
+            true => painter.write_char(w, open)?,
+            false if !broken => {
+                painter.write_char(w, ',')?;
+                w.write_char(' ')?;
+            }
+            false => (),
+        }
+        if !broken {
+            return write_entry(w, key, val, alternate, precision, painter);
+        }
+        if empty {
+            w.write_char('\n')?;
+        }
+        let mut writer = IndentWriter {
+            inner: w,
+            indent,
+            fresh: true,
+        };
+        write_entry(&mut writer, key, val, alternate, precision, painter)?;
+        painter.write_char(&mut writer, ',')?;
+        writer.write_char('\n')
+    }
+}
+
+// Writer the output of the builder goes through: it is passed to the formatter through the padding, if any.
+struct Sink<'s, 'b> {
+    formatter: &'s mut Formatter<'b>,
+    #[cfg(feature = "alloc")]
+    padding: Option<&'s mut Padding>,
+}
+
+impl<'s, 'b> Write for Sink<'s, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        #[cfg(feature = "alloc")]
+        if let Some(padding) = self.padding.as_mut() {
+            return padding.write(self.formatter, s);
+        }
+        self.formatter.write_str(s)
+    }
+}
+
+// Pads the whole output up to the width as the format specifiers require. Only the output which may still need fill
+// before it is postponed: nothing is for the left alignment, and no more than the width is for the others.
+#[cfg(feature = "alloc")]
+struct Padding {
+    specifiers: Specifiers,
+    width: usize,
+    written: usize,
+    pending: Option<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl Padding {
+    fn of(formatter: &Formatter<'_>) -> Option<Self> {
+        let specifiers = Specifiers::of(formatter);
+        Some(Self {
+            specifiers,
+            width: specifiers.width?,
+            written: 0,
+            pending: specifiers.fills_before().then(String::new),
+        })
+    }
+
+    fn write(&mut self, w: &mut dyn Write, s: &str) -> FmtResult {
+        self.written += width(s);
+        if let Some(pending) = self.pending.as_mut() {
+            pending.push_str(s);
+            // Output wider than the width needs no fill, so it is not postponed anymore.
+            if self.written > self.width {
+                w.write_str(pending)?;
+                self.pending = None;
+            }
+            return Ok(());
+        }
+        w.write_str(s)
+    }
+
+    fn finish(self, w: &mut dyn Write) -> FmtResult {
+        let (before, after) = self
+            .specifiers
+            .spread(self.width.saturating_sub(self.written));
+        self.specifiers.write_fill(w, before)?;
+        if let Some(pending) = self.pending {
+            w.write_str(&pending)?;
+        }
+        self.specifiers.write_fill(w, after)
+    }
+}
+
+// Entry rendered ahead of its output: the key (if any), the value and the one-line output of the value,
+// if the value is prettified only when the builder is not laid out in one line.
+#[cfg(feature = "alloc")]
+struct Rendered {
+    key: Option<String>,
+    val: String,
+    one_line: Option<String>,
+}
+
+// Checks whether the entries outputted in one line between the brackets fit the specified width.
+#[cfg(feature = "alloc")]
+fn fits_line(rendered: &[Rendered], max_width: usize) -> bool {
+    let mut total = 2 + 2 * rendered.len().saturating_sub(1);
+    for entry in rendered {
+        let val = entry.one_line.as_ref().unwrap_or(&entry.val);
+        if val.contains('\n') {
+            return false;
+        }
+        total += entry.key.as_deref().map_or(0, |key| width(key) + 2) + width(val);
+    }
+    total <= max_width
+}
+
+// Checks whether the entry outputted in one line fits the specified line width along with indentation and comma after it.
+fn fits(key: Option<&dyn Display>, val: &dyn Display, width: usize) -> bool {
+    let key = key.map_or(0, |key| measure(key, false) + 2);
+    INDENT_WIDTH + key + measure(val, false) < width
+}
+
+fn write_entry(
+    w: &mut dyn Write,
+    key: Option<&dyn Display>,
+    val: &dyn Display,
+    alternate: bool,
+    precision: Option<usize>,
+    painter: Painter,
+) -> FmtResult {
+    if let Some(key) = key {
+        painter.paint(w, Part::Key, &mut |w| w.write_fmt(format_args!("{}", key)))?;
+        w.write_str(": ")?;
+    }
+    painter.paint(w, Part::Value, &mut |w| {
+        write_forwarded(val, alternate, precision, w)
+    })
+}
+
+// Puts the indentation at the start of every line written into it.
+struct IndentWriter<'a> {
+    inner: &'a mut dyn Write,
+    indent: &'static str,
+    fresh: bool,
+}
+
+impl<'a> Write for IndentWriter<'a> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for line in s.split_inclusive('\n') {
+            if self.fresh {
+                self.inner.write_str(self.indent)?;
+            }
+            self.fresh = line.ends_with('\n');
+            self.inner.write_str(line)?;
+        }
+        Ok(())
+    }
+}
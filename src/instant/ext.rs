@@ -0,0 +1,28 @@
+use super::{InstantSeparated, Passage};
+use core::fmt::Display;
+
+/// Extension trait letting to get ready-to-output adapters right from any cloneable iterator.
+/// Implemented for every [Iterator]+[Clone] type.
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
+pub trait DisplayIterExt: Iterator + Clone + Sized {
+    /// Returns adapter outputting the iterator items joined with the specified separator.
+    fn display_separated<S: Display>(self, separator: S) -> InstantSeparated<Self, Passage, S> {
+        InstantSeparated::new(self, separator)
+    }
+
+    /// Returns adapter outputting the iterator items as list with [Alternate::Inherit][crate::Alternate::Inherit] mode.
+    #[cfg(feature = "list")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "list", feature = "instant"))))]
+    fn display_list(self) -> crate::InstantList<Self, Passage> {
+        crate::InstantList::inherit(self)
+    }
+
+    /// Returns adapter outputting the iterator items as struct fields with [Alternate::Inherit][crate::Alternate::Inherit] mode.
+    #[cfg(feature = "struct")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "struct", feature = "instant"))))]
+    fn display_struct(self) -> crate::InstantStruct<Self, Passage> {
+        crate::InstantStruct::inherit(self)
+    }
+}
+
+impl<I: Iterator + Clone> DisplayIterExt for I {}
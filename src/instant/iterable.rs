@@ -5,26 +5,17 @@
 /// automatically implements [IntoIterator], and [Copy] implementation requires [Clone] implementation too, such
 /// type will suit both alternatives and will cause conflict until explicit [Kind] specified.
 /// Trait is not sealed, so any user can define own kind and use it along with own [Iterable] implementation for that kind.
-#[cfg_attr(
-    docsrs,
-    doc(cfg(all(any(feature = "list", feature = "struct"), feature = "instant")))
-)]
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
 pub trait Kind {}
 
 /// Type implementing [Kind] and used to mark types which are treated as [IntoIterator] and _not_ [Iterator].
-#[cfg_attr(
-    docsrs,
-    doc(cfg(all(any(feature = "list", feature = "struct"), feature = "instant")))
-)]
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
 pub struct Source;
 
 impl Kind for Source {}
 
 /// Type implementing [Kind] and used to mark types which are treated as [Iterator] and _not_ [IntoIterator].
-#[cfg_attr(
-    docsrs,
-    doc(cfg(all(any(feature = "list", feature = "struct"), feature = "instant")))
-)]
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
 pub struct Passage;
 
 impl Kind for Passage {}
@@ -35,10 +26,7 @@ impl Kind for Passage {}
 /// automatically implements [IntoIterator], and [Copy] implementation requires [Clone] implementation too, such
 /// type will suit both alternatives and will cause conflict until explicit [Kind] specified.
 /// Trait is not sealed, so any user can define own Iterable implementation.
-#[cfg_attr(
-    docsrs,
-    doc(cfg(all(any(feature = "list", feature = "struct"), feature = "instant")))
-)]
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
 pub trait Iterable<K: Kind> {
     type Iter: Iterator;
 
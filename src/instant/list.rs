@@ -44,14 +44,17 @@ where
     <I::Iter as Iterator>::Item: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        ListShow::new(f, self.alt)
-            .items_from_iter(self.val.iter())
-            .finish()
+        let mut show = ListShow::new(f, self.alt);
+        show.items_from_iter(self.val.iter());
+        show.finish()
     }
 }
 
 #[cfg(feature = "embed")]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "embed", feature = "list", feature = "instant"))))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "embed", feature = "list", feature = "instant")))
+)]
 impl<I, K> crate::EmbedList for InstantList<I, K>
 where
     K: Kind,
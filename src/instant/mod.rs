@@ -22,23 +22,25 @@
 //!
 //! impl Display for Object {
 //!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-//!         StructShow::inherit(f)
+//!         let mut show = StructShow::inherit(f);
+//!         show
 //!             .field(&"title", &self.title)
 //!             .field_opt(&"description", &self.description)
 //!             // self.properties field can be displayed as struct without any self-made helpers
-//!             .field(&"properties", &InstantStruct::inherit(&self.properties))
-//!             .finish()
+//!             .field(&"properties", &InstantStruct::inherit(&self.properties));
+//!         show.finish()
 //!     }
 //! }
 //!
 //! impl Display for Space {
 //!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-//!         StructShow::inherit(f)
+//!         let mut show = StructShow::inherit(f);
+//!         show
 //!             // self.tags field can be displayed as list without any self-made helpers
 //!             .field_override(&"tags", &InstantList::inherit(&self.tags), Alternate::OneLine)
 //!             // self.members field can be displayed as struct since Object provides Display implementation
-//!             .field(&"members", &InstantStruct::inherit(&self.members))
-//!             .finish()
+//!             .field(&"members", &InstantStruct::inherit(&self.members));
+//!         show.finish()
 //!     }
 //! }
 //!
@@ -72,19 +74,20 @@
 //! println!("Prettified: {:#}", space);
 //! ```
 
-#[cfg(any(feature = "list", feature = "struct"))]
-#[cfg_attr(docsrs, doc(cfg(any(feature = "list", feature = "struct"))))]
+mod ext;
 mod iterable;
 #[cfg(feature = "list")]
 #[cfg_attr(docsrs, doc(cfg(feature = "list")))]
 mod list;
+mod separated;
 #[cfg(feature = "struct")]
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 mod r#struct;
 
-#[cfg(any(feature = "list", feature = "struct"))]
+pub use ext::*;
 pub use iterable::*;
 #[cfg(feature = "list")]
 pub use list::*;
 #[cfg(feature = "struct")]
 pub use r#struct::*;
+pub use separated::*;
@@ -0,0 +1,53 @@
+use super::{Iterable, Kind};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+    marker::PhantomData,
+};
+
+/// Struct used to simplify displaying of any iterable data as items joined with the specified separator,
+/// without any surrounding brackets. Alternate mode is propagated to every item.
+#[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
+pub struct InstantSeparated<I, K, S> {
+    val: I,
+    separator: S,
+    _kind: PhantomData<K>,
+}
+
+impl<I, K, S> InstantSeparated<I, K, S>
+where
+    K: Kind,
+    I: Iterable<K>,
+    S: Display,
+{
+    /// Creates InstantSeparated examplar with the specified separator.
+    pub fn new(val: I, separator: S) -> Self {
+        Self {
+            val,
+            separator,
+            _kind: PhantomData,
+        }
+    }
+}
+
+impl<I, K, S> Display for InstantSeparated<I, K, S>
+where
+    K: Kind,
+    I: Iterable<K>,
+    <I::Iter as Iterator>::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        for (index, item) in self.val.iter().enumerate() {
+            if index > 0 {
+                f.write_fmt(format_args!("{}", self.separator))?;
+            }
+            match alternate {
+                true => f.write_fmt(format_args!("{:#}", item))?,
+                false => f.write_fmt(format_args!("{}", item))?,
+            }
+        }
+        Ok(())
+    }
+}
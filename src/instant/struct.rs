@@ -44,14 +44,17 @@ where
     <I::Iter as Iterator>::Item: DisplayPair,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        StructShow::new(f, self.alt)
-            .fields_from_iter(self.val.iter())
-            .finish()
+        let mut show = StructShow::new(f, self.alt);
+        show.fields_from_iter(self.val.iter());
+        show.finish()
     }
 }
 
 #[cfg(feature = "embed")]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "embed", feature = "struct", feature = "instant"))))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "embed", feature = "struct", feature = "instant")))
+)]
 impl<I, K> crate::EmbedStruct for InstantStruct<I, K>
 where
     K: Kind,
@@ -0,0 +1,91 @@
+//! Output written into files, sockets or locked standard output doesn't need intermediate [String] at all.
+//! This module contains [IoWriter] adapter letting to output cubob-formatted values directly
+//! into any [std::io::Write] implementing type, keeping the original I/O error on failure,
+//! along with [write_struct], [write_list] and [write_display] routines doing so.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{write_struct, Alternate};
+//!
+//! let mut output = Vec::new();
+//! write_struct(&mut output, &[(&"x", &1), (&"y", &2)], Alternate::OneLine).unwrap();
+//! assert_eq!(b"{x: 1, y: 2}", output.as_slice());
+//! ```
+
+use crate::{forward::write_rendered, Alternate};
+use core::fmt::{Display, Result as FmtResult, Write};
+use std::io::{Error as IoError, Result as IoResult, Write as IoWrite};
+
+/// Lets to output formatted values into any [std::io::Write] implementing type.
+/// The I/O error making the output fail is kept, so it can be reported instead of bare [core::fmt::Error].
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct IoWriter<W> {
+    inner: W,
+    error: Option<IoError>,
+}
+
+impl<W: IoWrite> IoWriter<W> {
+    /// Creates one [IoWriter] examplar writing into the specified writer.
+    pub fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Returns the I/O error which made the last output fail, if any.
+    pub fn take_error(&mut self) -> Option<IoError> {
+        self.error.take()
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Outputs the specified value, in prettified format if [Alternate::Pretty] is specified.
+    /// Failure of the value output itself is reported as [std::io::ErrorKind::Other] error.
+    pub fn write_display(&mut self, val: &dyn Display, alternate: Alternate) -> IoResult<()> {
+        write_rendered(val, alternate, self).map_err(|_| {
+            self.take_error()
+                .unwrap_or_else(|| IoError::other("formatting failed"))
+        })
+    }
+}
+
+impl<W: IoWrite> Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            core::fmt::Error
+        })
+    }
+}
+
+/// Outputs the specified value into the specified writer, in prettified format if [Alternate::Pretty] is specified.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_display<W: IoWrite>(w: W, val: &dyn Display, alternate: Alternate) -> IoResult<()> {
+    IoWriter::new(w).write_display(val, alternate)
+}
+
+/// Performs the whole struct output routine into the specified writer, with no need to define type implementing [Display].
+/// Output is prettified if [Alternate::Pretty] is specified.
+#[cfg(feature = "struct")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "struct"))))]
+pub fn write_struct<W: IoWrite>(
+    w: W,
+    fields: &[(&dyn Display, &dyn Display)],
+    alternate: Alternate,
+) -> IoResult<()> {
+    write_display(
+        w,
+        &crate::r#struct::StructOutput { fields, alternate },
+        alternate,
+    )
+}
+
+/// Performs the whole list output routine into the specified writer, with no need to define type implementing [Display].
+/// Output is prettified if [Alternate::Pretty] is specified.
+#[cfg(feature = "list")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "list"))))]
+pub fn write_list<W: IoWrite>(w: W, items: &[&dyn Display], alternate: Alternate) -> IoResult<()> {
+    write_display(w, &crate::list::ListOutput { items, alternate }, alternate)
+}
@@ -0,0 +1,94 @@
+//! JSON blobs of arbitrary shape are often kept in memory as [Value] trees and logged along with other data.
+//! This module contains [Json] adapter outputting such trees the way [StructShow] and [ListShow] do:
+//! objects as structs, arrays as lists, and strings, numbers, booleans and nulls as they are.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{Alternate, Json};
+//! use serde_json::json;
+//!
+//! let value = json!({"name": "demo", "sizes": [1, 2], "extra": null});
+//! assert_eq!(
+//!     "{extra: null, name: demo, sizes: [1, 2]}",
+//!     &format!("{}", Json::inherit(&value).sorted())
+//! );
+//! assert_eq!(
+//!     "{\n    extra: null,\n    name: demo,\n    sizes: [\n        1,\n        2,\n    ],\n}",
+//!     &format!("{:#}", Json::inherit(&value).sorted())
+//! );
+//! ```
+
+use crate::{Alternate, ListShow, StructShow};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use serde_json::Value;
+
+/// Lets to output [Value] tree regarding the specified alternate mode, with nested values
+/// outputted with [Alternate::Inherit] setting.
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+#[derive(Clone, Copy, Debug)]
+pub struct Json<'a> {
+    value: &'a Value,
+    alternate: Alternate,
+    sorted: bool,
+}
+
+impl<'a> Json<'a> {
+    /// Creates one [Json] examplar outputting the specified value in the specified alternate mode.
+    pub fn new(value: &'a Value, alternate: Alternate) -> Self {
+        Self {
+            value,
+            alternate,
+            sorted: false,
+        }
+    }
+
+    /// Creates one [Json] examplar outputting the specified value with [Alternate::Inherit] setting.
+    pub fn inherit(value: &'a Value) -> Self {
+        Self::new(value, Alternate::Inherit)
+    }
+
+    /// Makes current [Json] examplar output the keys of every object in lexicographical order
+    /// instead of the order they are stored in (which is insertion order if `preserve_order` feature
+    /// of `serde_json` is enabled).
+    pub fn sorted(mut self) -> Self {
+        self.sorted = true;
+        self
+    }
+
+    // Creates the examplar for the nested value, keeping the keys order setting.
+    fn nested(&self, value: &'a Value) -> Self {
+        Self {
+            value,
+            alternate: Alternate::Inherit,
+            sorted: self.sorted,
+        }
+    }
+}
+
+impl<'a> Display for Json<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.value {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => Display::fmt(value, f),
+            Value::Number(value) => Display::fmt(value, f),
+            Value::String(value) => f.write_str(value),
+            Value::Array(values) => {
+                let mut show = ListShow::new(f, self.alternate);
+                show.items_from_iter(values.iter().map(|value| self.nested(value)));
+                show.finish()
+            }
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                if self.sorted {
+                    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+                }
+                let mut show = StructShow::new(f, self.alternate);
+                for (key, value) in entries {
+                    show.field(key, &self.nested(value));
+                }
+                show.finish()
+            }
+        }
+    }
+}
@@ -42,46 +42,156 @@
 //!println!("One-line: {}", line);
 //!println!("Prettified: {:#}", line);
 //! ```
+//!
+//! The crate is `no_std` compatible: with default `std` feature disabled it depends on `core` only,
+//! and on `alloc` if `alloc` feature (or any feature requiring it) is enabled. Without `std` the settings
+//! of the builders (like depth limit, layout or theme) are not propagated into nested builders,
+//! since there is no thread-local storage to pass them through.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+mod color;
+#[cfg(feature = "custom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom")))]
+mod custom;
+#[cfg(feature = "diff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+mod diff;
 #[cfg(feature = "embed")]
 #[cfg_attr(docsrs, doc(cfg(feature = "embed")))]
 mod embed;
+#[cfg(feature = "list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+mod error;
 #[cfg(feature = "field")]
 #[cfg_attr(docsrs, doc(cfg(feature = "field")))]
 mod field;
+#[cfg(any(feature = "field", feature = "list", feature = "struct"))]
+mod forward;
+#[cfg(feature = "gauge")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gauge")))]
+mod gauge;
+#[cfg(feature = "histogram")]
+#[cfg_attr(docsrs, doc(cfg(feature = "histogram")))]
+mod histogram;
+#[cfg(any(feature = "list", feature = "struct"))]
+mod indent;
 #[cfg(feature = "instant")]
 #[cfg_attr(docsrs, doc(cfg(feature = "instant")))]
 mod instant;
+#[cfg(all(feature = "std", any(feature = "list", feature = "struct")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod io;
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+mod json;
 #[cfg(feature = "list")]
 #[cfg_attr(docsrs, doc(cfg(feature = "list")))]
 mod list;
+#[cfg(any(
+    feature = "custom",
+    feature = "field",
+    feature = "histogram",
+    feature = "list",
+    feature = "struct",
+    feature = "table",
+    feature = "wrap"
+))]
+mod measure;
 #[cfg(feature = "struct")]
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 mod pair;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod serialize;
+#[cfg(feature = "snapshot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+mod snapshot;
 #[cfg(feature = "struct")]
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 mod r#struct;
+#[cfg(feature = "table")]
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+mod table;
+#[cfg(feature = "test-support")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-support")))]
+mod testing;
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+mod trace;
+#[cfg(feature = "tree")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tree")))]
+mod tree;
+#[cfg(feature = "struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
+mod triple;
+mod via;
+#[cfg(feature = "wrap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+mod wrap;
 
 #[cfg(all(test, feature = "list", feature = "struct", feature = "field"))]
 mod tests;
 
+#[cfg(feature = "color")]
+pub use color::*;
+#[cfg(feature = "custom")]
+pub use custom::*;
+#[cfg(feature = "diff")]
+pub use diff::*;
 #[cfg(feature = "embed")]
 pub use embed::*;
+#[cfg(feature = "list")]
+pub use error::*;
 #[cfg(feature = "field")]
 pub use field::*;
+#[cfg(feature = "gauge")]
+pub use gauge::*;
+#[cfg(feature = "histogram")]
+pub use histogram::*;
+#[cfg(all(feature = "std", any(feature = "list", feature = "struct")))]
+pub use indent::OverridesGuard;
+#[cfg(any(feature = "list", feature = "struct"))]
+pub use indent::{AlternatePolicy, Indent, Layout, Overrides};
 #[cfg(feature = "instant")]
 pub use instant::*;
+#[cfg(all(feature = "std", any(feature = "list", feature = "struct")))]
+pub use io::*;
+#[cfg(feature = "json")]
+pub use json::*;
 #[cfg(feature = "list")]
 pub use list::*;
 #[cfg(feature = "struct")]
 pub use pair::*;
 #[cfg(feature = "struct")]
 pub use r#struct::*;
+#[cfg(feature = "serde")]
+pub use serialize::*;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+#[cfg(feature = "table")]
+pub use table::*;
+#[cfg(feature = "test-support")]
+pub use testing::*;
+#[cfg(feature = "tracing")]
+pub use trace::*;
+#[cfg(feature = "tree")]
+pub use tree::*;
+#[cfg(feature = "struct")]
+pub use triple::*;
+pub use via::*;
+#[cfg(feature = "wrap")]
+pub use wrap::*;
 
-/// Alternate mode to use while outputting.
+/// Alternate mode to use while outputting. More modes may be added later, so matching it needs wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Alternate {
     /// Output data in one line (matches [Formatter][core::fmt::Formatter]::alternate() == false).
     OneLine,
@@ -89,4 +199,36 @@ pub enum Alternate {
     Pretty,
     /// Output data in format regarding alternate mode of given [Formatter][core::fmt::Formatter] examplar.
     Inherit,
+    /// Output data in one line if it fits the specified width (in characters), or in prettified format otherwise.
+    /// Builders output in this mode buffer their entries and measure their whole one-line output (brackets included),
+    /// putting the entries on their own lines if it exceeds the width, with the values which fit it kept in one line.
+    /// Without `alloc` feature only the values are measured, every one being formatted one more time to do so.
+    Auto {
+        /// Width budget for the one-line output.
+        max_width: usize,
+    },
+}
+
+impl Alternate {
+    /// Returns the mode [Alternate::Inherit] turns into while outputting with the given formatter,
+    /// or the current mode itself if it is not [Alternate::Inherit].
+    pub fn resolve(self, f: &core::fmt::Formatter<'_>) -> Self {
+        match (self, f.alternate()) {
+            (Self::Inherit, false) => Self::OneLine,
+            (Self::Inherit, true) => Self::Pretty,
+            (alternate, _) => alternate,
+        }
+    }
+}
+
+/// Alignment of the output within the space bigger than needed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    /// Output is put at the start of the space, the rest is filled after it.
+    #[default]
+    Left,
+    /// Output is put at the end of the space, the rest is filled before it.
+    Right,
+    /// Output is put at the middle of the space, the rest is filled at both sides of it.
+    Center,
 }
@@ -1,80 +1,95 @@
-use crate::Alternate;
-use core::{
-    fmt::{DebugList, Display, Formatter, Result as FmtResult},
-    format_args,
+#[cfg(feature = "alloc")]
+use crate::forward::render;
+use crate::{
+    indent::{Entries, Mode},
+    Alternate, AlternatePolicy, Indent, Layout,
 };
-
-type ListEntrier = fn(&mut DebugList<'_, '_>, &dyn Display);
-
-fn usual_list_entrier(w: &mut DebugList, v: &dyn Display) {
-    w.entry(&format_args!("{}", v));
-}
-
-fn alternative_list_entrier(w: &mut DebugList, v: &dyn Display) {
-    w.entry(&format_args!("{:#}", v));
-}
-
-fn null_list_entrier(_: &mut DebugList, _: &dyn Display) {}
-
-fn inherit_entrier(inherited_value: bool) -> ListEntrier {
-    match inherited_value {
-        false => usual_list_entrier,
-        true => alternative_list_entrier,
-    }
-}
+use core::fmt::{Display, Error as FmtError, Formatter, Result as FmtResult};
 
 /// Lets to output some listed data regarding the propagated value of output alternativeness.
 #[cfg_attr(docsrs, doc(cfg(feature = "list")))]
 pub struct ListShow<'a, 'b> {
-    wrapper: DebugList<'a, 'b>,
-    entrier: ListEntrier,
+    wrapper: Entries<'a, 'b>,
+    mode: Mode,
+    alternate: Alternate,
     inherited_value: bool,
 }
 
 impl<'a, 'b> ListShow<'a, 'b> {
-    fn choose_entrier(alternate: Alternate, inherited_value: bool) -> ListEntrier {
-        match alternate {
-            Alternate::OneLine => usual_list_entrier,
-            Alternate::Pretty => alternative_list_entrier,
-            Alternate::Inherit => inherit_entrier(inherited_value),
-        }
-    }
-
-    /// Creates one [ListShow] examplar starting its output.
-    pub fn new(formatter: &'a mut Formatter<'b>, alternate: Alternate) -> Self {
+    /// Creates one [ListShow] examplar starting its output. Accepts either [Alternate] mode or [AlternatePolicy].
+    pub fn new<A: Into<AlternatePolicy>>(formatter: &'a mut Formatter<'b>, alternate: A) -> Self {
         let inherited_value = formatter.alternate();
-        let entrier = Self::choose_entrier(alternate, inherited_value);
+        let mut wrapper = Entries::new(formatter, '[', ']');
+        let alternate = wrapper.resolve(alternate.into());
         Self {
-            wrapper: formatter.debug_list(),
-            entrier,
+            wrapper,
+            mode: Mode::of(alternate, inherited_value),
+            alternate,
             inherited_value,
         }
     }
 
     /// Creates one [ListShow] examplar with [Alternate::Inherit] setting and starts its output.
     pub fn inherit(formatter: &'a mut Formatter<'b>) -> Self {
-        let inherited_value = formatter.alternate();
-        let entrier = inherit_entrier(inherited_value);
-        Self {
-            wrapper: formatter.debug_list(),
-            entrier,
-            inherited_value,
-        }
+        Self::new(formatter, Alternate::Inherit)
+    }
+
+    /// Makes current [ListShow] examplar style its output (and output of the nested structs and lists) with the specified theme,
+    /// unless the colored output is not allowed according to [ColorChoice][crate::ColorChoice].
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn theme(&mut self, theme: &'static dyn crate::Theme) -> &mut Self {
+        self.wrapper.theme(theme);
+        self
+    }
+
+    /// Makes current [ListShow] examplar indent its items in prettified format as specified,
+    /// instead of the globally set way (see [Indent::set_global]).
+    pub fn indent(&mut self, indent: Indent) -> &mut Self {
+        self.wrapper.indent(indent);
+        self
+    }
+
+    /// Makes current [ListShow] examplar lay out its entries in prettified format as specified.
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.wrapper.layout(layout);
+        self
+    }
+
+    /// Makes current [ListShow] examplar output nested structs and lists (ones outputted by builders of this crate)
+    /// only up to the specified depth below it, outputting `…` instead of the deeper ones. Zero depth means that
+    /// even its own items are outputted only if they are not structs or lists themselves.
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.wrapper.max_depth(depth);
+        self
+    }
+
+    // Makes the items passed to the specified collector instead of being outputted.
+    #[cfg(feature = "diff")]
+    pub(crate) fn collect_into(&mut self, collector: crate::indent::Collector<'a>) {
+        self.wrapper.collect_into(collector);
     }
 
     /// Adds one item to the list output.
     pub fn item(&mut self, val: &dyn Display) -> &mut Self {
-        (self.entrier)(&mut self.wrapper, val);
+        self.wrapper.entry_in(self.mode, None, val);
         self
     }
 
+    /// Adds one item to the list output, returning error right away if its output
+    /// (or output of any item before it) failed, so the `?` operator stops at the failed item.
+    pub fn try_item(&mut self, val: &dyn Display) -> Result<&mut Self, FmtError> {
+        self.item(val);
+        self.wrapper.result().map(|_| self)
+    }
+
     /// Adds one item to the list output.
     pub fn item_override(&mut self, val: &dyn Display, alternate: Alternate) -> &mut Self {
-        // Safety: since only specified subset of predefined functions can take place in self.entrier,
-        // and null_list_entrier is one of them, the comparison through pointer values is safe enough.
-        if null_list_entrier as usize != self.entrier as usize {
-            let entrier = Self::choose_entrier(alternate, self.inherited_value);
-            entrier(&mut self.wrapper, val);
+        match alternate {
+            Alternate::Auto { max_width } => self.wrapper.entry_fitting(None, val, max_width),
+            _ => self
+                .wrapper
+                .entry_in(Mode::of(alternate, self.inherited_value), None, val),
         }
         self
     }
@@ -99,9 +114,8 @@ impl<'a, 'b> ListShow<'a, 'b> {
         self
     }
 
-    /// Finishes the list output, returning the result.
-    pub fn finish(&mut self) -> FmtResult {
-        self.entrier = null_list_entrier;
+    /// Finishes the list output, returning the result. Consumes the builder, so no entries can be added after it.
+    pub fn finish(self) -> FmtResult {
         self.wrapper.finish()
     }
 
@@ -116,7 +130,7 @@ impl<'a, 'b> ListShow<'a, 'b> {
         T: Display + 'c,
         I: Iterator<Item = T> + 'c,
     {
-        items.for_each(|val| (self.entrier)(&mut self.wrapper, &val));
+        items.for_each(|val| self.wrapper.entry_in(self.mode, None, &val));
         self
     }
 
@@ -124,6 +138,12 @@ impl<'a, 'b> ListShow<'a, 'b> {
     pub fn alternate(&self) -> bool {
         self.inherited_value
     }
+
+    /// Returns mode the values are outputted in, with [Alternate::Inherit] and [AlternatePolicy] resolved,
+    /// so the nested outputs can follow the same decisions as the list itself.
+    pub fn resolved_alternate(&self) -> Alternate {
+        self.alternate
+    }
 }
 
 /// Performs the whole list output routine from creation of [ListShow] examplar to finishing.
@@ -141,7 +161,59 @@ where
     T: Display + 'c,
     I: Iterator<Item = T> + 'c,
 {
-    ListShow::new(f, Alternate::Inherit)
-        .items_from_iter(items)
-        .finish()
+    let mut show = ListShow::new(f, Alternate::Inherit);
+    show.items_from_iter(items);
+    show.finish()
+}
+
+/// Performs the whole list output routine from creation of [ListShow] examplar to finishing, streaming the items:
+/// every item is outputted right after it is taken from the iterator and is never buffered, and no more items
+/// are taken once the output fails or turns out to be nested deeper than allowed. So any amount of items
+/// can be dumped with bounded memory: in automatic alternate mode every item is measured alone instead of
+/// the whole list, and the output padded up to some width with right or center alignment is postponed only
+/// while it is narrower than the width. Always inherits alternate mode.
+#[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+pub fn display_list_streamed<I>(f: &mut Formatter<'_>, items: I) -> FmtResult
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut show = ListShow::new(f, Alternate::Inherit);
+    show.wrapper.stream();
+    let mut items = items.into_iter();
+    while show.wrapper.accepts() {
+        let item = match items.next() {
+            Some(item) => item,
+            None => break,
+        };
+        show.item(&item);
+    }
+    show.finish()
+}
+
+// Outputs the items in the specified alternate mode, so they can be rendered into string or any writer.
+#[cfg(feature = "alloc")]
+pub(crate) struct ListOutput<'a> {
+    pub(crate) items: &'a [&'a dyn Display],
+    pub(crate) alternate: Alternate,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Display for ListOutput<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut show = ListShow::new(f, self.alternate);
+        show.items(self.items);
+        show.finish()
+    }
+}
+
+/// Performs the whole list output routine into new string, with no need to define type implementing [Display].
+/// Output is prettified if [Alternate::Pretty] is specified.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "list", feature = "alloc"))))]
+pub fn display_list_to_string(
+    items: &[&dyn Display],
+    alternate: Alternate,
+) -> alloc::string::String {
+    render(&ListOutput { items, alternate }, alternate)
 }
@@ -0,0 +1,106 @@
+use core::{
+    fmt::{Display, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Counts characters of the output written into it, discarding the output itself.
+/// With `unicode` feature enabled, terminal cells taken by the output are counted instead, so wide characters
+/// (like emoji) count twice and combining ones are not counted at all.
+/// With `color` feature enabled, ANSI escape sequences are not counted, since they take no place on the screen.
+#[derive(Default)]
+pub(crate) struct WriteCounter {
+    pub(crate) chars: usize,
+    #[cfg(feature = "color")]
+    escape: Escape,
+}
+
+#[cfg(not(feature = "color"))]
+impl Write for WriteCounter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.chars += width(s);
+        Ok(())
+    }
+}
+
+// Position of the written output relative to ANSI escape sequence.
+#[cfg(feature = "color")]
+#[derive(Default)]
+enum Escape {
+    #[default]
+    Outside,
+    Started,
+    Control,
+}
+
+#[cfg(feature = "color")]
+impl Write for WriteCounter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        // Start of the current run of characters outside escape sequences, which are measured all at once.
+        let mut start = None;
+        for (index, c) in s.char_indices() {
+            let (escape, visible) = match (&self.escape, c) {
+                (Escape::Outside, '\x1b') => (Escape::Started, false),
+                (Escape::Outside, _) => (Escape::Outside, true),
+                (Escape::Started, '[') => (Escape::Control, false),
+                (Escape::Started, _) => (Escape::Outside, false),
+                (Escape::Control, '\x40'..='\x7e') => (Escape::Outside, false),
+                (Escape::Control, _) => (Escape::Control, false),
+            };
+            self.escape = escape;
+            match (visible, start) {
+                (true, None) => start = Some(index),
+                (false, Some(from)) => {
+                    self.chars += width(&s[from..index]);
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+        if let Some(from) = start {
+            self.chars += width(&s[from..]);
+        }
+        Ok(())
+    }
+}
+
+/// Returns amount of characters in the specified text.
+#[cfg(not(feature = "unicode"))]
+pub(crate) fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Returns amount of terminal cells the specified text takes.
+#[cfg(feature = "unicode")]
+pub(crate) fn width(s: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(s)
+}
+
+/// Returns amount of characters the specified character is counted as, which is always 1.
+#[cfg(all(
+    not(feature = "unicode"),
+    any(feature = "custom", feature = "table", feature = "wrap")
+))]
+pub(crate) fn char_width(_c: char) -> usize {
+    1
+}
+
+/// Returns amount of terminal cells the specified character takes, which is 0 for combining and control characters.
+#[cfg(all(
+    feature = "unicode",
+    any(feature = "custom", feature = "table", feature = "wrap")
+))]
+pub(crate) fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Returns amount of characters in the output of given value made in the specified alternate mode.
+pub(crate) fn measure(val: &dyn Display, alternate: bool) -> usize {
+    let mut counter = WriteCounter::default();
+    // WriteCounter never fails by itself, so any error is caused by the value Display implementation,
+    // and only the amount of characters outputted before it is counted then.
+    let _ = match alternate {
+        true => counter.write_fmt(format_args!("{:#}", val)),
+        false => counter.write_fmt(format_args!("{}", val)),
+    };
+    counter.chars
+}
@@ -1,7 +1,8 @@
 use core::fmt::Display;
 
-/// Trait used to generalize over tuples of displayable types
-/// and references onto such tuples.
+/// Trait used to generalize over tuples of displayable types, two-element arrays of displayable type
+/// and references onto them. Since references onto displayable types are displayable too,
+/// tuples like `(&K, &V)`, `(K, &V)` and `(&K, V)` produced by map and zip iterators are supported as well.
 /// Trait is not sealed and can be implementd for any other needed type.
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 pub trait DisplayPair {
@@ -14,7 +15,19 @@ pub trait DisplayPair {
     fn left(&self) -> &Self::Left;
 
     /// Return a reference onto the right-side variable in corresponding 'left: right' construction.
-    fn rifgt(&self) -> &Self::Right;
+    /// Default implementation delegates to deprecated [rifgt][DisplayPair::rifgt] to keep existing implementations working,
+    /// so at least one of these methods must be implemented: otherwise they call each other endlessly.
+    fn right(&self) -> &Self::Right {
+        #[allow(deprecated)]
+        self.rifgt()
+    }
+
+    /// Return a reference onto the right-side variable in corresponding 'left: right' construction.
+    /// Misspelled predecessor of [right][DisplayPair::right], which it delegates to by default.
+    #[deprecated(since = "2.0.0", note = "use `right` instead")]
+    fn rifgt(&self) -> &Self::Right {
+        self.right()
+    }
 }
 
 impl<L: Display, R: Display> DisplayPair for (L, R) {
@@ -25,7 +38,7 @@ impl<L: Display, R: Display> DisplayPair for (L, R) {
         &self.0
     }
 
-    fn rifgt(&self) -> &Self::Right {
+    fn right(&self) -> &Self::Right {
         &self.1
     }
 }
@@ -38,7 +51,46 @@ impl<L: Display, R: Display> DisplayPair for &(L, R) {
         &self.0
     }
 
-    fn rifgt(&self) -> &Self::Right {
+    fn right(&self) -> &Self::Right {
+        &self.1
+    }
+}
+
+impl<L: Display, R: Display> DisplayPair for &&(L, R) {
+    type Left = L;
+    type Right = R;
+
+    fn left(&self) -> &Self::Left {
+        &self.0
+    }
+
+    fn right(&self) -> &Self::Right {
         &self.1
     }
 }
+
+impl<T: Display> DisplayPair for [T; 2] {
+    type Left = T;
+    type Right = T;
+
+    fn left(&self) -> &Self::Left {
+        &self[0]
+    }
+
+    fn right(&self) -> &Self::Right {
+        &self[1]
+    }
+}
+
+impl<T: Display> DisplayPair for &[T; 2] {
+    type Left = T;
+    type Right = T;
+
+    fn left(&self) -> &Self::Left {
+        &self[0]
+    }
+
+    fn right(&self) -> &Self::Right {
+        &self[1]
+    }
+}
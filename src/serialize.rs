@@ -0,0 +1,521 @@
+//! Any type implementing [Serialize] can be outputted the way [StructShow] and [ListShow] do without writing
+//! [Display] implementation by hand: structs and maps are outputted as structs, sequences and tuples as lists,
+//! and the scalar values as they are displayed. This module contains [Serializer] making it possible,
+//! [Serialized] adapter implementing [Display] for any serializable type, and [to_string] and [to_writer] entry points.
+//!
+//! Enum variants are outputted with their names: unit ones as just names, newtype ones like `Name(value)`,
+//! tuple and struct ones as names followed by the list or struct output respectively.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{to_string, Alternate};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! #[derive(Serialize)]
+//! struct Line {
+//!     name: &'static str,
+//!     points: [Point; 2],
+//! }
+//!
+//! let line = Line {
+//!     name: "diagonal",
+//!     points: [Point { x: 0, y: 0 }, Point { x: 1, y: 1 }],
+//! };
+//! assert_eq!(
+//!     "{name: diagonal, points: [{x: 0, y: 0}, {x: 1, y: 1}]}",
+//!     &to_string(&line, Alternate::OneLine).unwrap()
+//! );
+//! ```
+
+use crate::{Alternate, ListShow, StructShow};
+use alloc::string::{String, ToString};
+use core::{
+    cell::Cell,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+/// Error of the value serialization with [Serializer].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SerializeError {
+    /// The underlying formatter or writer failed, or some nested value failed to be serialized.
+    Format,
+    /// The value failed to be serialized with the specified message.
+    Custom(String),
+}
+
+impl Display for SerializeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Format => f.write_str("formatting failed"),
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl From<core::fmt::Error> for SerializeError {
+    fn from(_: core::fmt::Error) -> Self {
+        Self::Format
+    }
+}
+
+/// Lets to output any serializable value the way [StructShow] and [ListShow] do, regarding the specified alternate mode.
+/// Nested values are outputted with their own [Serializer] examplars in [Alternate::Inherit] mode.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct Serializer<'a, 'b> {
+    formatter: &'a mut Formatter<'b>,
+    alternate: Alternate,
+}
+
+impl<'a, 'b> Serializer<'a, 'b> {
+    /// Creates one [Serializer] examplar outputting into the specified formatter.
+    pub fn new(formatter: &'a mut Formatter<'b>, alternate: Alternate) -> Self {
+        Self {
+            formatter,
+            alternate,
+        }
+    }
+
+    fn display(self, val: &dyn Display) -> Result<(), SerializeError> {
+        Ok(Display::fmt(val, self.formatter)?)
+    }
+
+    fn list(self) -> ListSerializer<'a, 'b> {
+        ListSerializer {
+            show: ListShow::new(self.formatter, self.alternate),
+        }
+    }
+
+    fn r#struct(self) -> StructSerializer<'a, 'b> {
+        StructSerializer {
+            show: StructShow::new(self.formatter, self.alternate),
+            key: None,
+        }
+    }
+
+    fn named(self, name: &str) -> Result<Self, SerializeError> {
+        self.formatter.write_str(name)?;
+        self.formatter.write_char(' ')?;
+        Ok(self)
+    }
+}
+
+/// Adapter implementing [Display] for any serializable type by outputting it with [Serializer].
+/// Error messages of the failed serialization are lost, since [Display] can only report the fact of failure.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct Serialized<'a, T: ?Sized> {
+    val: &'a T,
+    alternate: Alternate,
+    error: Cell<Option<SerializeError>>,
+}
+
+impl<'a, T: Serialize + ?Sized> Serialized<'a, T> {
+    /// Creates one [Serialized] examplar outputting the specified value in the specified alternate mode.
+    pub fn new(val: &'a T, alternate: Alternate) -> Self {
+        Self {
+            val,
+            alternate,
+            error: Cell::new(None),
+        }
+    }
+
+    /// Creates one [Serialized] examplar outputting the specified value with [Alternate::Inherit] setting.
+    pub fn inherit(val: &'a T) -> Self {
+        Self::new(val, Alternate::Inherit)
+    }
+}
+
+impl<'a, T: Serialize + ?Sized> Display for Serialized<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.val
+            .serialize(Serializer::new(f, self.alternate))
+            .map_err(|error| {
+                self.error.set(Some(error));
+                core::fmt::Error
+            })
+    }
+}
+
+/// Outputs the specified value into the specified writer, in prettified format if [Alternate::Pretty] is specified.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub fn to_writer<W, T>(w: &mut W, val: &T, alternate: Alternate) -> Result<(), SerializeError>
+where
+    W: Write + ?Sized,
+    T: Serialize + ?Sized,
+{
+    let serialized = Serialized::new(val, alternate);
+    let result = match alternate {
+        Alternate::Pretty => w.write_fmt(format_args!("{:#}", serialized)),
+        _ => w.write_fmt(format_args!("{}", serialized)),
+    };
+    match (result, serialized.error.take()) {
+        (Ok(()), _) => Ok(()),
+        (Err(_), Some(error)) => Err(error),
+        (Err(error), None) => Err(error.into()),
+    }
+}
+
+/// Outputs the specified value into [String], in prettified format if [Alternate::Pretty] is specified.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub fn to_string<T: Serialize + ?Sized>(
+    val: &T,
+    alternate: Alternate,
+) -> Result<String, SerializeError> {
+    let mut output = String::new();
+    to_writer(&mut output, val, alternate)?;
+    Ok(output)
+}
+
+impl<'a, 'b> ser::Serializer for Serializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+    type SerializeSeq = ListSerializer<'a, 'b>;
+    type SerializeTuple = ListSerializer<'a, 'b>;
+    type SerializeTupleStruct = ListSerializer<'a, 'b>;
+    type SerializeTupleVariant = ListSerializer<'a, 'b>;
+    type SerializeMap = StructSerializer<'a, 'b>;
+    type SerializeStruct = StructSerializer<'a, 'b>;
+    type SerializeStructVariant = StructSerializer<'a, 'b>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SerializeError> {
+        self.display(&v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SerializeError> {
+        let mut list = self.list();
+        list.show.items_from_iter(v.iter());
+        Ok(list.show.finish()?)
+    }
+
+    fn serialize_none(self) -> Result<(), SerializeError> {
+        self.display(&"None")
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerializeError> {
+        self.display(&"()")
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<(), SerializeError> {
+        self.display(&name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), SerializeError> {
+        self.display(&variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.formatter.write_str(variant)?;
+        self.formatter.write_char('(')?;
+        value.serialize(Serializer::new(self.formatter, self.alternate))?;
+        Ok(self.formatter.write_char(')')?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, SerializeError> {
+        Ok(self.list())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, SerializeError> {
+        Ok(self.list())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerializeError> {
+        Ok(self.list())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerializeError> {
+        Ok(self.named(variant)?.list())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, SerializeError> {
+        Ok(self.r#struct())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, SerializeError> {
+        Ok(self.r#struct())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, SerializeError> {
+        Ok(self.named(variant)?.r#struct())
+    }
+
+    fn collect_str<T: Display + ?Sized>(self, value: &T) -> Result<(), SerializeError> {
+        self.display(&value)
+    }
+}
+
+/// Outputs sequences, tuples and tuple variants of the value serialized with [Serializer] as lists.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct ListSerializer<'a, 'b> {
+    show: ListShow<'a, 'b>,
+}
+
+impl<'a, 'b> ListSerializer<'a, 'b> {
+    fn item<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.show.item(&Serialized::inherit(value));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), SerializeError> {
+        Ok(self.show.finish()?)
+    }
+}
+
+impl<'a, 'b> SerializeSeq for ListSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+impl<'a, 'b> SerializeTuple for ListSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+impl<'a, 'b> SerializeTupleStruct for ListSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+impl<'a, 'b> SerializeTupleVariant for ListSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+/// Outputs maps, structs and struct variants of the value serialized with [Serializer] as structs.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct StructSerializer<'a, 'b> {
+    show: StructShow<'a, 'b>,
+    // Output of the map key waiting for its value.
+    key: Option<String>,
+}
+
+impl<'a, 'b> StructSerializer<'a, 'b> {
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &dyn Display,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.show.field(key, &Serialized::inherit(value));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), SerializeError> {
+        Ok(self.show.finish()?)
+    }
+}
+
+impl<'a, 'b> SerializeMap for StructSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerializeError> {
+        self.key = Some(to_string(key, Alternate::OneLine)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = self.key.take().unwrap_or_default();
+        self.field(&key, value)
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), SerializeError>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        self.field(&Serialized::new(key, Alternate::OneLine), value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+impl<'a, 'b> SerializeStruct for StructSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
+
+impl<'a, 'b> SerializeStructVariant for StructSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        self.finish()
+    }
+}
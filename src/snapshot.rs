@@ -0,0 +1,155 @@
+//! Snapshot tests compare the output with the one saved before, so any output varying between runs
+//! (like the order of hash map entries, float rounding noise, timestamps or random identifiers) makes them flaky.
+//! This module contains [Snapshot] settings making all the [StructShow][crate::StructShow] and
+//! [ListShow][crate::ListShow] builders created on the current thread output deterministically:
+//! entries sorted by their keys, values looking like floating point numbers outputted with fixed precision,
+//! and values of the volatile fields replaced with placeholder.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{display_struct, Alternate, Snapshot};
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//!
+//! struct Job {
+//!     id: u64,
+//!     progress: f64,
+//!     name: &'static str,
+//! }
+//!
+//! impl Display for Job {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         display_struct(f, &[(&"name", &self.name), (&"id", &self.id), (&"progress", &self.progress)])
+//!     }
+//! }
+//!
+//! let job = Job { id: 8_137_342, progress: 0.1 + 0.2, name: "backup" };
+//! let snapshot = Snapshot::new().with_precision(Some(2)).with_volatile(&["id"]);
+//! assert_eq!("{id: [volatile], name: backup, progress: 0.30}", &snapshot.render(&job, Alternate::OneLine));
+//! ```
+
+use crate::{forward::render, Alternate};
+use alloc::{format, string::String};
+use core::{cell::Cell, fmt::Display, marker::PhantomData};
+
+std::thread_local! {
+    // Settings of the snapshot mode in effect, if any.
+    static SNAPSHOT: Cell<Option<Snapshot>> = const { Cell::new(None) };
+}
+
+/// Settings of deterministic output, applied to all the [StructShow][crate::StructShow] and [ListShow][crate::ListShow]
+/// builders created on the current thread while the guard returned by [Snapshot::apply] is alive.
+/// Entries are buffered till the builder finishes, so it takes memory proportional to the output size,
+/// unless they are streamed (see [display_struct_streamed][crate::display_struct_streamed]), which leaves them unsorted.
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    sorted: bool,
+    precision: Option<usize>,
+    volatile: &'static [&'static str],
+    placeholder: &'static str,
+}
+
+impl Snapshot {
+    /// Creates one [Snapshot] examplar sorting the entries, outputting floating point numbers with 6 digits
+    /// after the point and treating no fields as volatile.
+    pub const fn new() -> Self {
+        Self {
+            sorted: true,
+            precision: Some(6),
+            volatile: &[],
+            placeholder: "[volatile]",
+        }
+    }
+
+    /// Makes current [Snapshot] examplar sort the struct entries by their keys (compared by their one-line output)
+    /// or keep them in the order they are outputted in. List items are never sorted.
+    pub fn with_sorted(self, sorted: bool) -> Self {
+        Self { sorted, ..self }
+    }
+
+    /// Makes current [Snapshot] examplar output the values looking like floating point numbers (having point
+    /// or exponent in their one-line output) with the specified amount of digits after the point,
+    /// or output them as they are if no amount is specified.
+    pub fn with_precision(self, precision: Option<usize>) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Makes current [Snapshot] examplar replace the values of the fields having the specified keys with placeholder.
+    pub fn with_volatile(self, volatile: &'static [&'static str]) -> Self {
+        Self { volatile, ..self }
+    }
+
+    /// Makes current [Snapshot] examplar replace the values of the volatile fields with the specified placeholder
+    /// instead of `[volatile]`.
+    pub fn with_placeholder(self, placeholder: &'static str) -> Self {
+        Self {
+            placeholder,
+            ..self
+        }
+    }
+
+    /// Returns the snapshot settings currently in effect on this thread, if any.
+    pub fn current() -> Option<Self> {
+        SNAPSHOT.with(Cell::get)
+    }
+
+    /// Makes current [Snapshot] examplar in effect on this thread until the returned guard is dropped.
+    pub fn apply(self) -> SnapshotGuard {
+        SnapshotGuard {
+            outer: SNAPSHOT.with(|current| current.replace(Some(self))),
+            thread_bound: PhantomData,
+        }
+    }
+
+    /// Outputs the specified value into new string with current [Snapshot] examplar in effect,
+    /// in prettified format if [Alternate::Pretty] is specified.
+    pub fn render(self, val: &dyn Display, alternate: Alternate) -> String {
+        let _guard = self.apply();
+        render(val, alternate)
+    }
+
+    // Returns whether the field with the specified key is volatile.
+    pub(crate) fn is_volatile(&self, key: &str) -> bool {
+        self.volatile.contains(&key)
+    }
+
+    pub(crate) fn placeholder(&self) -> &'static str {
+        self.placeholder
+    }
+
+    pub(crate) fn sorted(&self) -> bool {
+        self.sorted
+    }
+
+    // Returns the value output with fixed precision, if it looks like floating point number and the precision is fixed.
+    pub(crate) fn fixed(&self, text: &str) -> Option<String> {
+        let precision = self.precision?;
+        if !text.contains(['.', 'e', 'E']) {
+            return None;
+        }
+        let number: f64 = text.parse().ok()?;
+        Some(format!("{:.*}", precision, number))
+    }
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Guard keeping [Snapshot] settings in effect, restoring the ones which were in effect before (if any) when dropped.
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+#[must_use = "snapshot settings are in effect only while the guard is alive"]
+#[derive(Debug)]
+pub struct SnapshotGuard {
+    outer: Option<Snapshot>,
+    // Settings are kept in thread-local storage, so the guard must be dropped on the same thread.
+    thread_bound: PhantomData<*const ()>,
+}
+
+impl Drop for SnapshotGuard {
+    fn drop(&mut self) {
+        SNAPSHOT.with(|current| current.set(self.outer));
+    }
+}
@@ -1,73 +1,110 @@
-use crate::{Alternate, DisplayPair};
+#[cfg(feature = "alloc")]
+use crate::forward::render;
+use crate::{
+    indent::{Entries, Mode},
+    Alternate, AlternatePolicy, DisplayPair, DisplayTriple, Indent, Layout,
+};
 use core::{
-    fmt::{DebugSet, Display, Formatter, Result as FmtResult},
+    fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
     format_args,
 };
 
-type StructEntrier = fn(&mut DebugSet<'_, '_>, &dyn Display, &dyn Display);
-
-fn usual_struct_entrier(w: &mut DebugSet, k: &dyn Display, v: &dyn Display) {
-    w.entry(&format_args!("{}: {}", k, v));
-}
-
-fn alternative_struct_entrier(w: &mut DebugSet, k: &dyn Display, v: &dyn Display) {
-    w.entry(&format_args!("{}: {:#}", k, v));
+// Outputs value followed by its unit, keeping alternate mode for the value only.
+struct Measured<'a> {
+    val: &'a dyn Display,
+    unit: &'a dyn Display,
 }
 
-fn null_struct_entrier(_: &mut DebugSet, _: &dyn Display, _: &dyn Display) {}
-
-fn inherit_entrier(inherited_value: bool) -> StructEntrier {
-    match inherited_value {
-        false => usual_struct_entrier,
-        true => alternative_struct_entrier,
+impl<'a> Display for Measured<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match f.alternate() {
+            true => f.write_fmt(format_args!("{:#} {}", self.val, self.unit)),
+            false => f.write_fmt(format_args!("{} {}", self.val, self.unit)),
+        }
     }
 }
 
 /// Lets to output some structure regarding the propagated value of output alternativeness.
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 pub struct StructShow<'a, 'b> {
-    wrapper: DebugSet<'a, 'b>,
-    entrier: StructEntrier,
+    wrapper: Entries<'a, 'b>,
+    mode: Mode,
+    alternate: Alternate,
     inherited_value: bool,
 }
 
 impl<'a, 'b> StructShow<'a, 'b> {
-    fn choose_entrier(alternate: Alternate, inherited_value: bool) -> StructEntrier {
-        match alternate {
-            Alternate::OneLine => usual_struct_entrier,
-            Alternate::Pretty => alternative_struct_entrier,
-            Alternate::Inherit => inherit_entrier(inherited_value),
-        }
-    }
-
-    /// Creates one [StructShow] examplar starting its output.
-    pub fn new(formatter: &'a mut Formatter<'b>, alternate: Alternate) -> Self {
+    /// Creates one [StructShow] examplar starting its output. Accepts either [Alternate] mode or [AlternatePolicy].
+    pub fn new<A: Into<AlternatePolicy>>(formatter: &'a mut Formatter<'b>, alternate: A) -> Self {
         let inherited_value = formatter.alternate();
-        let entrier = Self::choose_entrier(alternate, inherited_value);
+        let mut wrapper = Entries::new(formatter, '{', '}');
+        let alternate = wrapper.resolve(alternate.into());
         Self {
-            wrapper: formatter.debug_set(),
-            entrier,
+            wrapper,
+            mode: Mode::of(alternate, inherited_value),
+            alternate,
             inherited_value,
         }
     }
 
     /// Creates one [StructShow] examplar with [Alternate::Inherit] setting and starts its output.
     pub fn inherit(formatter: &'a mut Formatter<'b>) -> Self {
-        let inherited_value = formatter.alternate();
-        let entrier = inherit_entrier(inherited_value);
-        Self {
-            wrapper: formatter.debug_set(),
-            entrier,
-            inherited_value,
-        }
+        Self::new(formatter, Alternate::Inherit)
+    }
+
+    /// Makes current [StructShow] examplar style its output (and output of the nested structs and lists) with the specified theme,
+    /// unless the colored output is not allowed according to [ColorChoice][crate::ColorChoice].
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn theme(&mut self, theme: &'static dyn crate::Theme) -> &mut Self {
+        self.wrapper.theme(theme);
+        self
+    }
+
+    /// Makes current [StructShow] examplar indent its entries in prettified format as specified,
+    /// instead of the globally set way (see [Indent::set_global]).
+    pub fn indent(&mut self, indent: Indent) -> &mut Self {
+        self.wrapper.indent(indent);
+        self
+    }
+
+    /// Makes current [StructShow] examplar lay out its entries in prettified format as specified.
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.wrapper.layout(layout);
+        self
+    }
+
+    /// Makes current [StructShow] examplar output nested structs and lists (ones outputted by builders of this crate)
+    /// only up to the specified depth below it, outputting `…` instead of the deeper ones. Zero depth means that
+    /// even its own entries are outputted only if they are not structs or lists themselves.
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.wrapper.max_depth(depth);
+        self
+    }
+
+    // Makes the fields passed to the specified collector instead of being outputted.
+    #[cfg(feature = "diff")]
+    pub(crate) fn collect_into(&mut self, collector: crate::indent::Collector<'a>) {
+        self.wrapper.collect_into(collector);
     }
 
     /// Adds one key-value pair to the struct output.
     pub fn field(&mut self, key: &dyn Display, val: &dyn Display) -> &mut Self {
-        (self.entrier)(&mut self.wrapper, key, val);
+        self.wrapper.entry_in(self.mode, Some(key), val);
         self
     }
 
+    /// Adds one key-value pair to the struct output, returning error right away if its output
+    /// (or output of any entry before it) failed, so the `?` operator stops at the failed field.
+    pub fn try_field(
+        &mut self,
+        key: &dyn Display,
+        val: &dyn Display,
+    ) -> Result<&mut Self, FmtError> {
+        self.field(key, val);
+        self.wrapper.result().map(|_| self)
+    }
+
     /// Adds one key-value pair to the struct output.
     pub fn field_override(
         &mut self,
@@ -75,11 +112,11 @@ impl<'a, 'b> StructShow<'a, 'b> {
         val: &dyn Display,
         alternate: Alternate,
     ) -> &mut Self {
-        // Safety: since only specified subset of predefined functions can take place in self.entrier,
-        // and null_struct_entrier is one of them, the comparison through pointer values is safe enough.
-        if null_struct_entrier as usize != self.entrier as usize {
-            let entrier = Self::choose_entrier(alternate, self.inherited_value);
-            entrier(&mut self.wrapper, key, val);
+        match alternate {
+            Alternate::Auto { max_width } => self.wrapper.entry_fitting(Some(key), val, max_width),
+            _ => self
+                .wrapper
+                .entry_in(Mode::of(alternate, self.inherited_value), Some(key), val),
         }
         self
     }
@@ -105,9 +142,18 @@ impl<'a, 'b> StructShow<'a, 'b> {
         self
     }
 
-    /// Finishes the struct output, returning the result.
-    pub fn finish(&mut self) -> FmtResult {
-        self.entrier = null_struct_entrier;
+    /// Adds one key-value pair with the value unit to the struct output, like `key: value unit`.
+    pub fn field_unit(
+        &mut self,
+        key: &dyn Display,
+        val: &dyn Display,
+        unit: &dyn Display,
+    ) -> &mut Self {
+        self.field(key, &Measured { val, unit })
+    }
+
+    /// Finishes the struct output, returning the result. Consumes the builder, so no entries can be added after it.
+    pub fn finish(self) -> FmtResult {
         self.wrapper.finish()
     }
 
@@ -122,7 +168,19 @@ impl<'a, 'b> StructShow<'a, 'b> {
         I: Iterator + 'c,
         I::Item: DisplayPair,
     {
-        fields.for_each(|p| (self.entrier)(&mut self.wrapper, p.left(), p.rifgt()));
+        fields.for_each(|p| self.wrapper.entry_in(self.mode, Some(p.left()), p.right()));
+        self
+    }
+
+    /// Adds several key-value pairs with the value units to the struct output from iterator.
+    pub fn fields_unit_from_iter<'c, I>(&mut self, fields: I) -> &mut Self
+    where
+        I: Iterator + 'c,
+        I::Item: DisplayTriple,
+    {
+        fields.for_each(|t| {
+            self.field_unit(t.left(), t.middle(), t.right());
+        });
         self
     }
 
@@ -130,15 +188,21 @@ impl<'a, 'b> StructShow<'a, 'b> {
     pub fn alternate(&self) -> bool {
         self.inherited_value
     }
+
+    /// Returns mode the values are outputted in, with [Alternate::Inherit] and [AlternatePolicy] resolved,
+    /// so the nested outputs can follow the same decisions as the struct itself.
+    pub fn resolved_alternate(&self) -> Alternate {
+        self.alternate
+    }
 }
 
 /// Performs the whole struct output routine from creation of [StructShow] examplar to finishing (for example see the crate-level documentation).
 /// Works with slice, always inherits alternate mode.
 #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
 pub fn display_struct(f: &mut Formatter<'_>, fields: &[(&dyn Display, &dyn Display)]) -> FmtResult {
-    StructShow::new(f, Alternate::Inherit)
-        .fields(fields)
-        .finish()
+    let mut show = StructShow::new(f, Alternate::Inherit);
+    show.fields(fields);
+    show.finish()
 }
 
 /// Performs the whole struct output routine from creation of [StructShow] examplar to finishing.
@@ -149,7 +213,60 @@ where
     I: Iterator + 'c,
     I::Item: DisplayPair,
 {
-    StructShow::new(f, Alternate::Inherit)
-        .fields_from_iter(fields)
-        .finish()
+    let mut show = StructShow::new(f, Alternate::Inherit);
+    show.fields_from_iter(fields);
+    show.finish()
+}
+
+/// Performs the whole struct output routine from creation of [StructShow] examplar to finishing, streaming the fields:
+/// every key-value pair is outputted right after it is taken from the iterator and is never buffered, and no more
+/// pairs are taken once the output fails or turns out to be nested deeper than allowed. So any amount of fields
+/// can be dumped with bounded memory: in snapshot mode the fields are not sorted, in automatic alternate mode
+/// every value is measured alone instead of the whole struct, and the output padded up to some width with right
+/// or center alignment is postponed only while it is narrower than the width. Always inherits alternate mode.
+#[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
+pub fn display_struct_streamed<I, K, V>(f: &mut Formatter<'_>, fields: I) -> FmtResult
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    let mut show = StructShow::new(f, Alternate::Inherit);
+    show.wrapper.stream();
+    let mut fields = fields.into_iter();
+    while show.wrapper.accepts() {
+        let (key, val) = match fields.next() {
+            Some(pair) => pair,
+            None => break,
+        };
+        show.field(&key, &val);
+    }
+    show.finish()
+}
+
+// Outputs the fields in the specified alternate mode, so they can be rendered into string or any writer.
+#[cfg(feature = "alloc")]
+pub(crate) struct StructOutput<'a> {
+    pub(crate) fields: &'a [(&'a dyn Display, &'a dyn Display)],
+    pub(crate) alternate: Alternate,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Display for StructOutput<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut show = StructShow::new(f, self.alternate);
+        show.fields(self.fields);
+        show.finish()
+    }
+}
+
+/// Performs the whole struct output routine into new string, with no need to define type implementing [Display].
+/// Output is prettified if [Alternate::Pretty] is specified.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "struct", feature = "alloc"))))]
+pub fn display_struct_to_string(
+    fields: &[(&dyn Display, &dyn Display)],
+    alternate: Alternate,
+) -> alloc::string::String {
+    render(&StructOutput { fields, alternate }, alternate)
 }
@@ -0,0 +1,1438 @@
+//! Sometimes a collection of similar items is better outputted as table, with every item being a row
+//! and every item field being a column. This module contains [Row] trait letting to define such columns
+//! and [Table] struct which outputs any collection of [Row] implementors aligned in columns in alternate mode,
+//! or just its short summary otherwise.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{AsTable, Row};
+//!
+//! struct Planet {
+//!     name: &'static str,
+//!     moons: u32,
+//! }
+//!
+//! impl Row for Planet {
+//!     const KEYS: &'static [&'static str] = &["name", "moons"];
+//!
+//!     fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+//!         match index {
+//!             0 => Display::fmt(&self.name, f),
+//!             _ => Display::fmt(&self.moons, f),
+//!         }
+//!     }
+//! }
+//!
+//! let planets = vec![
+//!     Planet { name: "Earth", moons: 1 },
+//!     Planet { name: "Mars", moons: 2 },
+//! ];
+//! assert_eq!(
+//!     "name  | moons\n------+------\nEarth | 1    \nMars  | 2    ",
+//!     &format!("{:#}", planets.as_table())
+//! );
+//! assert_eq!("2 rows × 2 cols", &format!("{}", planets.as_table()));
+//! ```
+
+mod buffered;
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "color"))))]
+mod color;
+mod csv;
+mod dynamic;
+mod grid;
+mod keyed;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "rayon"))))]
+mod parallel;
+mod record;
+mod stream;
+mod style;
+
+pub use buffered::*;
+#[cfg(feature = "color")]
+pub use color::*;
+pub use csv::*;
+pub use dynamic::*;
+pub use grid::*;
+pub use keyed::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use record::*;
+pub use stream::*;
+pub use style::*;
+
+use crate::{
+    measure::{char_width, measure, width, WriteCounter},
+    Align,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Error, Formatter, Result as FmtResult, Write},
+};
+use smallvec::SmallVec;
+use style::MarkdownEscaper;
+
+// Amount of columns the per-column data is kept on stack for, covering most of the tables.
+const INLINE_COLUMNS: usize = 8;
+
+// Amount of bytes of the cell text kept on stack while outputting it.
+const INLINE_CELL: usize = 64;
+
+// Widths of the table columns.
+type Sizes = SmallVec<[usize; INLINE_COLUMNS]>;
+
+// Alignments of the table columns.
+type Alignments = SmallVec<[Align; INLINE_COLUMNS]>;
+
+// Texts of the row cells, every cell being formatted once per output.
+type Texts = SmallVec<[String; INLINE_COLUMNS]>;
+
+/// Lets to output some type as table row: every key is used as column header,
+/// and every cell is outputted in the column with the same index.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub trait Row {
+    /// Headers of the columns, also defining the number of them.
+    const KEYS: &'static [&'static str];
+
+    /// Alignments of the columns, matched with [Row::KEYS] by index. Columns without alignment specified
+    /// are aligned to the left.
+    const ALIGNMENTS: &'static [Align] = &[];
+
+    /// Outputs the cell of the column with the specified index, which is always less than the number of [Row::KEYS].
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<R: Row + ?Sized> Row for &R {
+    const KEYS: &'static [&'static str] = R::KEYS;
+    const ALIGNMENTS: &'static [Align] = R::ALIGNMENTS;
+
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        (**self).cell(index, f)
+    }
+}
+
+// Lets to output cells of any row-like value by column index.
+trait Cells {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<R: Row + ?Sized> Cells for R {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        Row::cell(self, index, f)
+    }
+}
+
+// Lets to output the row with pipes inside its cells escaped for Markdown.
+struct MarkdownRow<R>(R);
+
+impl<R: Cells> Cells for MarkdownRow<R> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        let cell = RowCell {
+            row: &self.0,
+            index,
+        };
+        MarkdownEscaper { inner: f }.write_fmt(format_args!("{}", cell))
+    }
+}
+
+// Lets to output footer row made of displayable values, missing cells being empty.
+struct FooterRow<'a>(&'a [&'a dyn Display]);
+
+impl<'a> Cells for FooterRow<'a> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0.get(index) {
+            Some(val) => Display::fmt(val, f),
+            None => Ok(()),
+        }
+    }
+}
+
+// Lets to output cells of the borrowed row.
+struct ByRef<'a, C>(&'a C);
+
+impl<'a, C: Cells> Cells for ByRef<'a, C> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        self.0.cell(index, f)
+    }
+}
+
+// Lets to output one cell of the row as usual displayable value, with line breaks inside it replaced
+// by visible escapes, so multi-line values don't break the table layout.
+struct RowCell<'a, R: ?Sized> {
+    row: &'a R,
+    index: usize,
+}
+
+impl<'a, R: Cells + ?Sized> Display for RowCell<'a, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let cell = RawCell {
+            row: self.row,
+            index: self.index,
+        };
+        match f.alternate() {
+            true => LineEscaper { inner: f }.write_fmt(format_args!("{:#}", cell)),
+            false => LineEscaper { inner: f }.write_fmt(format_args!("{}", cell)),
+        }
+    }
+}
+
+// Lets to output one cell of the row exactly as the row outputs it.
+struct RawCell<'a, R: ?Sized> {
+    row: &'a R,
+    index: usize,
+}
+
+impl<'a, R: Cells + ?Sized> Display for RawCell<'a, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.row.cell(self.index, f)
+    }
+}
+
+// Replaces line breaks with their escapes, like `\n` and `\r`, while passing the output to the inner formatter.
+struct LineEscaper<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+}
+
+impl<'a, 'b> Write for LineEscaper<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '\n' => self.inner.write_str("\\n")?,
+                '\r' => self.inner.write_str("\\r")?,
+                c => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Lets to output cells of the row already formatted into texts.
+struct TextRow<'a>(&'a [String]);
+
+impl<'a> Cells for TextRow<'a> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.0[index])
+    }
+}
+
+// Lets to output the header of the table as one more row.
+struct Header<'a, 'k>(&'a [&'k str]);
+
+impl<'a, 'k> Cells for Header<'a, 'k> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.0[index])
+    }
+}
+
+/// Lets to output value padded with spaces up to the specified width (in characters), so it fits table column.
+/// Values longer than the width are truncated to it, with the last character replaced by `…`.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Cell<'a> {
+    val: &'a dyn Display,
+    width: usize,
+    align: Align,
+}
+
+impl<'a> Cell<'a> {
+    /// Creates one [Cell] examplar aligned to the left, ready to be outputted.
+    pub fn new(val: &'a dyn Display, width: usize) -> Self {
+        Self {
+            val,
+            width,
+            align: Align::Left,
+        }
+    }
+
+    /// Makes current [Cell] examplar align its value with the specified alignment.
+    pub fn aligned(self, align: Align) -> Self {
+        Self { align, ..self }
+    }
+}
+
+impl<'a> Cell<'a> {
+    // Outputs the cell, formatting its value only once into the specified buffer, which is both measured and outputted.
+    fn write_buffered(&self, f: &mut Formatter<'_>, buffer: &mut CellBuffer) -> FmtResult {
+        buffer.clear();
+        buffer.write_fmt(format_args!("{}", self.val))?;
+        write_aligned(f, buffer.as_str(), self.width, self.align)
+    }
+}
+
+// Outputs the text padded with spaces up to the specified width as the alignment requires, or truncated to it.
+fn write_aligned(f: &mut Formatter<'_>, text: &str, width: usize, align: Align) -> FmtResult {
+    let mut counter = WriteCounter::default();
+    // WriteCounter never fails by itself.
+    let _ = counter.write_str(text);
+    let length = counter.chars;
+    if length > width {
+        return match width {
+            0 => Ok(()),
+            width => {
+                let mut writer = SlicingWriter {
+                    inner: f,
+                    left: width - 1,
+                    full: false,
+                };
+                writer.write_str(text)?;
+                // Wide character may not fit the rest of the space, which is filled after ellipsis then.
+                let rest = writer.left;
+                f.write_char('…')?;
+                for _ in 0..rest {
+                    f.write_char(' ')?;
+                }
+                Ok(())
+            }
+        };
+    }
+    let lack = width - length;
+    let (before, after) = match align {
+        Align::Left => (0, lack),
+        Align::Right => (lack, 0),
+        Align::Center => (lack / 2, lack - lack / 2),
+    };
+    for _ in 0..before {
+        f.write_char(' ')?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(' ')?;
+    }
+    Ok(())
+}
+
+impl<'a> Display for Cell<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.write_buffered(f, &mut CellBuffer::default())
+    }
+}
+
+// Text of the cell being outputted, kept on stack unless it is too long.
+#[derive(Default)]
+struct CellBuffer(SmallVec<[u8; INLINE_CELL]>);
+
+impl CellBuffer {
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn as_str(&self) -> &str {
+        // Only whole strings are written into the buffer, so it always holds valid UTF-8.
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl Write for CellBuffer {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+// Passes only the characters fitting the specified width to the inner formatter, discarding the rest.
+struct SlicingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    left: usize,
+    full: bool,
+}
+
+impl<'a, 'b> Write for SlicingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            let width = char_width(c);
+            self.full = self.full || width > self.left;
+            if self.full {
+                break;
+            }
+            self.inner.write_char(c)?;
+            self.left -= width;
+        }
+        Ok(())
+    }
+}
+
+// Splits the output into physical lines at line breaks and, if the width is specified, at the width boundary,
+// passing only the characters of the specified line to the inner formatter (if any) and counting the lines.
+struct LineSplitter<'a, 'b> {
+    inner: Option<&'a mut Formatter<'b>>,
+    width: Option<usize>,
+    line: usize,
+    current: usize,
+    column: usize,
+    widest: usize,
+}
+
+impl<'a, 'b> LineSplitter<'a, 'b> {
+    fn new(inner: Option<&'a mut Formatter<'b>>, width: Option<usize>, line: usize) -> Self {
+        Self {
+            inner,
+            width,
+            line,
+            current: 0,
+            column: 0,
+            widest: 0,
+        }
+    }
+}
+
+impl<'a, 'b> Write for LineSplitter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '\r' => continue,
+                '\n' => {
+                    self.current += 1;
+                    self.column = 0;
+                    continue;
+                }
+                _ => (),
+            }
+            let width = char_width(c);
+            if matches!(self.width, Some(limit) if limit > 0 && self.column > 0 && self.column + width > limit)
+            {
+                self.current += 1;
+                self.column = 0;
+            }
+            if let (true, Some(inner)) = (self.current == self.line, self.inner.as_mut()) {
+                inner.write_char(c)?;
+            }
+            self.column += width;
+            self.widest = self.widest.max(self.column);
+        }
+        Ok(())
+    }
+}
+
+// Lets to output only one physical line of the cell which is wrapped or has line breaks inside.
+struct CellLine<'a> {
+    text: &'a str,
+    width: Option<usize>,
+    line: usize,
+}
+
+impl<'a> Display for CellLine<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        LineSplitter::new(Some(f), self.width, self.line).write_str(self.text)
+    }
+}
+
+// Returns width of the cell text, which is the width of its longest line if line breaks inside it are kept.
+fn text_width(text: &str, multiline: bool) -> usize {
+    // Neither LineSplitter without inner formatter nor WriteCounter ever fail.
+    match multiline {
+        true => {
+            let mut splitter = LineSplitter::new(None, None, 0);
+            let _ = splitter.write_str(text);
+            splitter.widest
+        }
+        false => {
+            let mut counter = WriteCounter::default();
+            let _ = counter.write_str(text);
+            counter.chars
+        }
+    }
+}
+
+// Returns width of the cell, which is the width of its longest line if line breaks inside it are kept.
+fn cell_width<R: Cells + ?Sized>(row: &R, index: usize, multiline: bool) -> usize {
+    match multiline {
+        true => {
+            let mut splitter = LineSplitter::new(None, None, 0);
+            // LineSplitter never fails without inner formatter, just like WriteCounter.
+            let _ = splitter.write_fmt(format_args!("{}", RawCell { row, index }));
+            splitter.widest
+        }
+        false => measure(&RowCell { row, index }, false),
+    }
+}
+
+/// Maximal widths (in characters) of the table columns.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WidthLimits<'a> {
+    /// Maximal widths of the columns, matched with [Row::KEYS] by index.
+    pub columns: &'a [usize],
+    /// Maximal width of any column.
+    pub all: Option<usize>,
+}
+
+impl<'a> WidthLimits<'a> {
+    /// Returns maximal width of the column with the specified index, if it is limited.
+    pub fn limit(&self, index: usize) -> Option<usize> {
+        match (self.columns.get(index).copied(), self.all) {
+            (Some(column), Some(all)) => Some(column.min(all)),
+            (column, all) => column.or(all),
+        }
+    }
+}
+
+/// Returns widths (in characters) of the columns needed to output the specified rows along with their headers,
+/// clamped with the specified limits.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub fn sizes_list<I>(rows: I, limits: &WidthLimits<'_>) -> Vec<usize>
+where
+    I: IntoIterator,
+    I::Item: Row,
+{
+    column_sizes(<I::Item as Row>::KEYS, rows, limits, false).into_vec()
+}
+
+// Returns widths of the columns with the specified keys needed to output the specified rows, clamped with the limits.
+fn column_sizes<I>(keys: &[&str], rows: I, limits: &WidthLimits<'_>, multiline: bool) -> Sizes
+where
+    I: IntoIterator,
+    I::Item: Cells,
+{
+    let mut sizes = key_sizes(keys);
+    for row in rows {
+        widen_sizes(&mut sizes, &row, multiline);
+    }
+    clamp_sizes(sizes, limits)
+}
+
+// Returns widths of the columns needed to output their keys only.
+fn key_sizes(keys: &[&str]) -> Sizes {
+    keys.iter().map(|key| width(key)).collect()
+}
+
+// Widens the columns to fit the cells of the specified row.
+fn widen_sizes<R: Cells + ?Sized>(sizes: &mut [usize], row: &R, multiline: bool) {
+    for (index, size) in sizes.iter_mut().enumerate() {
+        *size = (*size).max(cell_width(row, index, multiline));
+    }
+}
+
+// Returns the specified columns widths clamped with the limits.
+fn clamp_sizes(mut sizes: Sizes, limits: &WidthLimits<'_>) -> Sizes {
+    for (index, size) in sizes.iter_mut().enumerate() {
+        if let Some(limit) = limits.limit(index) {
+            *size = (*size).min(limit);
+        }
+    }
+    sizes
+}
+
+// Returns the specified alignments of the columns with the specified keys, complemented with right alignment
+// for the columns having only numbers in their non-empty cells.
+fn numeric_alignments<I>(keys: &[&str], alignments: &[Align], rows: I) -> Alignments
+where
+    I: Iterator,
+    I::Item: Cells,
+{
+    // Columns with explicit alignment are considered non-numeric right away, the ones with no cells yet are undecided.
+    let mut numeric: SmallVec<[Option<bool>; INLINE_COLUMNS]> = (0..keys.len())
+        .map(|index| alignments.get(index).map(|_| false))
+        .collect();
+    for row in rows {
+        for (index, numeric) in numeric.iter_mut().enumerate() {
+            if *numeric == Some(false) {
+                continue;
+            }
+            let mut detector = NumberDetector::default();
+            // NumberDetector never fails by itself, just like WriteCounter.
+            let _ = detector.write_fmt(format_args!("{}", RowCell { row: &row, index }));
+            if detector.chars > 0 {
+                *numeric = Some(detector.is_number());
+            }
+        }
+    }
+    numeric
+        .into_iter()
+        .enumerate()
+        .map(|(index, numeric)| match numeric {
+            Some(true) => Align::Right,
+            _ => alignments.get(index).copied().unwrap_or_default(),
+        })
+        .collect()
+}
+
+// Checks whether the output written into it looks like a number, discarding the output itself.
+#[derive(Default)]
+struct NumberDetector {
+    chars: usize,
+    digits: bool,
+    percent: bool,
+    invalid: bool,
+}
+
+impl NumberDetector {
+    fn is_number(&self) -> bool {
+        self.digits && !self.invalid
+    }
+}
+
+impl Write for NumberDetector {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            match c {
+                '0'..='9' if !self.percent => self.digits = true,
+                '+' | '-' if self.chars == 0 => (),
+                '.' | ',' | '_' if self.digits && !self.percent => (),
+                '%' if self.digits && !self.percent => self.percent = true,
+                _ => self.invalid = true,
+            }
+            self.chars += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Widths (in characters) of the table columns known before the output, so rows are outputted in single pass
+/// instead of being iterated once more to measure them. Cells wider than their columns are truncated or wrapped.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnWidths<'a> {
+    /// Widths of the columns, matched with [Row::KEYS] by index. Columns with no width specified fit their keys.
+    Given(&'a [usize]),
+    /// Total width of the table lines, shared equally between the columns, though every column still fits its key.
+    Budget(usize),
+}
+
+impl<'a> ColumnWidths<'a> {
+    // Returns widths of the columns with the specified keys, regarding the separators of the specified style.
+    fn sizes(&self, keys: &[&str], style: TableStyle) -> Sizes {
+        let keys = keys.iter().map(|key| width(key));
+        match *self {
+            Self::Given(widths) => keys
+                .enumerate()
+                .map(|(index, key)| widths.get(index).copied().unwrap_or(key))
+                .collect(),
+            Self::Budget(budget) => {
+                let columns = keys.len();
+                let space = budget.saturating_sub(style.parts_width(columns));
+                let (share, rest) = match columns {
+                    0 => (0, 0),
+                    _ => (space / columns, space % columns),
+                };
+                keys.enumerate()
+                    .map(|(index, key)| key.max(share + usize::from(index < rest)))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Order of the table rows sorting.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Order {
+    /// Rows with lesser cells go first.
+    #[default]
+    Ascending,
+    /// Rows with greater cells go first.
+    Descending,
+}
+
+// Part of the table which can be colored.
+#[derive(Clone, Copy)]
+enum Part {
+    Header,
+    Rule,
+    Stripe,
+}
+
+// Settings of the table rows sorting.
+#[derive(Clone, Copy)]
+struct Sort {
+    column: usize,
+    order: Order,
+    compare: fn(&str, &str) -> Ordering,
+}
+
+impl Sort {
+    // Returns the given rows sorted by the text of their cells, or as is if there is no column to sort by.
+    fn apply<C: Cells>(&self, rows: Vec<C>, columns: usize) -> Vec<C> {
+        if self.column >= columns {
+            return rows;
+        }
+        let mut keyed: Vec<(String, C)> = rows
+            .into_iter()
+            .map(|row| {
+                let mut text = String::new();
+                let cell = RowCell {
+                    row: &row,
+                    index: self.column,
+                };
+                // String never fails by itself, so any error is caused by the cell output.
+                let _ = text.write_fmt(format_args!("{}", cell));
+                (text, row)
+            })
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| match self.order {
+            Order::Ascending => (self.compare)(a, b),
+            Order::Descending => (self.compare)(b, a),
+        });
+        keyed.into_iter().map(|(_, row)| row).collect()
+    }
+}
+
+/// Lets to output collection of [Row] implementors as table with aligned columns,
+/// header line made of [Row::KEYS] and rule line after it, when outputted in alternate mode.
+/// Otherwise outputs one-line summary of the table size, like `3 rows × 4 cols`.
+/// Line breaks inside cells are outputted as `\n` and `\r` escapes, so they don't break the layout.
+/// Every cell is formatted once per output, so the texts of all the cells are buffered while measuring the rows,
+/// unless the columns widths are known beforehand (see [Table::measured] and [Table::streamed]).
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Table<'a, T: ?Sized> {
+    rows: &'a T,
+    limits: WidthLimits<'a>,
+    wrap: bool,
+    style: TableStyle,
+    header: bool,
+    footer: &'a [&'a [&'a dyn Display]],
+    sort: Option<Sort>,
+    limit: Option<usize>,
+    separated: bool,
+    #[cfg(feature = "color")]
+    colors: Option<TableColors>,
+    widths: Option<ColumnWidths<'a>>,
+    measured: Option<Sizes>,
+    detected: Option<Alignments>,
+    numeric: bool,
+    multiline: bool,
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Creates one [Table] examplar with unlimited column widths, ready to be outputted.
+    pub fn new(rows: &'a T) -> Self {
+        Self {
+            rows,
+            limits: WidthLimits::default(),
+            wrap: false,
+            style: TableStyle::PLAIN,
+            header: true,
+            footer: &[],
+            sort: None,
+            limit: None,
+            separated: false,
+            #[cfg(feature = "color")]
+            colors: None,
+            widths: None,
+            measured: None,
+            detected: None,
+            numeric: false,
+            multiline: false,
+        }
+    }
+
+    /// Makes current [Table] examplar truncate cells wider than the specified width in any column.
+    pub fn max_width(self, width: usize) -> Self {
+        Self {
+            limits: WidthLimits {
+                all: Some(width),
+                ..self.limits
+            },
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar truncate cells wider than the specified widths, matched with [Row::KEYS] by index.
+    pub fn max_widths(self, widths: &'a [usize]) -> Self {
+        Self {
+            limits: WidthLimits {
+                columns: widths,
+                ..self.limits
+            },
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar break cells wider than their column limits into several lines
+    /// instead of truncating them.
+    pub fn wrapped(self) -> Self {
+        Self { wrap: true, ..self }
+    }
+
+    /// Makes current [Table] examplar use the specified style of borders and separators.
+    pub fn styled(self, style: TableStyle) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Makes current [Table] examplar omit the header line and the rule line after it. Columns widths still
+    /// fit the keys, so the output lines up with another table of the same rows type.
+    pub fn headless(self) -> Self {
+        Self {
+            header: false,
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar output the specified rows (like totals or averages) after the usual ones,
+    /// separated from them with rule line. Every footer row consists of cells matched with the columns by index,
+    /// missing cells are left empty. Footer rows are taken into account while calculating columns widths.
+    pub fn with_footer(self, footer: &'a [&'a [&'a dyn Display]]) -> Self {
+        Self { footer, ..self }
+    }
+
+    /// Makes current [Table] examplar output rows sorted by the text of their cells in the column
+    /// with the specified index. Rows are buffered before the output to do so.
+    pub fn sorted_by(self, column: usize, order: Order) -> Self {
+        self.sorted_with(column, order, str::cmp)
+    }
+
+    /// Makes current [Table] examplar output rows sorted by the text of their cells in the column
+    /// with the specified index, compared with the specified function. Rows are buffered before the output to do so.
+    pub fn sorted_with(
+        self,
+        column: usize,
+        order: Order,
+        compare: fn(&str, &str) -> Ordering,
+    ) -> Self {
+        Self {
+            sort: Some(Sort {
+                column,
+                order,
+                compare,
+            }),
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar draw rule line between every two rows too, not only after the header.
+    /// Has no effect on Markdown tables, since it would break their syntax.
+    pub fn row_separated(self) -> Self {
+        Self {
+            separated: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar output only the specified amount of the first rows (after sorting, if any),
+    /// followed by the trailer like `… (+5 rows)` if some rows are omitted. Columns widths are calculated
+    /// over the outputted rows only.
+    pub fn limit(self, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar use the specified columns widths instead of measuring the rows, so they are
+    /// iterated only once. Suits large or non-replayable row sources, like [RowStream]. Width limits still apply,
+    /// and amount of the rows omitted due to [Table::limit] is counted during the same pass.
+    pub fn streamed(self, widths: ColumnWidths<'a>) -> Self {
+        Self {
+            widths: Some(widths),
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar keep line breaks inside cells instead of escaping them, so multi-line values
+    /// (like nested tables outputted in alternate mode) take several lines within their rows, every line being
+    /// aligned within the column, which is as wide as the longest line. Has no effect on Markdown tables,
+    /// since it would break their syntax.
+    pub fn multiline(self) -> Self {
+        Self {
+            multiline: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar align to the right the columns having only numbers in their non-empty cells,
+    /// like `-12`, `3.5`, `1,000` or `42%`, unless [Row::ALIGNMENTS] specify alignment of the column.
+    /// Has no effect in streaming mode (see [Table::streamed]), since it needs one more pass over the rows,
+    /// which is made only once if the table is measured beforehand (see [Table::measured]).
+    pub fn numeric_aligned(self) -> Self {
+        Self {
+            numeric: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Table] examplar measure its rows right away and keep the columns widths (along with
+    /// the columns alignments detected, see [Table::numeric_aligned]), so the table can be outputted repeatedly
+    /// (like periodic status output) without measuring the rows every time. Rows are measured after being sorted
+    /// and limited the same way they are outputted, and cells changed since then are truncated or padded
+    /// to the kept widths. Other settings affect the widths, so this should be called after them;
+    /// use [FilteredTable::measured] to measure only the rows matching the predicate.
+    pub fn measured(self) -> Self
+    where
+        &'a T: IntoIterator,
+        <&'a T as IntoIterator>::Item: Row,
+    {
+        type Item<'a, T> = <&'a T as IntoIterator>::Item;
+        let rows = self.outputted(self.rows.into_iter(), Item::<T>::KEYS.len());
+        self.measure_rows(Item::<T>::KEYS, Item::<T>::ALIGNMENTS, &rows)
+    }
+
+    /// Makes current [Table] examplar color its header, rule lines and every second row with ANSI escape sequences.
+    /// Escape sequences inside cells are not taken into account while calculating columns widths.
+    /// Use [TableColors::resolved] to get no colors when the output is not expected to be colored.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "color"))))]
+    pub fn colored(self, colors: TableColors) -> Self {
+        Self {
+            colors: Some(colors),
+            ..self
+        }
+    }
+
+    /// Returns [Csv] examplar outputting the same rows as comma-separated values.
+    pub fn to_csv(&self) -> Csv<'a, T> {
+        Csv::new(self.rows, ',')
+    }
+
+    /// Returns [Csv] examplar outputting the same rows as tab-separated values.
+    pub fn to_tsv(&self) -> Csv<'a, T> {
+        Csv::new(self.rows, '\t')
+    }
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    // Returns the specified rows sorted and limited the same way they are outputted.
+    fn outputted<I>(&self, rows: I, columns: usize) -> Vec<I::Item>
+    where
+        I: Iterator,
+        I::Item: Cells,
+    {
+        let limit = self.limit.unwrap_or(usize::MAX);
+        match self.sort {
+            Some(sort) => {
+                let mut rows = sort.apply(rows.collect(), columns);
+                rows.truncate(limit);
+                rows
+            }
+            None => rows.take(limit).collect(),
+        }
+    }
+
+    // Returns current table keeping the columns widths and alignments needed to output the specified rows.
+    fn measure_rows<C: Cells>(self, keys: &[&str], alignments: &[Align], rows: &[C]) -> Self {
+        let sizes = match self.style.markdown {
+            true => self.measure_sizes(keys, rows.iter().map(ByRef).map(MarkdownRow), column_sizes),
+            false => self.measure_sizes(keys, rows.iter().map(ByRef), column_sizes),
+        };
+        self.keep_measured(keys, alignments, rows, sizes)
+    }
+
+    // Returns current table keeping the specified columns widths along with the columns alignments
+    // detected over the specified rows, if detection is enabled.
+    fn keep_measured<C: Cells>(
+        self,
+        keys: &[&str],
+        alignments: &[Align],
+        rows: &[C],
+        sizes: Sizes,
+    ) -> Self {
+        let rows = rows.iter().map(ByRef);
+        let detected = match (self.is_numeric(), self.style.markdown) {
+            (false, _) => None,
+            (true, true) => Some(numeric_alignments(keys, alignments, rows.map(MarkdownRow))),
+            (true, false) => Some(numeric_alignments(keys, alignments, rows)),
+        };
+        Self {
+            measured: Some(sizes),
+            detected,
+            ..self
+        }
+    }
+
+    // Returns whether numeric columns are detected, which is impossible in streaming mode.
+    fn is_numeric(&self) -> bool {
+        self.numeric && self.widths.is_none()
+    }
+
+    // Returns whether line breaks inside cells are kept, which is impossible in Markdown tables.
+    fn is_multiline(&self) -> bool {
+        self.multiline && !self.style.markdown
+    }
+
+    // Writes texts of the row cells into the specified ones, with line breaks inside them escaped unless they are kept.
+    fn write_texts<R: Cells>(&self, row: &R, texts: &mut Texts, columns: usize) -> FmtResult {
+        let multiline = self.is_multiline();
+        texts.resize_with(columns, String::new);
+        for (index, text) in texts.iter_mut().enumerate() {
+            text.clear();
+            match multiline {
+                true => text.write_fmt(format_args!("{}", RawCell { row, index }))?,
+                false => text.write_fmt(format_args!("{}", RowCell { row, index }))?,
+            }
+        }
+        Ok(())
+    }
+
+    // Returns texts of the row cells, with line breaks inside them escaped unless they are kept.
+    fn texts<R: Cells>(&self, row: &R, columns: usize) -> Result<Texts, Error> {
+        let mut texts = Texts::new();
+        self.write_texts(row, &mut texts, columns)?;
+        Ok(texts)
+    }
+
+    // Outputs one row, taking several physical lines if wrapping is enabled or cells have line breaks inside.
+    fn write_row(
+        &self,
+        f: &mut Formatter<'_>,
+        texts: &[String],
+        sizes: &[usize],
+        alignments: &[Align],
+    ) -> FmtResult {
+        let multiline = self.is_multiline();
+        let lines = match self.wrap || multiline {
+            true => row_lines(texts, sizes, self.wrap),
+            false => 1,
+        };
+        // Every line of the cell is written into this buffer before being outputted, so it is allocated
+        // at most once per row.
+        let mut buffer = CellBuffer::default();
+        for line in 0..lines {
+            if line > 0 {
+                f.write_char('\n')?;
+            }
+            self.style.write_row_start(f)?;
+            for (index, (text, size)) in texts.iter().zip(sizes).enumerate() {
+                if index > 0 {
+                    self.style.write_row_separator(f)?;
+                }
+                let align = alignments.get(index).copied().unwrap_or_default();
+                match self.wrap || multiline {
+                    true => {
+                        let cell = CellLine {
+                            text,
+                            width: self.wrap.then_some(*size),
+                            line,
+                        };
+                        buffer.clear();
+                        buffer.write_fmt(format_args!("{}", cell))?;
+                        write_aligned(f, buffer.as_str(), *size, align)?
+                    }
+                    false => write_aligned(f, text, *size, align)?,
+                }
+            }
+            self.style.write_row_end(f)?;
+        }
+        Ok(())
+    }
+
+    // Outputs header, rule line and all the rows, with rows being obtained anew on every iteration.
+    // Every cell is formatted only once: rows are buffered if they are measured or their numeric columns are detected,
+    // and outputted right away otherwise.
+    fn write_table<I, F>(
+        &self,
+        f: &mut Formatter<'_>,
+        keys: &[&str],
+        alignments: &[Align],
+        rows: F,
+    ) -> FmtResult
+    where
+        I: Iterator,
+        I::Item: Cells,
+        F: Fn() -> I,
+    {
+        let columns = keys.len();
+        let known = match (&self.measured, self.widths) {
+            (Some(measured), _) if measured.len() == columns => Some(measured.clone()),
+            (_, Some(widths)) => Some(clamp_sizes(widths.sizes(keys, self.style), &self.limits)),
+            _ => None,
+        };
+        let cached = self
+            .detected
+            .as_ref()
+            .filter(|cached| cached.len() == columns);
+        let footer = self
+            .footer
+            .iter()
+            .map(|row| match self.style.markdown {
+                true => self.texts(&MarkdownRow(FooterRow(row)), columns),
+                false => self.texts(&FooterRow(row), columns),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match (known, cached.is_some() || !self.is_numeric()) {
+            (Some(sizes), true) => {
+                let alignments = cached.map_or(alignments, |cached| cached);
+                let mut rows = rows();
+                self.write_body(f, keys, &sizes, alignments, &footer, |texts| {
+                    match rows.next() {
+                        Some(row) => self.write_texts(&row, texts, columns).map(|_| true),
+                        None => Ok(false),
+                    }
+                })
+            }
+            (known, _) => {
+                let buffered = rows()
+                    .map(|row| self.texts(&row, columns))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let sizes = match known {
+                    Some(sizes) => sizes,
+                    None => self.text_sizes(keys, buffered.iter().chain(&footer)),
+                };
+                let detected;
+                let alignments = match cached {
+                    Some(cached) => cached,
+                    None if self.is_numeric() => {
+                        let rows = buffered.iter().map(|texts| TextRow(texts));
+                        detected = numeric_alignments(keys, alignments, rows);
+                        &detected
+                    }
+                    None => alignments,
+                };
+                let mut rows = buffered.into_iter();
+                self.write_body(f, keys, &sizes, alignments, &footer, |texts| {
+                    Ok(rows.next().map(|row| *texts = row).is_some())
+                })
+            }
+        }
+    }
+
+    // Returns widths of the columns needed to output the specified texts of the rows, clamped with the limits.
+    fn text_sizes<'t, I: Iterator<Item = &'t Texts>>(&self, keys: &[&str], rows: I) -> Sizes {
+        let multiline = self.is_multiline();
+        let mut sizes = key_sizes(keys);
+        for texts in rows {
+            for (size, text) in sizes.iter_mut().zip(texts) {
+                *size = (*size).max(text_width(text, multiline));
+            }
+        }
+        clamp_sizes(sizes, &self.limits)
+    }
+
+    // Outputs header, rule line, the rows obtained with the specified function one by one and the footer.
+    // The function writes texts of the next row into the specified ones, or returns false if there are no more rows.
+    fn write_body<N>(
+        &self,
+        f: &mut Formatter<'_>,
+        keys: &[&str],
+        sizes: &[usize],
+        alignments: &[Align],
+        footer: &[Texts],
+        mut next_row: N,
+    ) -> FmtResult
+    where
+        N: FnMut(&mut Texts) -> Result<bool, Error>,
+    {
+        let mut first = true;
+        if self.style.top.is_some() {
+            self.paint(f, Part::Rule, |f| self.style.write_top(f, sizes))?;
+            first = false;
+        }
+        if self.header {
+            let header = self.texts(&Header(keys), keys.len())?;
+            line_break(f, &mut first)?;
+            self.paint(f, Part::Header, |f| {
+                self.write_row(f, &header, sizes, alignments)
+            })?;
+            f.write_char('\n')?;
+            self.paint(f, Part::Rule, |f| {
+                self.style.write_rule(f, sizes, alignments)
+            })?;
+        }
+        let mut texts = Texts::new();
+        let mut index = 0;
+        while next_row(&mut texts)? {
+            if index > 0 && self.separated && !self.style.markdown {
+                f.write_char('\n')?;
+                self.paint(f, Part::Rule, |f| {
+                    self.style.write_rule(f, sizes, alignments)
+                })?;
+            }
+            line_break(f, &mut first)?;
+            match index % 2 {
+                1 => self.paint(f, Part::Stripe, |f| {
+                    self.write_row(f, &texts, sizes, alignments)
+                })?,
+                _ => self.write_row(f, &texts, sizes, alignments)?,
+            }
+            index += 1;
+        }
+        self.write_footer(f, footer, sizes, alignments, &mut first)?;
+        if self.style.bottom.is_some() {
+            line_break(f, &mut first)?;
+            self.paint(f, Part::Rule, |f| self.style.write_bottom(f, sizes))?;
+        }
+        Ok(())
+    }
+
+    // Outputs whatever the given function outputs, colored as the specified table part if colors are set.
+    #[cfg(feature = "color")]
+    fn paint<W>(&self, f: &mut Formatter<'_>, part: Part, write: W) -> FmtResult
+    where
+        W: FnOnce(&mut Formatter<'_>) -> FmtResult,
+    {
+        let colors = self.colors.as_ref().and_then(|colors| colors.part(part));
+        color::write_painted(f, colors, write)
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn paint<W>(&self, f: &mut Formatter<'_>, _part: Part, write: W) -> FmtResult
+    where
+        W: FnOnce(&mut Formatter<'_>) -> FmtResult,
+    {
+        write(f)
+    }
+
+    // Returns widths of the columns needed to output the specified rows, measured with the specified function,
+    // along with the footer ones.
+    fn measure_sizes<I, M>(&self, keys: &[&str], rows: I, measure: M) -> Sizes
+    where
+        I: Iterator,
+        I::Item: Cells,
+        M: FnOnce(&[&str], I, &WidthLimits<'_>, bool) -> Sizes,
+    {
+        let multiline = self.is_multiline();
+        let mut sizes = measure(keys, rows, &self.limits, multiline);
+        if !self.footer.is_empty() {
+            let footer = self.footer.iter().map(|row| FooterRow(row));
+            let footer_sizes = match self.style.markdown {
+                true => column_sizes(keys, footer.map(MarkdownRow), &self.limits, false),
+                false => column_sizes(keys, footer, &self.limits, multiline),
+            };
+            for (size, footer_size) in sizes.iter_mut().zip(footer_sizes) {
+                *size = (*size).max(footer_size);
+            }
+        }
+        sizes
+    }
+
+    // Outputs footer rows along with the rule line before them, if there are any. Markdown tables get no rule line
+    // since it would break their syntax.
+    fn write_footer(
+        &self,
+        f: &mut Formatter<'_>,
+        footer: &[Texts],
+        sizes: &[usize],
+        alignments: &[Align],
+        first: &mut bool,
+    ) -> FmtResult {
+        if footer.is_empty() {
+            return Ok(());
+        }
+        if !self.style.markdown {
+            line_break(f, first)?;
+            self.paint(f, Part::Rule, |f| {
+                self.style.write_rule(f, sizes, alignments)
+            })?;
+        }
+        for texts in footer {
+            line_break(f, first)?;
+            self.write_row(f, texts, sizes, alignments)?;
+        }
+        Ok(())
+    }
+
+    // Outputs either the table or its summary regarding the alternate mode, sorting the rows if needed.
+    fn display<I, F>(
+        &self,
+        f: &mut Formatter<'_>,
+        keys: &[&str],
+        alignments: &[Align],
+        rows: F,
+    ) -> FmtResult
+    where
+        I: Iterator,
+        I::Item: Cells,
+        F: Fn() -> I,
+    {
+        match (f.alternate(), self.sort) {
+            (true, None) => self.write_limited(f, keys, alignments, rows),
+            (true, Some(sort)) => {
+                let rows = sort.apply(rows().collect(), keys.len());
+                self.write_limited(f, keys, alignments, || rows.iter().map(ByRef))
+            }
+            (false, _) => write_summary(f, rows().count(), keys.len()),
+        }
+    }
+
+    // Outputs the table with only limited amount of rows, if required, along with the trailer about the rest.
+    fn write_limited<I, F>(
+        &self,
+        f: &mut Formatter<'_>,
+        keys: &[&str],
+        alignments: &[Align],
+        rows: F,
+    ) -> FmtResult
+    where
+        I: Iterator,
+        I::Item: Cells,
+        F: Fn() -> I,
+    {
+        let limit = match self.limit {
+            Some(limit) => limit,
+            None => return self.write_styled(f, keys, alignments, rows),
+        };
+        let rest = match self.widths {
+            // Rows are iterated only once, so the omitted ones are counted while skipping them.
+            Some(_) => {
+                let rest = core::cell::Cell::new(0);
+                self.write_styled(f, keys, alignments, || {
+                    rows()
+                        .enumerate()
+                        .filter_map(|(index, row)| match index < limit {
+                            true => Some(row),
+                            false => {
+                                rest.set(rest.get() + 1);
+                                None
+                            }
+                        })
+                })?;
+                rest.get()
+            }
+            None => {
+                self.write_styled(f, keys, alignments, || rows().take(limit))?;
+                rows().skip(limit).count()
+            }
+        };
+        match rest {
+            0 => Ok(()),
+            rest => f.write_fmt(format_args!(
+                "\n… (+{} {})",
+                rest,
+                plural(rest, "row", "rows")
+            )),
+        }
+    }
+
+    // Outputs the table in the current style, escaping cells if the style requires so.
+    fn write_styled<I, F>(
+        &self,
+        f: &mut Formatter<'_>,
+        keys: &[&str],
+        alignments: &[Align],
+        rows: F,
+    ) -> FmtResult
+    where
+        I: Iterator,
+        I::Item: Cells,
+        F: Fn() -> I,
+    {
+        match self.style.markdown {
+            true => self.write_table(f, keys, alignments, || rows().map(MarkdownRow)),
+            false => self.write_table(f, keys, alignments, rows),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Display for Table<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Row,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        type Item<'a, T> = <&'a T as IntoIterator>::Item;
+        self.display(f, Item::<T>::KEYS, Item::<T>::ALIGNMENTS, || {
+            self.rows.into_iter()
+        })
+    }
+}
+
+// Returns amount of physical lines needed to output the row with its cells wrapped to the specified widths,
+// if required, and split at line breaks inside them, if they are kept.
+fn row_lines(texts: &[String], sizes: &[usize], wrap: bool) -> usize {
+    texts
+        .iter()
+        .zip(sizes)
+        .map(|(text, size)| {
+            let mut splitter = LineSplitter::new(None, wrap.then_some(*size), 0);
+            // LineSplitter never fails without inner formatter, just like WriteCounter.
+            let _ = splitter.write_str(text);
+            splitter.current + 1
+        })
+        .fold(1, usize::max)
+}
+
+// Outputs line break before every line except the first one.
+fn line_break(f: &mut Formatter<'_>, first: &mut bool) -> FmtResult {
+    match core::mem::replace(first, false) {
+        true => Ok(()),
+        false => f.write_char('\n'),
+    }
+}
+
+// Outputs one-line summary of the table size.
+fn write_summary(f: &mut Formatter<'_>, rows: usize, columns: usize) -> FmtResult {
+    f.write_fmt(format_args!(
+        "{} {} × {} {}",
+        rows,
+        plural(rows, "row", "rows"),
+        columns,
+        plural(columns, "col", "cols")
+    ))
+}
+
+// Chooses the word form matching the specified amount.
+fn plural(amount: usize, one: &'static str, many: &'static str) -> &'static str {
+    match amount {
+        1 => one,
+        _ => many,
+    }
+}
+
+/// Lets to output only those rows of the collection which match the predicate, as [Table] does.
+/// Is obtained with [Table::filter]; columns widths are calculated over the matching rows only. The texts of the cells
+/// of the matching rows are buffered while measuring them, unless the columns widths are known beforehand
+/// (see [FilteredTable::measured] and [Table::streamed]).
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct FilteredTable<'a, T: ?Sized, P> {
+    table: Table<'a, T>,
+    predicate: P,
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Returns [FilteredTable] examplar outputting only the rows matching the specified predicate,
+    /// with the same settings as current [Table] examplar has.
+    pub fn filter<P>(self, predicate: P) -> FilteredTable<'a, T, P>
+    where
+        &'a T: IntoIterator,
+        P: Fn(&<&'a T as IntoIterator>::Item) -> bool,
+    {
+        FilteredTable {
+            table: self,
+            predicate,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, P> FilteredTable<'a, T, P>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Row,
+    P: Fn(&<&'a T as IntoIterator>::Item) -> bool,
+{
+    /// Makes current [FilteredTable] examplar measure the rows matching the predicate right away and keep
+    /// the columns widths, just like [Table::measured] does.
+    pub fn measured(self) -> Self {
+        type Item<'a, T> = <&'a T as IntoIterator>::Item;
+        let rows = self.table.outputted(
+            self.table.rows.into_iter().filter(&self.predicate),
+            Item::<T>::KEYS.len(),
+        );
+        Self {
+            table: self
+                .table
+                .measure_rows(Item::<T>::KEYS, Item::<T>::ALIGNMENTS, &rows),
+            predicate: self.predicate,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, P> Display for FilteredTable<'a, T, P>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Row,
+    P: Fn(&<&'a T as IntoIterator>::Item) -> bool,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        type Item<'a, T> = <&'a T as IntoIterator>::Item;
+        self.table
+            .display(f, Item::<T>::KEYS, Item::<T>::ALIGNMENTS, || {
+                self.table.rows.into_iter().filter(&self.predicate)
+            })
+    }
+}
+
+/// Lets to output any collection of [Row] implementors as [Table].
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub trait AsTable {
+    /// Returns [Table] examplar outputting current collection.
+    fn as_table(&self) -> Table<'_, Self>;
+}
+
+impl<T: ?Sized> AsTable for T
+where
+    for<'a> &'a T: IntoIterator,
+    for<'a> <&'a T as IntoIterator>::Item: Row,
+{
+    fn as_table(&self) -> Table<'_, Self> {
+        Table::new(self)
+    }
+}
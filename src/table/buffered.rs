@@ -0,0 +1,74 @@
+use super::{Row, Table};
+use alloc::vec::Vec;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FromIterator,
+    slice::Iter,
+};
+
+/// Buffer of rows collected from any iterator yielding owned [Row] implementors, like adapters and generators
+/// which can not be iterated twice as [Table] needs. Outputs the same way [Table] with default settings does,
+/// and can be turned into [Table] to be configured.
+/// ```
+/// use core::fmt::{Display, Formatter, Result as FmtResult};
+/// use cubob::{Row, TableRows};
+///
+/// struct Square(u32);
+///
+/// impl Row for Square {
+///     const KEYS: &'static [&'static str] = &["n", "square"];
+///
+///     fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+///         match index {
+///             0 => Display::fmt(&self.0, f),
+///             _ => Display::fmt(&(self.0 * self.0), f),
+///         }
+///     }
+/// }
+///
+/// let squares: TableRows<_> = (1..4).map(Square).collect();
+/// assert_eq!("3 rows × 2 cols", &format!("{}", squares));
+/// assert_eq!(
+///     "n | square\n--+-------\n1 | 1     \n2 | 4     \n3 | 9     ",
+///     &format!("{:#}", squares)
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct TableRows<R> {
+    rows: Vec<R>,
+}
+
+impl<R> TableRows<R> {
+    /// Creates one [TableRows] examplar buffering all the rows from the specified source.
+    pub fn new<I: IntoIterator<Item = R>>(rows: I) -> Self {
+        Self {
+            rows: rows.into_iter().collect(),
+        }
+    }
+
+    /// Returns [Table] examplar outputting buffered rows.
+    pub fn table(&self) -> Table<'_, Self> {
+        Table::new(self)
+    }
+}
+
+impl<R> FromIterator<R> for TableRows<R> {
+    fn from_iter<I: IntoIterator<Item = R>>(rows: I) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<'a, R> IntoIterator for &'a TableRows<R> {
+    type Item = &'a R;
+    type IntoIter = Iter<'a, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+impl<R: Row> Display for TableRows<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.table(), f)
+    }
+}
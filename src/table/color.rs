@@ -0,0 +1,72 @@
+use super::Part;
+use core::fmt::{Formatter, Result as FmtResult};
+
+/// ANSI colors of the [Table][super::Table] parts, each one being the parameters of SGR escape sequence
+/// (like `1` for bold or `48;5;236` for dark grey background). Parts with no parameters specified are not colored.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "color"))))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableColors {
+    /// Colors of the header line.
+    pub header: Option<&'static str>,
+    /// Colors of the rule lines and borders.
+    pub rule: Option<&'static str>,
+    /// Colors of every second row, so rows are easier to track visually.
+    pub stripe: Option<&'static str>,
+}
+
+impl TableColors {
+    /// Bold header, dimmed rule lines and dark grey background of every second row.
+    pub const DEFAULT: Self = Self {
+        header: Some("1"),
+        rule: Some("2"),
+        stripe: Some("48;5;236"),
+    };
+    /// No colors at all, so the table is outputted as if it was not colored.
+    pub const PLAIN: Self = Self {
+        header: None,
+        rule: None,
+        stripe: None,
+    };
+
+    /// Returns current colors if the colored output is allowed according to [ColorChoice][crate::ColorChoice],
+    /// or [TableColors::PLAIN] otherwise.
+    pub fn resolved(self) -> Self {
+        match crate::ColorChoice::enabled() {
+            true => self,
+            false => Self::PLAIN,
+        }
+    }
+}
+
+impl TableColors {
+    // Returns colors of the specified table part, if any.
+    pub(super) fn part(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::Header => self.header,
+            Part::Rule => self.rule,
+            Part::Stripe => self.stripe,
+        }
+    }
+}
+
+impl Default for TableColors {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// Outputs whatever the given function outputs, surrounded with the escape sequences setting and resetting colors.
+pub(super) fn write_painted(
+    f: &mut Formatter<'_>,
+    colors: Option<&'static str>,
+    write: impl FnOnce(&mut Formatter<'_>) -> FmtResult,
+) -> FmtResult {
+    match colors {
+        Some(colors) => {
+            f.write_fmt(format_args!("\x1b[{}m", colors))?;
+            write(f)?;
+            f.write_str("\x1b[0m")
+        }
+        None => write(f),
+    }
+}
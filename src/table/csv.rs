@@ -0,0 +1,94 @@
+use super::{RawCell, Row};
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+/// Lets to output collection of [Row] implementors as CSV (or TSV): header made of [Row::KEYS] and then the rows,
+/// with cells quoted if they contain delimiter, quotes or line breaks. No alignment, width limits or alternate mode
+/// are applied. Is obtained with [Table::to_csv][super::Table::to_csv] or [Table::to_tsv][super::Table::to_tsv].
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Csv<'a, T: ?Sized> {
+    rows: &'a T,
+    delimiter: char,
+}
+
+impl<'a, T: ?Sized> Csv<'a, T> {
+    /// Creates one [Csv] examplar with the specified delimiter between cells.
+    pub fn new(rows: &'a T, delimiter: char) -> Self {
+        Self { rows, delimiter }
+    }
+}
+
+impl<'a, T: ?Sized> Display for Csv<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Row,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let keys = <<&'a T as IntoIterator>::Item as Row>::KEYS;
+        for (index, key) in keys.iter().enumerate() {
+            if index > 0 {
+                f.write_char(self.delimiter)?;
+            }
+            self.write_cell(f, key)?;
+        }
+        for row in self.rows {
+            f.write_char('\n')?;
+            for index in 0..keys.len() {
+                if index > 0 {
+                    f.write_char(self.delimiter)?;
+                }
+                self.write_cell(f, &RawCell { row: &row, index })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: ?Sized> Csv<'a, T> {
+    // Outputs the cell as is or, if it contains special characters, quoted with inner quotes doubled.
+    fn write_cell(&self, f: &mut Formatter<'_>, val: &dyn Display) -> FmtResult {
+        let mut detector = SpecialDetector {
+            delimiter: self.delimiter,
+            found: false,
+        };
+        // SpecialDetector never fails by itself, just like WriteCounter.
+        let _ = detector.write_fmt(format_args!("{}", val));
+        if !detector.found {
+            return f.write_fmt(format_args!("{}", val));
+        }
+        f.write_char('"')?;
+        QuoteEscaper { inner: f }.write_fmt(format_args!("{}", val))?;
+        f.write_char('"')
+    }
+}
+
+// Checks whether the output written into it contains characters which require the cell to be quoted.
+struct SpecialDetector {
+    delimiter: char,
+    found: bool,
+}
+
+impl Write for SpecialDetector {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.found |= s
+            .chars()
+            .any(|c| c == self.delimiter || c == '"' || c == '\n' || c == '\r');
+        Ok(())
+    }
+}
+
+// Doubles quotes written into it.
+struct QuoteEscaper<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+}
+
+impl<'a, 'b> Write for QuoteEscaper<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            if c == '"' {
+                self.inner.write_char('"')?;
+            }
+            self.inner.write_char(c)?;
+        }
+        Ok(())
+    }
+}
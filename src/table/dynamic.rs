@@ -0,0 +1,107 @@
+use super::{Cells, Table};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Lets to output some type as table row with columns known only at runtime, like map-shaped values.
+/// Unlike [Row][super::Row], different rows of the same type can have different sets of columns.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub trait DynRow {
+    /// Returns keys of the columns current row has cells in.
+    fn keys(&self) -> impl Iterator<Item = &str>;
+
+    /// Outputs the cell of the column with the specified key, or nothing if current row has no such column.
+    fn cell(&self, key: &str, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<R: DynRow + ?Sized> DynRow for &R {
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        (**self).keys()
+    }
+
+    fn cell(&self, key: &str, f: &mut Formatter<'_>) -> FmtResult {
+        (**self).cell(key, f)
+    }
+}
+
+impl<K: Borrow<str> + Ord, V: Display> DynRow for BTreeMap<K, V> {
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys().map(Borrow::borrow)
+    }
+
+    fn cell(&self, key: &str, f: &mut Formatter<'_>) -> FmtResult {
+        match self.get(key) {
+            Some(val) => Display::fmt(val, f),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "std"))))]
+impl<K, V, S> DynRow for std::collections::HashMap<K, V, S>
+where
+    K: Borrow<str> + Eq + core::hash::Hash,
+    V: Display,
+    S: core::hash::BuildHasher,
+{
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys().map(Borrow::borrow)
+    }
+
+    fn cell(&self, key: &str, f: &mut Formatter<'_>) -> FmtResult {
+        match self.get(key) {
+            Some(val) => Display::fmt(val, f),
+            None => Ok(()),
+        }
+    }
+}
+
+// Lets to output cells of the dynamic row by column index.
+struct DynCells<'a, 'k, R> {
+    row: &'a R,
+    keys: &'a [&'k str],
+}
+
+impl<'a, 'k, R: DynRow> Cells for DynCells<'a, 'k, R> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        DynRow::cell(self.row, self.keys[index], f)
+    }
+}
+
+/// Lets to output collection of [DynRow] implementors as table, with columns being the union of all the rows keys
+/// in order of their first appearance. Is obtained with [Table::dynamic], so it is configured the same way;
+/// every column is aligned to the left.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct DynTable<'a, T: ?Sized> {
+    table: Table<'a, T>,
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Returns [DynTable] examplar outputting the same collection with the same settings,
+    /// but treating its items as [DynRow] implementors.
+    pub fn dynamic(self) -> DynTable<'a, T> {
+        DynTable { table: self }
+    }
+}
+
+impl<'a, T: ?Sized> Display for DynTable<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: DynRow,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let rows: Vec<_> = self.table.rows.into_iter().collect();
+        let mut keys: Vec<&str> = Vec::new();
+        for key in rows.iter().flat_map(DynRow::keys) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        self.table.display(f, &keys, &[], || {
+            rows.iter().map(|row| DynCells { row, keys: &keys })
+        })
+    }
+}
@@ -0,0 +1,189 @@
+use super::{write_summary, Cell};
+use crate::{measure::width, Align};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+// Lets to output the value of the cell with the cell formatter of the grid.
+struct Formatted<'g, T> {
+    val: &'g T,
+    format: fn(&T, &mut Formatter<'_>) -> FmtResult,
+}
+
+impl<'g, T> Display for Formatted<'g, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        (self.format)(self.val, f)
+    }
+}
+
+// Returns the output of the header with the specified index, or empty string if there is no such header.
+fn header(headers: &[&dyn Display], index: usize) -> String {
+    headers
+        .get(index)
+        .map_or_else(String::new, |header| format!("{}", header))
+}
+
+/// Lets to output 2D data (like matrix or any other values addressed by row and column) with aligned columns
+/// and optional row and column headers, when outputted in alternate mode. Otherwise outputs one-line summary
+/// of the grid size, like `3 rows × 4 cols`. Unlike [Table][super::Table], needs no [Row][super::Row]
+/// implementation: cells are outputted with the cell formatter, which is their [Display] implementation by default,
+/// missing cells are left empty, and every column is aligned to the right unless specified otherwise.
+///
+/// Usage example:
+/// ```
+/// use cubob::Grid;
+///
+/// let grid = Grid::from_rows(&[[1, 20, 3], [40, 5, 600]])
+///     .row_headers(&[&"x", &"y"])
+///     .column_headers(&[&"a", &"b", &"c"]);
+/// assert_eq!("   a  b   c\nx  1 20   3\ny 40  5 600", &format!("{:#}", grid));
+/// assert_eq!("2 rows × 3 cols", &format!("{}", grid));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Grid<'a, T> {
+    rows: Vec<Vec<Option<T>>>,
+    columns: usize,
+    row_headers: &'a [&'a dyn Display],
+    column_headers: &'a [&'a dyn Display],
+    format: fn(&T, &mut Formatter<'_>) -> FmtResult,
+    align: Align,
+}
+
+impl<'a, T: Display> Grid<'a, T> {
+    /// Creates one [Grid] examplar outputting the specified rows, every one being some iterable of cells,
+    /// like slice of slices or vector of arrays.
+    pub fn from_rows<I, R>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let rows: Vec<Vec<Option<T>>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Some).collect())
+            .collect();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        Self::new(rows, columns)
+    }
+
+    /// Creates one [Grid] examplar outputting the specified cells, every one addressed by its row and column indices.
+    /// The cells not specified are left empty, the cell specified more than once is outputted with its last value.
+    pub fn from_cells<I: IntoIterator<Item = (usize, usize, T)>>(cells: I) -> Self {
+        let mut rows: Vec<Vec<Option<T>>> = Vec::new();
+        let mut columns = 0;
+        for (row, column, val) in cells {
+            if rows.len() <= row {
+                rows.resize_with(row + 1, Vec::new);
+            }
+            let row = &mut rows[row];
+            if row.len() <= column {
+                row.resize_with(column + 1, || None);
+            }
+            row[column] = Some(val);
+            columns = columns.max(column + 1);
+        }
+        Self::new(rows, columns)
+    }
+
+    fn new(rows: Vec<Vec<Option<T>>>, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            row_headers: &[],
+            column_headers: &[],
+            format: T::fmt,
+            align: Align::Right,
+        }
+    }
+}
+
+impl<'a, T> Grid<'a, T> {
+    /// Makes current [Grid] examplar output the specified headers before the rows, matched with them by index.
+    pub fn row_headers(self, row_headers: &'a [&'a dyn Display]) -> Self {
+        Self {
+            row_headers,
+            ..self
+        }
+    }
+
+    /// Makes current [Grid] examplar output the specified headers above the columns, matched with them by index.
+    pub fn column_headers(self, column_headers: &'a [&'a dyn Display]) -> Self {
+        Self {
+            column_headers,
+            ..self
+        }
+    }
+
+    /// Makes current [Grid] examplar output the cells with the specified function instead of their [Display]
+    /// implementation, e.g. to output numbers with fixed precision.
+    pub fn formatted_with(self, format: fn(&T, &mut Formatter<'_>) -> FmtResult) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Makes current [Grid] examplar align every column (along with its header) with the specified alignment.
+    pub fn aligned(self, align: Align) -> Self {
+        Self { align, ..self }
+    }
+}
+
+impl<'a, T> Display for Grid<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let rows = self.rows.len().max(self.row_headers.len());
+        let columns = self.columns.max(self.column_headers.len());
+        if !f.alternate() {
+            return write_summary(f, rows, columns);
+        }
+        let cells: Vec<Vec<String>> = (0..rows)
+            .map(|row| {
+                (0..columns)
+                    .map(|column| {
+                        let val = self.rows.get(row).and_then(|row| row.get(column));
+                        match val.and_then(Option::as_ref) {
+                            Some(val) => format!(
+                                "{}",
+                                Formatted {
+                                    val,
+                                    format: self.format
+                                }
+                            ),
+                            None => String::new(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let labels: Vec<String> = (0..rows).map(|row| header(self.row_headers, row)).collect();
+        let headers: Vec<String> = (0..columns)
+            .map(|column| header(self.column_headers, column))
+            .collect();
+        let label_width = labels.iter().map(|label| width(label)).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                cells
+                    .iter()
+                    .map(|row| width(&row[column]))
+                    .fold(width(&headers[column]), usize::max)
+            })
+            .collect();
+        // Column headers line goes first, with no row header before it.
+        let head = (!self.column_headers.is_empty()).then_some((String::new(), &headers));
+        for (index, (label, line)) in head
+            .into_iter()
+            .chain(labels.into_iter().zip(&cells))
+            .enumerate()
+        {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            if !self.row_headers.is_empty() {
+                Cell::new(&label, label_width).fmt(f)?;
+                f.write_char(' ')?;
+            }
+            for (column, (text, width)) in line.iter().zip(&widths).enumerate() {
+                if column > 0 {
+                    f.write_char(' ')?;
+                }
+                Cell::new(text, *width).aligned(self.align).fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
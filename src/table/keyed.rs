@@ -0,0 +1,84 @@
+use super::{Cells, Row, Table};
+use crate::Align;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Lets to output some type as table row prepended with key column, like entries of maps with [Row] implementors
+/// as values. Is implemented for any pair of displayable key and [Row] implementor.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub trait KeyedRow {
+    /// Type of the key.
+    type Key: Display;
+    /// Type of the rest of the row.
+    type Row: Row;
+
+    /// Returns the key to output in the first column.
+    fn key(&self) -> &Self::Key;
+
+    /// Returns the rest of the row to output in the other columns.
+    fn row(&self) -> &Self::Row;
+}
+
+impl<K: Display, R: Row> KeyedRow for (K, R) {
+    type Key = K;
+    type Row = R;
+
+    fn key(&self) -> &Self::Key {
+        &self.0
+    }
+
+    fn row(&self) -> &Self::Row {
+        &self.1
+    }
+}
+
+// Lets to output the key of the row in the first column and the rest of it in the other ones.
+struct KeyedCells<P>(P);
+
+impl<P: KeyedRow> Cells for KeyedCells<P> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        match index {
+            0 => Display::fmt(self.0.key(), f),
+            _ => Row::cell(self.0.row(), index - 1, f),
+        }
+    }
+}
+
+/// Lets to output collection of [KeyedRow] implementors (like [BTreeMap][alloc::collections::BTreeMap]
+/// or [HashMap][std::collections::HashMap] with [Row] implementors as values) as table with the keys column first.
+/// Is obtained with [Table::keyed], so it is configured the same way.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct KeyedTable<'a, T: ?Sized> {
+    table: Table<'a, T>,
+    header: &'a str,
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Returns [KeyedTable] examplar outputting the same collection with the same settings,
+    /// treating its items as [KeyedRow] implementors and using the specified header for the keys column.
+    pub fn keyed(self, header: &'a str) -> KeyedTable<'a, T> {
+        KeyedTable {
+            table: self,
+            header,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Display for KeyedTable<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: KeyedRow,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        type Inner<'a, T> = <<&'a T as IntoIterator>::Item as KeyedRow>::Row;
+        let mut keys = Vec::with_capacity(Inner::<T>::KEYS.len() + 1);
+        keys.push(self.header);
+        keys.extend_from_slice(Inner::<T>::KEYS);
+        let mut alignments = Vec::with_capacity(Inner::<T>::ALIGNMENTS.len() + 1);
+        alignments.push(Align::Left);
+        alignments.extend_from_slice(Inner::<T>::ALIGNMENTS);
+        self.table.display(f, &keys, &alignments, || {
+            self.table.rows.into_iter().map(KeyedCells)
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use super::{
+    clamp_sizes, column_sizes, key_sizes, widen_sizes, ByRef, Cells, MarkdownRow, Row, Sizes,
+    Table, WidthLimits,
+};
+use alloc::vec::Vec;
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+// Amount of rows starting from which the table measures them in parallel, since fewer rows are measured faster
+// than the work is shared between the threads.
+const PARALLEL_ROWS: usize = 1024;
+
+/// Returns widths (in characters) of the columns needed to output the specified rows along with their headers,
+/// clamped with the specified limits, just like [sizes_list][super::sizes_list] does, but measuring the rows
+/// in parallel on the rayon thread pool. Worth it for the huge tables or the rows with costly cells output.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "table", feature = "rayon"))))]
+pub fn par_sizes_list<I>(rows: I, limits: &WidthLimits<'_>) -> Vec<usize>
+where
+    I: IntoParallelIterator,
+    I::Item: Row,
+{
+    par_column_sizes(<I::Item as Row>::KEYS, rows, limits, false).into_vec()
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Makes current [Table] examplar measure its rows right away and keep the columns widths, just like
+    /// [Table::measured] does, but measuring the rows in parallel on the rayon thread pool if there are
+    /// thousands of them. Rows are buffered while being measured, so they are shared between the threads.
+    pub fn par_measured(self) -> Self
+    where
+        &'a T: IntoIterator,
+        <&'a T as IntoIterator>::Item: Row + Sync,
+    {
+        type Item<'a, T> = <&'a T as IntoIterator>::Item;
+        let keys = Item::<T>::KEYS;
+        let rows = self.outputted(self.rows.into_iter(), keys.len());
+        let buffered = rows.iter().map(ByRef);
+        let sizes = match self.style.markdown {
+            true => self.measure_sizes(keys, buffered.map(MarkdownRow), auto_column_sizes),
+            false => self.measure_sizes(keys, buffered, auto_column_sizes),
+        };
+        self.keep_measured(keys, Item::<T>::ALIGNMENTS, &rows, sizes)
+    }
+}
+
+// Returns widths of the columns with the specified keys needed to output the specified rows, clamped with the limits,
+// measuring the rows in parallel if there are enough of them. Rows are buffered to share them between the threads.
+fn auto_column_sizes<I>(
+    keys: &[&str],
+    mut rows: I,
+    limits: &WidthLimits<'_>,
+    multiline: bool,
+) -> Sizes
+where
+    I: Iterator,
+    I::Item: Cells + Sync,
+{
+    let mut buffered: Vec<I::Item> = rows.by_ref().take(PARALLEL_ROWS).collect();
+    if buffered.len() < PARALLEL_ROWS {
+        return column_sizes(keys, buffered, limits, multiline);
+    }
+    buffered.extend(rows);
+    par_column_sizes(keys, buffered.par_iter().map(ByRef), limits, multiline)
+}
+
+// Returns widths of the columns with the specified keys needed to output the specified rows, clamped with the limits,
+// measuring the rows in parallel on the rayon thread pool.
+fn par_column_sizes<I>(keys: &[&str], rows: I, limits: &WidthLimits<'_>, multiline: bool) -> Sizes
+where
+    I: IntoParallelIterator,
+    I::Item: Cells,
+{
+    let sizes = rows
+        .into_par_iter()
+        .fold(
+            || key_sizes(keys),
+            |mut sizes, row| {
+                widen_sizes(&mut sizes, &row, multiline);
+                sizes
+            },
+        )
+        .reduce(
+            || key_sizes(keys),
+            |mut sizes, other| {
+                for (size, other) in sizes.iter_mut().zip(other) {
+                    *size = (*size).max(other);
+                }
+                sizes
+            },
+        );
+    clamp_sizes(sizes, limits)
+}
@@ -0,0 +1,108 @@
+use super::{Cells, Row, RowCell, Table, TableStyle};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// Lets to output one field of the row as two cells: its key and its value.
+struct RecordLine<'a, R: ?Sized> {
+    row: &'a R,
+    index: usize,
+}
+
+impl<'a, R: Row + ?Sized> Cells for RecordLine<'a, R> {
+    fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+        match index {
+            0 => f.write_str(R::KEYS[self.index]),
+            _ => self.row.cell(self.index, f),
+        }
+    }
+}
+
+/// Lets to output single [Row] implementor vertically, as two-column table with field keys in the first column
+/// and their values in the second one, when outputted in alternate mode. Otherwise outputs the fields in one line,
+/// like `name: Alice, age: 30`. Is much more readable than [Table] for the rows with many columns.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Record<'a, R: ?Sized> {
+    row: &'a R,
+    style: TableStyle,
+}
+
+impl<'a, R: ?Sized> Record<'a, R> {
+    /// Creates one [Record] examplar ready to be outputted.
+    pub fn new(row: &'a R) -> Self {
+        Self {
+            row,
+            style: TableStyle::PLAIN,
+        }
+    }
+
+    /// Makes current [Record] examplar use the specified style of borders and separators.
+    pub fn styled(self, style: TableStyle) -> Self {
+        Self { style, ..self }
+    }
+}
+
+impl<'a, R: Row + ?Sized> Display for Record<'a, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            let table = Table::new(&()).headless().styled(self.style);
+            return table.write_styled(f, &["", ""], &[], || {
+                (0..R::KEYS.len()).map(|index| RecordLine {
+                    row: self.row,
+                    index,
+                })
+            });
+        }
+        for (index, key) in R::KEYS.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(key)?;
+            f.write_str(": ")?;
+            Display::fmt(
+                &RowCell {
+                    row: self.row,
+                    index,
+                },
+                f,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Lets to output every row of the collection as [Record], separated with empty lines in alternate mode
+/// and with semicolons otherwise. Is obtained with [Table::records].
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct Records<'a, T: ?Sized> {
+    rows: &'a T,
+    style: TableStyle,
+}
+
+impl<'a, T: ?Sized> Table<'a, T> {
+    /// Returns [Records] examplar outputting the same rows vertically one by one, in the same style.
+    pub fn records(self) -> Records<'a, T> {
+        Records {
+            rows: self.rows,
+            style: self.style,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Display for Records<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Row,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let separator = match f.alternate() {
+            true => "\n\n",
+            false => "; ",
+        };
+        for (index, row) in self.rows.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+            Display::fmt(&Record::new(&row).styled(self.style), f)?;
+        }
+        Ok(())
+    }
+}
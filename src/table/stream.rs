@@ -0,0 +1,70 @@
+use super::{ColumnWidths, Row, Table};
+use core::{
+    cell::Cell,
+    fmt::{Display, Error, Formatter, Result as FmtResult},
+};
+
+/// Source of rows taken from any iterator yielding owned [Row][super::Row] implementors only once, without buffering,
+/// so even very large sources can be outputted as [Table] in streaming mode (see [Table::streamed]).
+/// Rows are taken only once, so outputting the source again, or outputting it in the mode which needs one more pass
+/// over the rows (like the limited table with no columns widths specified), fails with [Error][core::fmt::Error].
+/// ```
+/// use core::fmt::{Display, Formatter, Result as FmtResult};
+/// use cubob::{ColumnWidths, Row, RowStream};
+///
+/// struct Square(u32);
+///
+/// impl Row for Square {
+///     const KEYS: &'static [&'static str] = &["n", "square"];
+///
+///     fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+///         match index {
+///             0 => Display::fmt(&self.0, f),
+///             _ => Display::fmt(&(self.0 * self.0), f),
+///         }
+///     }
+/// }
+///
+/// let squares = RowStream::new((1..=10).map(Square));
+/// assert_eq!(
+///     "n   | square\n----+-------\n1   | 1     \n2   | 4     \n3   | 9     \n… (+7 rows)",
+///     &format!("{:#}", squares.table(ColumnWidths::Given(&[3])).limit(3))
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+pub struct RowStream<I> {
+    rows: Cell<Option<I>>,
+}
+
+impl<I: Iterator> RowStream<I> {
+    /// Creates one [RowStream] examplar taking rows from the specified source.
+    pub fn new<S: IntoIterator<IntoIter = I>>(rows: S) -> Self {
+        Self {
+            rows: Cell::new(Some(rows.into_iter())),
+        }
+    }
+
+    /// Returns [Table] examplar outputting rows of the source in streaming mode with the specified columns widths.
+    pub fn table<'a>(&'a self, widths: ColumnWidths<'a>) -> Table<'a, Self> {
+        Table::new(self).streamed(widths)
+    }
+}
+
+impl<'a, I> Display for Table<'a, RowStream<I>>
+where
+    I: Iterator,
+    I::Item: Row,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let replayed = Cell::new(false);
+        self.display(f, I::Item::KEYS, I::Item::ALIGNMENTS, || {
+            let rows = self.rows.rows.take();
+            replayed.set(replayed.get() || rows.is_none());
+            rows.into_iter().flatten()
+        })?;
+        match replayed.get() {
+            true => Err(Error),
+            false => Ok(()),
+        }
+    }
+}
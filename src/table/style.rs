@@ -0,0 +1,274 @@
+use crate::{measure::width, Align};
+use core::fmt::{Formatter, Result as FmtResult, Write};
+
+/// Characters of one horizontal line of the [Table][super::Table], like rule line after the header or border line.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RuleLine {
+    /// Text put at the left border, if the table has one.
+    pub left: &'static str,
+    /// Character the line is drawn with.
+    pub fill: char,
+    /// Text put where the line crosses columns separators.
+    pub cross: &'static str,
+    /// Text put at the right border, if the table has one.
+    pub right: &'static str,
+}
+
+impl RuleLine {
+    /// Creates one [RuleLine] examplar with the specified characters.
+    pub const fn new(
+        left: &'static str,
+        fill: char,
+        cross: &'static str,
+        right: &'static str,
+    ) -> Self {
+        Self {
+            left,
+            fill,
+            cross,
+            right,
+        }
+    }
+}
+
+/// Style of the [Table][super::Table] borders, separators and padding.
+#[cfg_attr(docsrs, doc(cfg(feature = "table")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableStyle {
+    /// Text put before the first cell of every row line, no left border is drawn if it is empty.
+    pub left: &'static str,
+    /// Text put between cells of every row line.
+    pub separator: &'static str,
+    /// Text put after the last cell of every row line, no right border is drawn if it is empty.
+    pub right: &'static str,
+    /// Amount of spaces put at the sides of every cell having separator or border.
+    pub padding: usize,
+    /// Border line above the header, if any.
+    pub top: Option<RuleLine>,
+    /// Rule line separating header from the rows (and rows from each other or from the footer, if required).
+    pub rule: RuleLine,
+    /// Border line below the last row, if any.
+    pub bottom: Option<RuleLine>,
+    /// Whether the output should stay valid Markdown table: pipes inside cells are escaped, rule line carries
+    /// columns alignment marks, and no rule lines are drawn except the one after the header.
+    pub markdown: bool,
+}
+
+impl TableStyle {
+    /// Columns separated with ` | `, header separated with `-+-` rule line, no outer borders.
+    pub const PLAIN: Self = Self {
+        left: "",
+        separator: "|",
+        right: "",
+        padding: 1,
+        top: None,
+        rule: RuleLine::new("", '-', "+", ""),
+        bottom: None,
+        markdown: false,
+    };
+    /// Markdown table with `| a | b |` rows and `|---|---|` rule line carrying columns alignment,
+    /// pipes inside cells are escaped.
+    pub const MARKDOWN: Self = Self {
+        left: "|",
+        separator: "|",
+        right: "|",
+        padding: 1,
+        top: None,
+        rule: RuleLine::new("|", '-', "|", "|"),
+        bottom: None,
+        markdown: true,
+    };
+    /// Table surrounded with borders drawn using Unicode box-drawing characters like `│`, `─` and `┼`.
+    pub const BOXED: Self = Self {
+        left: "│",
+        separator: "│",
+        right: "│",
+        padding: 1,
+        top: Some(RuleLine::new("┌", '─', "┬", "┐")),
+        rule: RuleLine::new("├", '─', "┼", "┤"),
+        bottom: Some(RuleLine::new("└", '─', "┴", "┘")),
+        markdown: false,
+    };
+    /// Table surrounded with borders drawn using ASCII characters `|`, `-` and `+`, for terminals without Unicode support.
+    pub const BOXED_ASCII: Self = Self {
+        left: "|",
+        separator: "|",
+        right: "|",
+        padding: 1,
+        top: Some(RuleLine::new("+", '-', "+", "+")),
+        rule: RuleLine::new("+", '-', "+", "+"),
+        bottom: Some(RuleLine::new("+", '-', "+", "+")),
+        markdown: false,
+    };
+    /// Columns separated with two spaces only, no rule lines except the dashed one after the header, like in plain logs.
+    pub const COMPACT: Self = Self {
+        left: "",
+        separator: "",
+        right: "",
+        padding: 1,
+        top: None,
+        rule: RuleLine::new("", '-', "", ""),
+        bottom: None,
+        markdown: false,
+    };
+
+    /// Makes current [TableStyle] examplar put the specified text between cells.
+    pub const fn with_separator(self, separator: &'static str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Makes current [TableStyle] examplar put the specified amount of spaces at the sides of every cell
+    /// having separator or border.
+    pub const fn with_padding(self, padding: usize) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Makes current [TableStyle] examplar separate header from the rows with the specified rule line.
+    pub const fn with_rule(self, rule: RuleLine) -> Self {
+        Self { rule, ..self }
+    }
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+impl TableStyle {
+    // Returns total width of the texts and padding put around the cells of one row line with the specified amount of columns.
+    pub(super) fn parts_width(&self, columns: usize) -> usize {
+        let side = |border: &str| match border.is_empty() {
+            true => 0,
+            false => width(border) + self.padding,
+        };
+        let separator = width(self.separator) + 2 * self.padding;
+        side(self.left) + separator * columns.saturating_sub(1) + side(self.right)
+    }
+
+    // Outputs the text put before the first cell of the row line.
+    pub(super) fn write_row_start(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.left.is_empty() {
+            f.write_str(self.left)?;
+            write_repeated(f, ' ', self.padding)?;
+        }
+        Ok(())
+    }
+
+    // Outputs the text put between cells of the row line.
+    pub(super) fn write_row_separator(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_repeated(f, ' ', self.padding)?;
+        f.write_str(self.separator)?;
+        write_repeated(f, ' ', self.padding)
+    }
+
+    // Outputs the text put after the last cell of the row line.
+    pub(super) fn write_row_end(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.right.is_empty() {
+            write_repeated(f, ' ', self.padding)?;
+            f.write_str(self.right)?;
+        }
+        Ok(())
+    }
+
+    // Outputs the border line above the header, if any.
+    pub(super) fn write_top(&self, f: &mut Formatter<'_>, sizes: &[usize]) -> FmtResult {
+        match self.top {
+            Some(line) => self.write_line(f, sizes, line, None),
+            None => Ok(()),
+        }
+    }
+
+    // Outputs the border line below the last row, if any.
+    pub(super) fn write_bottom(&self, f: &mut Formatter<'_>, sizes: &[usize]) -> FmtResult {
+        match self.bottom {
+            Some(line) => self.write_line(f, sizes, line, None),
+            None => Ok(()),
+        }
+    }
+
+    // Outputs the rule line separating header from the rows, with alignment marks in Markdown tables.
+    pub(super) fn write_rule(
+        &self,
+        f: &mut Formatter<'_>,
+        sizes: &[usize],
+        alignments: &[Align],
+    ) -> FmtResult {
+        let marks = match self.markdown {
+            true => Some(alignments),
+            false => None,
+        };
+        self.write_line(f, sizes, self.rule, marks)
+    }
+
+    // Outputs horizontal line, with every column segment being wider by padding at the sides having separator
+    // or border. Segments get colons at their ends matching the columns alignment, if the alignments are specified.
+    fn write_line(
+        &self,
+        f: &mut Formatter<'_>,
+        sizes: &[usize],
+        line: RuleLine,
+        marks: Option<&[Align]>,
+    ) -> FmtResult {
+        let left = !self.left.is_empty();
+        let right = !self.right.is_empty();
+        if left {
+            f.write_str(line.left)?;
+        }
+        for (index, size) in sizes.iter().enumerate() {
+            if index > 0 {
+                f.write_str(line.cross)?;
+            }
+            let before = match index > 0 || left {
+                true => self.padding,
+                false => 0,
+            };
+            let after = match index + 1 < sizes.len() || right {
+                true => self.padding,
+                false => 0,
+            };
+            let width = before + size + after;
+            let align = marks.map(|marks| marks.get(index).copied().unwrap_or_default());
+            for position in 0..width {
+                let mark = match align {
+                    Some(Align::Center) => position == 0 || position + 1 == width,
+                    Some(Align::Right) => position + 1 == width,
+                    _ => false,
+                };
+                match mark {
+                    true => f.write_char(':')?,
+                    false => f.write_char(line.fill)?,
+                }
+            }
+        }
+        if right {
+            f.write_str(line.right)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_repeated(f: &mut Formatter<'_>, c: char, count: usize) -> FmtResult {
+    for _ in 0..count {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+// Escapes pipes written into it, so they are not treated as Markdown table cell borders.
+pub(super) struct MarkdownEscaper<'a, 'b> {
+    pub(super) inner: &'a mut Formatter<'b>,
+}
+
+impl<'a, 'b> Write for MarkdownEscaper<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            if c == '|' {
+                self.inner.write_char('\\')?;
+            }
+            self.inner.write_char(c)?;
+        }
+        Ok(())
+    }
+}
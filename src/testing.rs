@@ -0,0 +1,139 @@
+//! Testing [Display] implementations built with this crate means comparing their output in both alternate modes
+//! with the expected one, and the default `assert_eq!` message makes long mismatching outputs hard to read.
+//! This module contains [assert_display_eq!][crate::assert_display_eq] and [assert_pretty_eq!][crate::assert_pretty_eq]
+//! macros comparing the output with trailing whitespace ignored, which panic with [Mismatch] outputting
+//! line-by-line difference between the expected and the actual output.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{assert_display_eq, assert_pretty_eq, display_struct};
+//!
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl Display for Point {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         display_struct(f, &[(&"x", &self.x), (&"y", &self.y)])
+//!     }
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//! assert_display_eq!(point, "{x: 1, y: 2}");
+//! assert_pretty_eq!(point, "{\n    x: 1,\n    y: 2,\n}\n");
+//! assert_display_eq!(point, "{x: 1, y: 2}", "{\n    x: 1,\n    y: 2,\n}");
+//! ```
+
+use crate::{forward::render, Alternate, ListDiffShow, StructShow};
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// Returns the text with the trailing whitespace of every line and of the whole text removed.
+fn normalized(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for line in text.trim_end().lines() {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(line.trim_end());
+    }
+    output
+}
+
+/// Mismatch between the expected and the actual output of some value in some alternate mode.
+/// Outputs the mode and the line-by-line difference between the outputs as struct, regarding
+/// the propagated value of output alternativeness: lines missing from the actual output are marked with `-`,
+/// unexpected ones are marked with `+`.
+#[cfg_attr(docsrs, doc(cfg(feature = "test-support")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    alternate: Alternate,
+    expected: String,
+    actual: String,
+}
+
+impl Mismatch {
+    /// Returns the alternate mode the value was outputted in.
+    pub fn alternate(&self) -> Alternate {
+        self.alternate
+    }
+
+    /// Returns the expected output, with trailing whitespace removed.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Returns the actual output, with trailing whitespace removed.
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mode = match self.alternate {
+            Alternate::Pretty => "pretty",
+            _ => "one-line",
+        };
+        let diff = ListDiffShow::from_iters(self.expected.lines(), self.actual.lines())
+            .with_unchanged(true);
+        let mut show = StructShow::inherit(f);
+        show.field(&"mode", &mode).field(&"diff", &diff);
+        show.finish()
+    }
+}
+
+/// Outputs the specified value in the specified alternate mode and compares the output with the expected one,
+/// ignoring trailing whitespace. Returns [Mismatch] if the outputs differ.
+#[cfg_attr(docsrs, doc(cfg(feature = "test-support")))]
+pub fn compare_display<T: Display + ?Sized>(
+    val: &T,
+    expected: &str,
+    alternate: Alternate,
+) -> Result<(), Mismatch> {
+    let actual = normalized(&render(&val, alternate));
+    let expected = normalized(expected);
+    match actual == expected {
+        true => Ok(()),
+        false => Err(Mismatch {
+            alternate,
+            expected,
+            actual,
+        }),
+    }
+}
+
+/// Asserts that the one-line output of the value equals the expected one, ignoring trailing whitespace.
+/// With two expected outputs given, asserts the prettified output equals the second one too.
+/// Panics with the difference between the outputs otherwise.
+#[cfg_attr(docsrs, doc(cfg(feature = "test-support")))]
+#[macro_export]
+macro_rules! assert_display_eq {
+    ($val:expr, $one_line:expr $(,)?) => {
+        if let ::core::result::Result::Err(mismatch) =
+            $crate::compare_display(&$val, &$one_line, $crate::Alternate::OneLine)
+        {
+            ::core::panic!("assertion failed: output mismatch\n{:#}", mismatch);
+        }
+    };
+    ($val:expr, $one_line:expr, $pretty:expr $(,)?) => {
+        $crate::assert_display_eq!($val, $one_line);
+        $crate::assert_pretty_eq!($val, $pretty);
+    };
+}
+
+/// Asserts that the prettified output of the value equals the expected one, ignoring trailing whitespace.
+/// Panics with the difference between the outputs otherwise.
+#[cfg_attr(docsrs, doc(cfg(feature = "test-support")))]
+#[macro_export]
+macro_rules! assert_pretty_eq {
+    ($val:expr, $pretty:expr $(,)?) => {
+        if let ::core::result::Result::Err(mismatch) =
+            $crate::compare_display(&$val, &$pretty, $crate::Alternate::Pretty)
+        {
+            ::core::panic!("assertion failed: output mismatch\n{:#}", mismatch);
+        }
+    };
+}
@@ -50,13 +50,13 @@ struct Diverse {
 
 impl Display for Diverse {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        StructShow::new(f, Alternate::Inherit)
-            .fields(&[(&"a", &self.a), (&'b', &self.b), (&"c".to_owned(), &self.c)])
+        let mut show = StructShow::new(f, Alternate::Inherit);
+        show.fields(&[(&"a", &self.a), (&'b', &self.b), (&"c".to_owned(), &self.c)])
             .field_opt(&"d", &self.d)
             .field(&'e', &self.e)
             .field_opt(&"f".to_owned(), &self.f)
-            .field(&"g", &self.g)
-            .finish()
+            .field(&"g", &self.g);
+        show.finish()
     }
 }
 
@@ -69,10 +69,10 @@ struct Array4 {
 
 impl Display for Array4 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        ListShow::new(f, Alternate::Inherit)
-            .items(&[&self.one, &self.two, &self.three])
-            .item_opt(&self.four)
-            .finish()
+        let mut show = ListShow::new(f, Alternate::Inherit);
+        show.items(&[&self.one, &self.two, &self.three])
+            .item_opt(&self.four);
+        show.finish()
     }
 }
 
@@ -155,10 +155,7 @@ fn display() {
             }
         )
     );
-    assert_eq!(
-        "[1, 2, 3, 4, 5]",
-        &format!("{}", Hector((1..6).into_iter().collect()))
-    );
+    assert_eq!("[1, 2, 3, 4, 5]", &format!("{}", Hector((1..6).collect())));
     assert_eq!(
         "{0: 0, 1: 2, 3: 5}",
         &format!(
@@ -279,7 +276,7 @@ fn display_alternative() {
     4,
     5,
 ]"#,
-        &format!("{:#}", Hector((1..6).into_iter().collect()))
+        &format!("{:#}", Hector((1..6).collect()))
     );
     assert_eq!(
         r#"{
@@ -347,3 +344,4301 @@ fn debug_alternative() {
         &format!("{:#?}", Field::new("point", &Complex::new(1, 2)))
     );
 }
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_scientific() {
+    assert_eq!(
+        "1.23e+06",
+        &format!("{}", Custom::new(&1234567.0, Scientific::new(2)))
+    );
+    assert_eq!(
+        "1.5e-07",
+        &format!("{}", Custom::new(&0.00000015, Scientific::default()))
+    );
+    assert_eq!(
+        "-4.200e+01",
+        &format!("{}", Custom::new(&-42, Scientific::new(3)))
+    );
+    struct Constants;
+
+    impl Display for Constants {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(
+                f,
+                &[
+                    (&"c", &Custom::new(&299792458, Scientific::new(3))),
+                    (&"h", &Custom::new(&6.62607015e-34, Scientific::new(2))),
+                ],
+            )
+        }
+    }
+
+    assert_eq!("{c: 2.998e+08, h: 6.63e-34}", &format!("{}", Constants));
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_percent() {
+    assert_eq!(
+        "42.5%",
+        &format!("{}", Custom::new(&0.425, Percent::ratio(1)))
+    );
+    assert_eq!("42%", &format!("{}", Custom::new(&42u8, Percent::raw(0))));
+    assert_eq!(
+        "100.00%",
+        &format!("{}", Custom::new(&1.7f32, Percent::ratio(2).clamped()))
+    );
+    assert_eq!(
+        "0.0%",
+        &format!("{}", Custom::new(&-5, Percent::raw(1).clamped()))
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_currency() {
+    assert_eq!(
+        "$1,234.56",
+        &format!("{}", Custom::new(&123456, Currency::USD))
+    );
+    assert_eq!("$0.05", &format!("{}", Custom::new(&5u8, Currency::USD)));
+    assert_eq!(
+        "-$1,000,000.00",
+        &format!("{}", Custom::new(&-100000000i64, Currency::USD))
+    );
+    assert_eq!(
+        "¥1 234 567",
+        &format!(
+            "{}",
+            Custom::new(&1234567u32, Currency::new("¥", 0).thousands(' '))
+        )
+    );
+    assert_eq!(
+        "€12.300",
+        &format!("{}", Custom::new(&12300, Currency::new("€", 3)))
+    );
+    assert_eq!(
+        format!("-€0.{:0>40}", 12),
+        format!("{}", Custom::new(&-12, Currency::new("€", 40)))
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_locale() {
+    assert_eq!(
+        "1.234,56",
+        &format!("{}", Custom::new(&1234.56, Localized::new(Locale::DE)))
+    );
+    assert_eq!(
+        "-1,234,567.000",
+        &format!(
+            "{}",
+            Custom::new(&-1234567.0, Localized::new(Locale::EN).precision(3))
+        )
+    );
+    assert_eq!(
+        "1'000",
+        &format!("{}", Custom::new(&1000u32, Localized::new(Locale::CH)))
+    );
+    assert_eq!(
+        "999,5",
+        &format!(
+            "{}",
+            Custom::new(&999.5f32, Localized::new(Locale::FR).precision(1))
+        )
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_or_else() {
+    assert_eq!(
+        "n/a",
+        &format!("{}", Custom::new(&None::<Integer>, OrElse("n/a")))
+    );
+    assert_eq!(
+        "'5'",
+        &format!("{}", Custom::new(&Some(Integer(5)), OrElse("n/a")))
+    );
+    assert_eq!(
+        "Integer value '5'",
+        &format!("{:#}", Custom::new(&Some(Integer(5)), OrElse("n/a")))
+    );
+    struct Partial {
+        f: Option<Integer>,
+        g: Option<Integer>,
+    }
+
+    impl Display for Partial {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field(&"f", &Custom::new(&self.f, OrElse('-')))
+                .field(&"g", &Custom::new(&self.g, OrElse('-')));
+            show.finish()
+        }
+    }
+
+    assert_eq!(
+        "{f: -, g: '4'}",
+        &format!(
+            "{}",
+            Partial {
+                f: None,
+                g: Some(Integer(4))
+            }
+        )
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_result() {
+    let ok: Result<Integer, &str> = Ok(Integer(7));
+    let err: Result<Integer, &str> = Err("timed out");
+    assert_eq!(
+        "✓ '7'",
+        &format!("{}", Custom::new(&ok, ResultStyle::default()))
+    );
+    assert_eq!(
+        "✓ Integer value '7'",
+        &format!("{:#}", Custom::new(&ok, ResultStyle::MARKS))
+    );
+    assert_eq!(
+        "✗ timed out",
+        &format!("{}", Custom::new(&err, ResultStyle::MARKS))
+    );
+    assert_eq!(
+        "error: timed out",
+        &format!("{}", Custom::new(&err, ResultStyle::WORDS))
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_masked() {
+    assert_eq!(
+        "******",
+        &format!("{}", Custom::new(&"secret", Masked::ALL))
+    );
+    assert_eq!(
+        "ab*****yz",
+        &format!("{}", Custom::new(&"abcdefxyz", Masked::new(2, 2)))
+    );
+    assert_eq!(
+        "j[hidden]@example.org",
+        &format!(
+            "{}",
+            Custom::new(
+                &"john.doe@example.org",
+                Masked::new(1, 12).mask(Mask::Token("[hidden]"))
+            )
+        )
+    );
+    assert_eq!(
+        "###",
+        &format!(
+            "{}",
+            Custom::new(&"abc", Masked::new(2, 2).mask(Mask::Char('#')))
+        )
+    );
+    assert_eq!(
+        "пар**ь",
+        &format!("{}", Custom::new(&"пароль", Masked::new(3, 1)))
+    );
+    assert_eq!(
+        "中***c",
+        &format!(
+            "{}",
+            Custom::new(&"中中中abc", Masked::new(1, 1).mask(Mask::Token("***")))
+        )
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_truncated() {
+    assert_eq!("short", &format!("{}", Truncated("short", 5)));
+    assert_eq!("shor…", &format!("{}", Truncated("shorter", 5)));
+    assert_eq!("", &format!("{}", Truncated("anything", 0)));
+    assert_eq!("…", &format!("{}", Truncated("anything", 1)));
+    assert_eq!("привет", &format!("{}", Truncated("привет", 6)));
+    assert_eq!("при…", &format!("{}", Truncated("привет", 4)));
+    assert_eq!(
+        "{r: '1', …",
+        &format!("{}", Truncated(Complex::new(1, 2), 10))
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_truncate_middle() {
+    assert_eq!("abcdef", &format!("{}", TruncateMiddle("abcdef", 6)));
+    assert_eq!("abc…yz", &format!("{}", TruncateMiddle("abcdefuvwxyz", 6)));
+    assert_eq!(
+        "abcde…vwxyz",
+        &format!("{}", TruncateMiddle("abcdefghijklmnopqrstuvwxyz", 11))
+    );
+    assert_eq!("…", &format!("{}", TruncateMiddle("abcdef", 1)));
+    assert_eq!("", &format!("{}", TruncateMiddle("abcdef", 0)));
+    assert_eq!("ж…я", &format!("{}", TruncateMiddle("жёлтая", 3)));
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_case() {
+    assert_eq!("HELLO, WORLD", &format!("{}", Upper("Hello, world")));
+    assert_eq!("straße", &format!("{}", Lower("STRAßE")));
+    assert_eq!("STRASSE", &format!("{}", Upper("straße")));
+    assert_eq!(
+        "Hello, Big-Wide World 2day",
+        &format!("{}", TitleCase("hello, BIG-wide world 2DAY"))
+    );
+    assert_eq!("Don't Stop", &format!("{}", TitleCase("don't stop")));
+    assert_eq!(
+        "'Quoted' Rock ’N’ Roll",
+        &format!("{}", TitleCase("'quoted' rock ’n’ roll"))
+    );
+    assert_eq!("INTEGER VALUE '1'", &format!("{:#}", Upper(Integer(1))));
+    assert_eq!(
+        "{R: '1', I: '2'}",
+        &format!("{}", Upper(Complex::new(1, 2)))
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_radix() {
+    assert_eq!("ff", &format!("{}", Hex::new(255u8)));
+    assert_eq!(
+        "0x00FF",
+        &format!("{}", Hex::new(255).prefixed().width(4).upper())
+    );
+    assert_eq!("0b0101", &format!("{}", Bin::new(5u8).prefixed().width(4)));
+    assert_eq!("755", &format!("{}", Oct::new(0o755)));
+    assert_eq!("0o0007", &format!("{}", Oct::new(7).prefixed().width(4)));
+
+    struct Registers(u8, u8);
+
+    impl Display for Registers {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::inherit(f);
+            show.item(&Hex::new(self.0).prefixed().width(2))
+                .item(&Hex::new(self.1).prefixed().width(2));
+            show.finish()
+        }
+    }
+
+    assert_eq!("[0x0a, 0x10]", &format!("{}", Registers(10, 16)));
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_escaped() {
+    assert_eq!(
+        "line one\\nline two\\ttabbed\\r",
+        &format!("{}", Escaped("line one\nline two\ttabbed\r"))
+    );
+    assert_eq!("bell\\u{7}\\0", &format!("{}", Escaped("bell\u{7}\0")));
+    assert_eq!("ünïcödé", &format!("{}", Escaped("ünïcödé")));
+    assert_eq!(r"a\\n", &format!("{}", Escaped(r"a\n")));
+    assert_ne!(
+        format!("{}", Escaped("a\n")),
+        format!("{}", Escaped(r"a\n"))
+    );
+    assert_eq!(
+        "{\\n    r: Integer value '1',\\n    i: Integer value '2',\\n}",
+        &format!("{:#}", Escaped(Complex::new(1, 2)))
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_json_escaped() {
+    assert_eq!("\"plain\"", &format!("{}", JsonEscaped("plain")));
+    assert_eq!(
+        r#""say \"hi\"\n\tC:\\path""#,
+        &format!("{}", JsonEscaped("say \"hi\"\n\tC:\\path"))
+    );
+    assert_eq!(
+        r#""\u0001\b\f""#,
+        &format!("{}", JsonEscaped("\u{1}\u{8}\u{c}"))
+    );
+    assert_eq!("\"ünï\u{7f}\"", &format!("{}", JsonEscaped("ünï\u{7f}")));
+    assert_eq!(
+        "\"{r: '1', i: '2'}\"",
+        &format!("{}", JsonEscaped(Complex::new(1, 2)))
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_quoted() {
+    assert_eq!("'text'", &format!("{}", Quoted::single("text")));
+    assert_eq!(
+        r#""say \"hi\"""#,
+        &format!("{}", Quoted::double("say \"hi\""))
+    );
+    assert_eq!(r"`a\`b`", &format!("{}", Quoted::backtick("a`b")));
+    assert_eq!(r#""a\\\"""#, &format!("{}", Quoted::double(r#"a\""#)));
+    assert_eq!(r#""a\"""#, &format!("{}", Quoted::double(r#"a""#)));
+    assert_eq!(
+        r"'\'1\''",
+        &format!("{}", Quoted::new(Integer(1), Quote::Single))
+    );
+
+    struct User {
+        name: &'static str,
+        role: &'static str,
+    }
+
+    impl Display for User {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(
+                f,
+                &[
+                    (&"name", &Quoted::double(self.name)),
+                    (&"role", &Quoted::single(self.role)),
+                ],
+            )
+        }
+    }
+
+    assert_eq!(
+        "{name: \"Ann\", role: 'admin'}",
+        &format!(
+            "{}",
+            User {
+                name: "Ann",
+                role: "admin"
+            }
+        )
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_delimited() {
+    assert_eq!("<T>", &format!("{}", Delimited::angle('T')));
+    assert_eq!("«'1'»", &format!("{}", Delimited::guillemets(Integer(1))));
+    assert_eq!("{{x}}", &format!("{}", Delimited::new("x", "{{", "}}")));
+    assert_eq!(
+        "(\"QUOTED\")",
+        &format!("{}", Delimited::parens(Quoted::double(Upper("quoted"))))
+    );
+    assert_eq!(
+        "[Integer value '2']",
+        &format!("{:#}", Delimited::square(Integer(2)))
+    );
+}
+
+#[cfg(feature = "instant")]
+#[test]
+fn instant_iter_ext() {
+    let numbers: &[i32] = &[1, 2, 3];
+    assert_eq!(
+        "1, 2, 3",
+        &format!("{}", numbers.iter().display_separated(", "))
+    );
+    assert_eq!(
+        "1 -> 2 -> 3",
+        &format!("{}", numbers.iter().display_separated(" -> "))
+    );
+    assert_eq!(
+        "[2, 4, 6]",
+        &format!("{}", numbers.iter().map(|n| n * 2).display_list())
+    );
+    assert_eq!(
+        "{a: 1, b: 2}",
+        &format!(
+            "{}",
+            ["a", "b"][..].iter().zip(numbers.iter()).display_struct()
+        )
+    );
+    assert_eq!(
+        "Integer value '1' | Integer value '2'",
+        &format!(
+            "{:#}",
+            [Integer(1), Integer(2)][..].iter().display_separated(" | ")
+        )
+    );
+    assert_eq!(
+        "[\n    1,\n    2,\n]",
+        &format!("{:#}", numbers.iter().take(2).display_list())
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_repeated() {
+    assert_eq!("----------", &format!("{}", Repeated::new('-', 10)));
+    assert_eq!("", &format!("{}", Repeated::new("ab", 0)));
+    assert_eq!(
+        "ab, ab, ab",
+        &format!("{}", Repeated::separated("ab", 3, ", "))
+    );
+    assert_eq!("═╪═", &format!("{}", Repeated::separated('═', 2, "╪")));
+    assert_eq!(
+        "Integer value '1'/Integer value '1'",
+        &format!("{:#}", Repeated::separated(Integer(1), 2, "/"))
+    );
+}
+
+#[cfg(feature = "wrap")]
+#[test]
+fn wrap_padded() {
+    assert_eq!("ab   ", &format!("{}", Padded::left("ab", 5)));
+    assert_eq!("   ab", &format!("{}", Padded::right("ab", 5)));
+    assert_eq!(".ab..", &format!("{}", Padded::center("ab", 5).fill('.')));
+    assert_eq!("toolong", &format!("{}", Padded::right("toolong", 3)));
+    assert_eq!("  ёж", &format!("{}", Padded::right("ёж", 4)));
+
+    struct Record(u32, &'static str);
+
+    impl Display for Record {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(
+                f,
+                &[
+                    (&"id", &Padded::right(self.0, 5).fill('0')),
+                    (&"name", &Padded::left(self.1, 5)),
+                ],
+            )
+        }
+    }
+
+    assert_eq!(
+        "{id: 00042, name: Ann  }",
+        &format!("{}", Record(42, "Ann"))
+    );
+}
+
+#[test]
+fn display_forwarded_options() {
+    struct Floats(Vec<f64>);
+
+    impl Display for Floats {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_list_from_iter(f, self.0.iter())
+        }
+    }
+
+    struct Person {
+        name: &'static str,
+        height: f64,
+    }
+
+    impl Display for Person {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(f, &[(&"name", &self.name), (&"height", &self.height)])
+        }
+    }
+
+    assert_eq!(
+        "  key: value",
+        &format!("{:>12}", Field::new("key", "value"))
+    );
+    assert_eq!("x: 1.23", &format!("{:.2}", Field::new("x", &1.23456)));
+    assert_eq!(
+        "name: Alice",
+        &format!("{:.2}", Field::new("name", "Alice"))
+    );
+    assert_eq!(
+        "{r: '1', i: '2'}....",
+        &format!("{:.<20}", Complex::new(1, 2))
+    );
+    assert_eq!(
+        "[1.50, 2.25, 3.00]",
+        &format!("{:.2}", Floats(vec![1.5, 2.25, 3.0]))
+    );
+    assert_eq!(
+        "  [1.5, 2.2]  ",
+        &format!("{:^14.1}", Floats(vec![1.5, 2.25]))
+    );
+    // Precision is applied to the numbers only, so texts are not cut.
+    let person = Person {
+        name: "Alice",
+        height: 1.6789,
+    };
+    assert_eq!("{name: Alice, height: 1.68}", &format!("{:.2}", person));
+    // Width is applied to the whole output instead of every value.
+    assert_eq!(
+        "    {name: Alice, height: 1.6789}",
+        &format!("{:>33}", person)
+    );
+    assert_eq!("[1, 2]  ", &format!("{:08}", Hector(vec![1, 2])));
+    assert_eq!(
+        "[\n    1,\n    2,\n]___",
+        &format!("{:_<#20}", Hector(vec![1, 2]))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn display_padded_bounded() {
+    use core::cell::{Cell, RefCell};
+
+    // Counts the characters written into it.
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl<'a> core::fmt::Write for Counted<'a> {
+        fn write_str(&mut self, s: &str) -> FmtResult {
+            self.0.set(self.0.get() + s.len());
+            Ok(())
+        }
+    }
+
+    // Records how many characters were written before every item is added.
+    struct Logged<'a>(&'a Cell<usize>, &'a RefCell<Vec<usize>>);
+
+    impl<'a> Display for Logged<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::inherit(f);
+            for item in 0..5 {
+                self.1.borrow_mut().push(self.0.get());
+                show.item(&item);
+            }
+            show.finish()
+        }
+    }
+
+    let written = Cell::new(0);
+    let log = RefCell::new(Vec::new());
+    let mut output = Counted(&written);
+    // Output aligned to the left is written right away, and the fill follows it.
+    core::fmt::Write::write_fmt(&mut output, format_args!("{:<20}", Logged(&written, &log)))
+        .unwrap();
+    assert_eq!(vec![0, 2, 5, 8, 11], log.take());
+    assert_eq!(20, written.take());
+    // Otherwise the output is postponed only until it exceeds the width.
+    core::fmt::Write::write_fmt(&mut output, format_args!("{:>8}", Logged(&written, &log)))
+        .unwrap();
+    assert_eq!(vec![0, 0, 0, 0, 11], log.take());
+    assert_eq!(15, written.take());
+    assert_eq!(
+        "  [0, 1, 2, 3, 4]  ",
+        &format!("{:^19}", Logged(&written, &log))
+    );
+}
+
+#[test]
+fn field_separator() {
+    assert_eq!(
+        "key = value",
+        &format!("{}", Field::new("key", "value").with_separator(" = "))
+    );
+    assert_eq!(
+        "a→'1'",
+        &format!("{}", Field::new("a", &Integer(1)).with_separator("→"))
+    );
+    assert_eq!(
+        "\"key\" => 12345",
+        &format!("{:?}", Field::new("key", &12345).with_separator(" => "))
+    );
+    assert_eq!(
+        "point = {\n    r: Integer value '1',\n    i: Integer value '2',\n}",
+        &format!(
+            "{:#}",
+            Field::new("point", &Complex::new(1, 2)).with_separator(" = ")
+        )
+    );
+}
+
+#[test]
+fn field_from_pairs() {
+    let pair = ("key", 12345);
+    assert_eq!("key: 12345", &format!("{}", Field::from(&pair)));
+    assert_eq!("key: 12345", &format!("{}", Field::from((&"key", &12345))));
+    assert_eq!("key: 12345", &format!("{}", Field::from_pair(&pair)));
+    let map = maplit::btreemap! { "a" => 1, "b" => 2 };
+    let fields: Vec<String> = map.iter().map(Field::from).map(|f| f.to_string()).collect();
+    assert_eq!(vec!["a: 1", "b: 2"], fields);
+}
+
+#[test]
+fn field_opt() {
+    let some = Some(Integer(3));
+    let none: Option<Integer> = None;
+    assert_eq!("n: '3'", &format!("{}", FieldOpt::new("n", &some)));
+    assert_eq!("", &format!("{}", FieldOpt::new("n", &none)));
+    assert_eq!(
+        "n: -",
+        &format!("{}", FieldOpt::new("n", &none).with_placeholder("-"))
+    );
+    assert_eq!(
+        "n = Integer value '3'",
+        &format!(
+            "{:#}",
+            FieldOpt::new("n", &some)
+                .with_separator(" = ")
+                .with_placeholder("-")
+        )
+    );
+    let mut line = String::new();
+    std::fmt::Write::write_fmt(
+        &mut line,
+        format_args!(
+            "status{}{}",
+            FieldOpt::new(" code", &Some(200)).with_separator("="),
+            FieldOpt::new(" error", &None::<&str>).with_separator("=")
+        ),
+    )
+    .unwrap();
+    assert_eq!("status code=200", &line);
+}
+
+#[test]
+fn field_aligned() {
+    assert_eq!("id    : 7", &format!("{}", Field::new("id", &7).aligned(6)));
+    assert_eq!(
+        "name  : Ann",
+        &format!("{}", Field::new("name", "Ann").aligned(6))
+    );
+    assert_eq!(
+        "toolong: x",
+        &format!("{}", Field::new("toolong", "x").aligned(3))
+    );
+    assert_eq!(
+        "\"id\"   = 7",
+        &format!(
+            "{:?}",
+            Field::new("id", &7).aligned(6).with_separator(" = ")
+        )
+    );
+    assert_eq!(
+        "ёж  : -",
+        &format!(
+            "{}",
+            FieldOpt::new("ёж", &None::<u8>)
+                .aligned(4)
+                .with_placeholder("-")
+        )
+    );
+}
+
+#[test]
+fn fields_inline() {
+    assert_eq!(
+        "a: 1, b: x",
+        &format!("{}", Fields::new(&[(&"a", &1), (&"b", &'x')]))
+    );
+    assert_eq!("", &format!("{}", Fields::new(&[])));
+    assert_eq!(
+        "request failed (code: 404; path: /index)",
+        &format!(
+            "request failed ({})",
+            Fields::new(&[(&"code", &404), (&"path", &"/index")]).with_separator("; ")
+        )
+    );
+    assert_eq!(
+        "c: Integer value '2' | d: Integer value '3'",
+        &format!(
+            "{:#}",
+            Fields::new(&[(&'c', &Integer(2)), (&'d', &Integer(3))]).with_separator(" | ")
+        )
+    );
+    // Width is applied to the whole output, while precision is applied to the numbers only.
+    assert_eq!(
+        "     a: 1.50, b: xyz",
+        &format!("{:>20.2}", Fields::new(&[(&"a", &1.5), (&"b", &"xyz")]))
+    );
+}
+
+#[test]
+fn display_pair_variety() {
+    struct Pairs<I>(I);
+
+    impl<I> Display for Pairs<I>
+    where
+        I: Iterator + Clone,
+        I::Item: DisplayPair,
+    {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct_from_iter(f, self.0.clone())
+        }
+    }
+
+    let keys: &[&str] = &["a", "b"];
+    let values: &[i32] = &[1, 2];
+    let expected = "{a: 1, b: 2}";
+    // (&K, &V)
+    assert_eq!(
+        expected,
+        &format!("{}", Pairs(keys.iter().zip(values.iter())))
+    );
+    // (K, &V)
+    assert_eq!(
+        expected,
+        &format!("{}", Pairs(keys.iter().copied().zip(values.iter())))
+    );
+    // (&K, V)
+    assert_eq!(
+        expected,
+        &format!("{}", Pairs(keys.iter().zip(values.iter().copied())))
+    );
+    // &&(K, V)
+    let tuples: &[(&str, i32)] = &[("a", 1), ("b", 2)];
+    let refs: Vec<&(&str, i32)> = tuples.iter().collect();
+    assert_eq!(expected, &format!("{}", Pairs(refs.iter())));
+    // [T; 2] and &[T; 2]
+    let arrays: Vec<[&str; 2]> = vec![["a", "1"], ["b", "2"]];
+    assert_eq!(expected, &format!("{}", Pairs(arrays.iter())));
+    assert_eq!(expected, &format!("{}", Pairs(arrays.into_iter())));
+}
+
+#[test]
+fn struct_field_unit() {
+    struct Sample {
+        mass: f32,
+        size: Integer,
+    }
+
+    impl Display for Sample {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field_unit(&"mass", &self.mass, &"kg")
+                .field_unit(&"size", &self.size, &"mm")
+                .fields_unit_from_iter([("t", 20, "°C"), ("p", 101, "kPa")].iter());
+            show.finish()
+        }
+    }
+
+    let sample = Sample {
+        mass: 1.5,
+        size: Integer(3),
+    };
+    assert_eq!(
+        "{mass: 1.5 kg, size: '3' mm, t: 20 °C, p: 101 kPa}",
+        &format!("{}", sample)
+    );
+    assert_eq!(
+        r#"{
+    mass: 1.5 kg,
+    size: Integer value '3' mm,
+    t: 20 °C,
+    p: 101 kPa,
+}"#,
+        &format!("{:#}", sample)
+    );
+}
+
+#[test]
+fn display_pair_migration() {
+    struct Legacy(&'static str, isize);
+
+    impl DisplayPair for Legacy {
+        type Left = &'static str;
+        type Right = isize;
+
+        fn left(&self) -> &Self::Left {
+            &self.0
+        }
+
+        fn rifgt(&self) -> &Self::Right {
+            &self.1
+        }
+    }
+
+    struct Modern(&'static str, isize);
+
+    impl DisplayPair for Modern {
+        type Left = &'static str;
+        type Right = isize;
+
+        fn left(&self) -> &Self::Left {
+            &self.0
+        }
+
+        fn right(&self) -> &Self::Right {
+            &self.1
+        }
+    }
+
+    assert_eq!(&1, Legacy("a", 1).right());
+    assert_eq!("a: 1", &format!("{}", Field::from_pair(&Legacy("a", 1))));
+    #[allow(deprecated)]
+    let right = *Modern("b", 2).rifgt();
+    assert_eq!(2, right);
+    assert_eq!("b: 2", &format!("{}", Field::from_pair(&Modern("b", 2))));
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like() {
+    let components = ["usr", "lib"];
+    assert_eq!(
+        "usr/lib",
+        &format!("{}", Custom::new(&components, PathLike::FS))
+    );
+    assert_eq!(
+        "/usr/lib",
+        &format!("{}", Custom::new(&components, PathLike::FS_ABSOLUTE))
+    );
+    assert_eq!(
+        "/usr/lib/",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS_ABSOLUTE.appended())
+        )
+    );
+    assert_eq!(
+        "usr/lib/",
+        &format!("{}", Custom::new(&components, PathLike::FS.appended()))
+    );
+    assert_eq!(
+        "cubob::Custom",
+        &format!("{}", Custom::new(&["cubob", "Custom"], PathLike::RUST))
+    );
+    let empty: &[&str] = &[];
+    assert_eq!(
+        "/",
+        &format!("{}", Custom::new(empty, PathLike::FS_ABSOLUTE.appended()))
+    );
+    assert_eq!("", &format!("{}", Custom::new(empty, PathLike::FS)));
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like_escaped() {
+    let components = ["a", "b/c", "d\\e"];
+    assert_eq!(
+        "a/b/c/d\\e",
+        &format!("{}", Custom::new(&components, PathLike::FS))
+    );
+    assert_eq!(
+        "a/b\\/c/d\\\\e",
+        &format!("{}", Custom::new(&components, PathLike::FS.escaped('\\')))
+    );
+    assert_eq!(
+        "std::a^:b::c",
+        &format!(
+            "{}",
+            Custom::new(&["std", "a:b", "c"], PathLike::RUST.escaped('^'))
+        )
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like_windows() {
+    let components = ["C:", "Users", "user"];
+    assert_eq!(
+        "C:\\Users\\user",
+        &format!("{}", Custom::new(&components, PathLike::FS_WINDOWS))
+    );
+    assert_eq!(
+        "C:\\Users\\user\\",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS_WINDOWS.appended())
+        )
+    );
+    let components = ["server", "share", "file.txt"];
+    assert_eq!(
+        "\\\\server\\share\\file.txt",
+        &format!("{}", Custom::new(&components, PathLike::FS_UNC))
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like_url() {
+    let components = ["docs", "a b", "ü?"];
+    assert_eq!(
+        "docs/a%20b/%C3%BC%3F",
+        &format!("{}", Custom::new(&components, PathLike::URL))
+    );
+    assert_eq!(
+        "https://example.com/docs/a%20b/%C3%BC%3F",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::url("https://example.com"))
+        )
+    );
+    assert_eq!(
+        "https://example.com/",
+        &format!(
+            "{}",
+            Custom::new(&[""; 0], PathLike::url("https://example.com"))
+        )
+    );
+}
+
+#[cfg(all(feature = "custom", feature = "std"))]
+#[test]
+fn custom_path_like_std() {
+    use std::path::{Path, PathBuf};
+
+    let path = Path::new("/usr/lib/cubob");
+    assert_eq!(
+        "/usr/lib/cubob",
+        &format!("{}", Custom::new(path, PathLike::FS))
+    );
+    assert_eq!(
+        "\\usr\\lib\\cubob",
+        &format!("{}", Custom::new(path, PathLike::FS_WINDOWS))
+    );
+    let path = PathBuf::from("target/../src/lib.rs");
+    assert_eq!(
+        "target::..::src::lib.rs",
+        &format!("{}", Custom::new(&path, PathLike::RUST))
+    );
+    let components = vec!["a", "b"];
+    assert_eq!(
+        "a.b",
+        &format!("{}", Custom::new(&components, PathLike::DOTTED))
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like_max_components() {
+    let components = ["a", "b", "c", "d", "y", "z"];
+    assert_eq!(
+        "a/b/…/y/z",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS.max_components(2, 2))
+        )
+    );
+    assert_eq!(
+        "/a/…/z/",
+        &format!(
+            "{}",
+            Custom::new(
+                &components,
+                PathLike::FS_ABSOLUTE.appended().max_components(1, 1)
+            )
+        )
+    );
+    assert_eq!(
+        "…/z",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS.max_components(0, 1))
+        )
+    );
+    assert_eq!(
+        "a/b/c/d/y/z",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS.max_components(3, 3))
+        )
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_path_like_skip_empty() {
+    let components = ["", "a", "", "b", ""];
+    assert_eq!(
+        "/a//b/",
+        &format!("{}", Custom::new(&components, PathLike::FS))
+    );
+    assert_eq!(
+        "a/b",
+        &format!("{}", Custom::new(&components, PathLike::FS.skip_empty()))
+    );
+    assert_eq!(
+        "/a/b/",
+        &format!(
+            "{}",
+            Custom::new(&components, PathLike::FS_ABSOLUTE.appended().skip_empty())
+        )
+    );
+    let empty = ["", ""];
+    assert_eq!(
+        "/",
+        &format!(
+            "{}",
+            Custom::new(&empty, PathLike::FS_ABSOLUTE.skip_empty())
+        )
+    );
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_key_path() {
+    let config = KeyPath::new("config");
+    let servers = config.key("servers");
+    let second = servers.index(2);
+    let host = second.key("host");
+    assert_eq!(4, host.depth());
+    assert_eq!(Segment::Key("host"), host.segment());
+    assert_eq!("config.servers[2].host", &format!("{}", host));
+    assert_eq!(
+        "/config/servers[2]/host",
+        &format!("{}", Custom::new(&host, PathLike::FS_ABSOLUTE))
+    );
+    assert_eq!(
+        "matrix[1][0]",
+        &format!("{}", KeyPath::new("matrix").index(1).index(0))
+    );
+    assert_eq!(
+        "a.b\\.c",
+        &format!(
+            "{}",
+            Custom::new(
+                &KeyPath::new("a").key("b.c"),
+                PathLike::DOTTED.escaped('\\')
+            )
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table() {
+    use core::fmt::Formatter;
+
+    struct Sample {
+        name: &'static str,
+        value: f64,
+    }
+
+    impl Row for Sample {
+        const KEYS: &'static [&'static str] = &["name", "value"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.name, f),
+                _ => Display::fmt(&self.value, f),
+            }
+        }
+    }
+
+    let samples = vec![
+        Sample {
+            name: "pi",
+            value: 3.25,
+        },
+        Sample {
+            name: "speed of light",
+            value: 299792458.0,
+        },
+    ];
+    assert_eq!(vec![14, 9], sizes_list(&samples, &WidthLimits::default()));
+    assert_eq!(
+        "name           | value    \n\
+         ---------------+----------\n\
+         pi             | 3.25     \n\
+         speed of light | 299792458",
+        &format!("{:#}", samples.as_table())
+    );
+    assert_eq!("2 rows × 2 cols", &format!("{}", samples.as_table()));
+    assert_eq!("1 row × 2 cols", &format!("{}", samples[..1].as_table()));
+    let none: &[Sample] = &[];
+    assert_eq!(
+        "name | value\n-----+------",
+        &format!("{:#}", Table::new(none))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_alignments() {
+    use core::fmt::Formatter;
+
+    struct Stock {
+        item: &'static str,
+        count: u32,
+        unit: &'static str,
+    }
+
+    impl Row for Stock {
+        const KEYS: &'static [&'static str] = &["item", "count", "unit"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Left, Align::Right, Align::Center];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.item, f),
+                1 => Display::fmt(&self.count, f),
+                _ => Display::fmt(&self.unit, f),
+            }
+        }
+    }
+
+    let stock = vec![
+        Stock {
+            item: "bolts",
+            count: 1200,
+            unit: "pcs",
+        },
+        Stock {
+            item: "oil",
+            count: 3,
+            unit: "l",
+        },
+    ];
+    assert_eq!(
+        "item  | count | unit\n\
+         ------+-------+-----\n\
+         bolts |  1200 | pcs \n\
+         oil   |     3 |  l  ",
+        &format!("{:#}", stock.as_table())
+    );
+    assert_eq!(
+        "  x",
+        &format!("{}", Cell::new(&"x", 3).aligned(Align::Right))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_width_limits() {
+    use core::fmt::Formatter;
+
+    struct Note {
+        title: &'static str,
+        text: &'static str,
+    }
+
+    impl Row for Note {
+        const KEYS: &'static [&'static str] = &["title", "text"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.title, f),
+                _ => Display::fmt(&self.text, f),
+            }
+        }
+    }
+
+    let notes = vec![
+        Note {
+            title: "groceries",
+            text: "milk, bread and some cheese",
+        },
+        Note {
+            title: "call",
+            text: "mom",
+        },
+    ];
+    let limits = WidthLimits {
+        columns: &[usize::MAX, 10],
+        all: None,
+    };
+    assert_eq!(vec![9, 10], sizes_list(&notes, &limits));
+    assert_eq!(
+        "title     | text      \n\
+         ----------+-----------\n\
+         groceries | milk, bre…\n\
+         call      | mom       ",
+        &format!("{:#}", notes.as_table().max_widths(&[usize::MAX, 10]))
+    );
+    assert_eq!(
+        "tit… | text\n\
+         -----+-----\n\
+         gro… | mil…\n\
+         call | mom ",
+        &format!("{:#}", notes.as_table().max_width(4))
+    );
+    assert_eq!("ab…", &format!("{}", Cell::new(&"abcdef", 3)));
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_wrapped() {
+    use core::fmt::Formatter;
+
+    struct Entry {
+        word: &'static str,
+        meaning: &'static str,
+    }
+
+    impl Row for Entry {
+        const KEYS: &'static [&'static str] = &["word", "meaning"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.word, f),
+                _ => Display::fmt(&self.meaning, f),
+            }
+        }
+    }
+
+    let entries = vec![
+        Entry {
+            word: "cubob",
+            meaning: "output helpers",
+        },
+        Entry {
+            word: "fmt",
+            meaning: "format",
+        },
+    ];
+    assert_eq!(
+        "word  | meanin\n      \
+               | g     \n\
+         ------+-------\n\
+         cubob | output\n      \
+               |  helpe\n      \
+               | rs    \n\
+         fmt   | format",
+        &format!("{:#}", entries.as_table().max_width(6).wrapped())
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_markdown() {
+    use core::fmt::Formatter;
+
+    struct Operator {
+        symbol: &'static str,
+        precedence: u8,
+    }
+
+    impl Row for Operator {
+        const KEYS: &'static [&'static str] = &["symbol", "precedence"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Center, Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.symbol, f),
+                _ => Display::fmt(&self.precedence, f),
+            }
+        }
+    }
+
+    let operators = vec![
+        Operator {
+            symbol: "||",
+            precedence: 3,
+        },
+        Operator {
+            symbol: "*",
+            precedence: 11,
+        },
+    ];
+    assert_eq!(
+        "| symbol | precedence |\n\
+         |:------:|-----------:|\n\
+         |  \\|\\|  |          3 |\n\
+         |   *    |         11 |",
+        &format!("{:#}", operators.as_table().styled(TableStyle::MARKDOWN))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_boxed() {
+    use core::fmt::Formatter;
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Row for Point {
+        const KEYS: &'static [&'static str] = &["x", "y"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.x, f),
+                _ => Display::fmt(&self.y, f),
+            }
+        }
+    }
+
+    let points = vec![Point { x: 10, y: -5 }, Point { x: 0, y: 7 }];
+    assert_eq!(
+        "┌────┬────┐\n\
+         │ x  │ y  │\n\
+         ├────┼────┤\n\
+         │ 10 │ -5 │\n\
+         │ 0  │ 7  │\n\
+         └────┴────┘",
+        &format!("{:#}", points.as_table().styled(TableStyle::BOXED))
+    );
+    assert_eq!(
+        "+----+----+\n\
+         | x  | y  |\n\
+         +----+----+\n\
+         | 10 | -5 |\n\
+         | 0  | 7  |\n\
+         +----+----+",
+        &format!("{:#}", points.as_table().styled(TableStyle::BOXED_ASCII))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_csv() {
+    use core::fmt::Formatter;
+
+    struct Book {
+        title: &'static str,
+        year: u16,
+    }
+
+    impl Row for Book {
+        const KEYS: &'static [&'static str] = &["title", "year"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.title, f),
+                _ => Display::fmt(&self.year, f),
+            }
+        }
+    }
+
+    let books = vec![
+        Book {
+            title: "Dune",
+            year: 1965,
+        },
+        Book {
+            title: "Crime and Punishment, \"Part 1\"",
+            year: 1866,
+        },
+    ];
+    assert_eq!(
+        "title,year\nDune,1965\n\"Crime and Punishment, \"\"Part 1\"\"\",1866",
+        &format!("{}", books.as_table().to_csv())
+    );
+    assert_eq!(
+        "title\tyear\nDune\t1965\n\"Crime and Punishment, \"\"Part 1\"\"\"\t1866",
+        &format!("{}", books.as_table().to_tsv())
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_dynamic() {
+    use core::fmt::Formatter;
+
+    struct Reading {
+        sensor: &'static str,
+        value: f32,
+    }
+
+    impl DynRow for Reading {
+        fn keys(&self) -> impl Iterator<Item = &str> {
+            core::iter::once(self.sensor)
+        }
+
+        fn cell(&self, key: &str, f: &mut Formatter<'_>) -> FmtResult {
+            match key == self.sensor {
+                true => Display::fmt(&self.value, f),
+                false => Ok(()),
+            }
+        }
+    }
+
+    let rows = vec![
+        maplit::btreemap! {"host" => "alpha", "cpu" => "12%"},
+        maplit::btreemap! {"host" => "beta", "disk" => "80%"},
+    ];
+    assert_eq!(
+        "cpu | host  | disk\n\
+         ----+-------+-----\n\
+         12% | alpha |     \n    \
+             | beta  | 80% ",
+        &format!("{:#}", Table::new(&rows).dynamic())
+    );
+    assert_eq!(
+        "2 rows × 3 cols",
+        &format!("{}", Table::new(&rows).dynamic())
+    );
+    let readings = vec![
+        Reading {
+            sensor: "temp",
+            value: 21.5,
+        },
+        Reading {
+            sensor: "humidity",
+            value: 40.0,
+        },
+    ];
+    assert_eq!(
+        "+------+----------+\n\
+         | temp | humidity |\n\
+         +------+----------+\n\
+         | 21.5 |          |\n\
+         |      | 40       |\n\
+         +------+----------+",
+        &format!(
+            "{:#}",
+            Table::new(&readings)
+                .styled(TableStyle::BOXED_ASCII)
+                .dynamic()
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_headless() {
+    use core::fmt::Formatter;
+
+    struct Pair {
+        key: &'static str,
+        value: u8,
+    }
+
+    impl Row for Pair {
+        const KEYS: &'static [&'static str] = &["key", "value"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.key, f),
+                _ => Display::fmt(&self.value, f),
+            }
+        }
+    }
+
+    let pairs = vec![Pair { key: "a", value: 1 }, Pair { key: "b", value: 2 }];
+    assert_eq!(
+        "a   | 1    \nb   | 2    ",
+        &format!("{:#}", pairs.as_table().headless())
+    );
+    assert_eq!(
+        "┌─────┬───────┐\n\
+         │ a   │ 1     │\n\
+         │ b   │ 2     │\n\
+         └─────┴───────┘",
+        &format!(
+            "{:#}",
+            pairs.as_table().headless().styled(TableStyle::BOXED)
+        )
+    );
+    let none: &[Pair] = &[];
+    assert_eq!(
+        "+-----+-------+\n+-----+-------+",
+        &format!(
+            "{:#}",
+            none.as_table().headless().styled(TableStyle::BOXED_ASCII)
+        )
+    );
+    assert_eq!("", &format!("{:#}", none.as_table().headless()));
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_footer() {
+    use core::fmt::Formatter;
+
+    struct Expense {
+        what: &'static str,
+        cost: u32,
+    }
+
+    impl Row for Expense {
+        const KEYS: &'static [&'static str] = &["what", "cost"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Left, Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.what, f),
+                _ => Display::fmt(&self.cost, f),
+            }
+        }
+    }
+
+    let expenses = vec![
+        Expense {
+            what: "rent",
+            cost: 900,
+        },
+        Expense {
+            what: "food",
+            cost: 350,
+        },
+    ];
+    let total: u32 = expenses.iter().map(|expense| expense.cost).sum();
+    let count = expenses.len();
+    let footer: &[&[&dyn Display]] = &[&[&"total", &total], &[&"count", &count]];
+    assert_eq!(
+        "what  | cost\n\
+         ------+-----\n\
+         rent  |  900\n\
+         food  |  350\n\
+         ------+-----\n\
+         total | 1250\n\
+         count |    2",
+        &format!("{:#}", expenses.as_table().with_footer(footer))
+    );
+    let footer: &[&[&dyn Display]] = &[&[&"a|b"]];
+    assert_eq!(
+        "| what | cost |\n\
+         |------|-----:|\n\
+         | rent |  900 |\n\
+         | food |  350 |\n\
+         | a\\|b |      |",
+        &format!(
+            "{:#}",
+            expenses
+                .as_table()
+                .with_footer(footer)
+                .styled(TableStyle::MARKDOWN)
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_sorted() {
+    use core::fmt::Formatter;
+
+    struct Runner {
+        name: &'static str,
+        time: u32,
+    }
+
+    impl Row for Runner {
+        const KEYS: &'static [&'static str] = &["name", "time"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.name, f),
+                _ => Display::fmt(&self.time, f),
+            }
+        }
+    }
+
+    let runners = vec![
+        Runner {
+            name: "Bob",
+            time: 95,
+        },
+        Runner {
+            name: "Alice",
+            time: 120,
+        },
+        Runner {
+            name: "Carol",
+            time: 101,
+        },
+    ];
+    assert_eq!(
+        "name  | time\n\
+         ------+-----\n\
+         Alice | 120 \n\
+         Bob   | 95  \n\
+         Carol | 101 ",
+        &format!("{:#}", runners.as_table().sorted_by(0, Order::Ascending))
+    );
+    assert_eq!(
+        "name  | time\n\
+         ------+-----\n\
+         Alice | 120 \n\
+         Carol | 101 \n\
+         Bob   | 95  ",
+        &format!(
+            "{:#}",
+            runners
+                .as_table()
+                .sorted_with(1, Order::Descending, |a, b| {
+                    a.parse::<u32>().unwrap().cmp(&b.parse().unwrap())
+                })
+        )
+    );
+    let rows = vec![
+        maplit::btreemap! {"x" => "2"},
+        maplit::btreemap! {"x" => "1"},
+    ];
+    assert_eq!(
+        "x\n-\n1\n2",
+        &format!(
+            "{:#}",
+            Table::new(&rows).sorted_by(0, Order::Ascending).dynamic()
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_filter() {
+    use core::fmt::Formatter;
+
+    struct Process {
+        name: &'static str,
+        memory: u32,
+    }
+
+    impl Row for Process {
+        const KEYS: &'static [&'static str] = &["name", "memory"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.name, f),
+                _ => Display::fmt(&self.memory, f),
+            }
+        }
+    }
+
+    let processes = vec![
+        Process {
+            name: "init",
+            memory: 4,
+        },
+        Process {
+            name: "very-long-daemon-name",
+            memory: 12,
+        },
+        Process {
+            name: "browser",
+            memory: 2048,
+        },
+    ];
+    let heavy = processes.as_table().filter(|process| process.memory > 100);
+    assert_eq!(
+        "name    | memory\n\
+         --------+-------\n\
+         browser | 2048  ",
+        &format!("{:#}", heavy)
+    );
+    assert_eq!("1 row × 2 cols", &format!("{}", heavy));
+    let sorted = processes
+        .as_table()
+        .sorted_by(0, Order::Descending)
+        .filter(|process| process.memory < 100);
+    assert_eq!(
+        "name                  | memory\n\
+         ----------------------+-------\n\
+         very-long-daemon-name | 12    \n\
+         init                  | 4     ",
+        &format!("{:#}", sorted)
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_record() {
+    use core::fmt::Formatter;
+
+    struct Host {
+        name: &'static str,
+        address: &'static str,
+        uptime_days: u32,
+    }
+
+    impl Row for Host {
+        const KEYS: &'static [&'static str] = &["name", "address", "uptime_days"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.name, f),
+                1 => Display::fmt(&self.address, f),
+                _ => Display::fmt(&self.uptime_days, f),
+            }
+        }
+    }
+
+    let hosts = vec![
+        Host {
+            name: "db",
+            address: "10.0.0.5",
+            uptime_days: 41,
+        },
+        Host {
+            name: "web",
+            address: "10.0.0.7",
+            uptime_days: 3,
+        },
+    ];
+    assert_eq!(
+        "name        | db      \n\
+         address     | 10.0.0.5\n\
+         uptime_days | 41      ",
+        &format!("{:#}", Record::new(&hosts[0]))
+    );
+    assert_eq!(
+        "name: db, address: 10.0.0.5, uptime_days: 41",
+        &format!("{}", Record::new(&hosts[0]))
+    );
+    assert_eq!(
+        "+-------------+----------+\n\
+         | name        | web      |\n\
+         | address     | 10.0.0.7 |\n\
+         | uptime_days | 3        |\n\
+         +-------------+----------+",
+        &format!(
+            "{:#}",
+            Record::new(&hosts[1]).styled(TableStyle::BOXED_ASCII)
+        )
+    );
+    assert_eq!(
+        "name: db, address: 10.0.0.5, uptime_days: 41; name: web, address: 10.0.0.7, uptime_days: 3",
+        &format!("{}", hosts.as_table().records())
+    );
+    assert_eq!(
+        "name        | db      \n\
+         address     | 10.0.0.5\n\
+         uptime_days | 41      \n\
+         \n\
+         name        | web     \n\
+         address     | 10.0.0.7\n\
+         uptime_days | 3       ",
+        &format!("{:#}", hosts.as_table().records())
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_keyed() {
+    use core::fmt::Formatter;
+
+    struct Account {
+        owner: &'static str,
+        balance: i64,
+    }
+
+    impl Row for Account {
+        const KEYS: &'static [&'static str] = &["owner", "balance"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Left, Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.owner, f),
+                _ => Display::fmt(&self.balance, f),
+            }
+        }
+    }
+
+    let accounts = maplit::btreemap! {
+        1001 => Account { owner: "Alice", balance: 250 },
+        1002 => Account { owner: "Bob", balance: -40 },
+    };
+    assert_eq!(
+        "id   | owner | balance\n\
+         -----+-------+--------\n\
+         1001 | Alice |     250\n\
+         1002 | Bob   |     -40",
+        &format!("{:#}", Table::new(&accounts).keyed("id"))
+    );
+    assert_eq!(
+        "2 rows × 3 cols",
+        &format!("{}", Table::new(&accounts).keyed("id"))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_rows_buffered() {
+    use core::fmt::Formatter;
+
+    struct Word<'a> {
+        text: &'a str,
+        length: usize,
+    }
+
+    impl<'a> Row for Word<'a> {
+        const KEYS: &'static [&'static str] = &["word", "length"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.text, f),
+                _ => Display::fmt(&self.length, f),
+            }
+        }
+    }
+
+    let sentence = "to be or not";
+    let words: TableRows<_> = sentence
+        .split(' ')
+        .map(|text| Word {
+            text,
+            length: text.len(),
+        })
+        .collect();
+    assert_eq!(
+        "word | length\n\
+         -----+-------\n\
+         to   | 2     \n\
+         be   | 2     \n\
+         or   | 2     \n\
+         not  | 3     ",
+        &format!("{:#}", words)
+    );
+    assert_eq!(
+        "word | length\n\
+         -----+-------\n\
+         not  | 3     ",
+        &format!("{:#}", words.table().filter(|word| word.length > 2))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_limit() {
+    use core::fmt::Formatter;
+
+    struct Line(usize, &'static str);
+
+    impl Row for Line {
+        const KEYS: &'static [&'static str] = &["no", "text"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let lines = vec![
+        Line(1, "short"),
+        Line(2, "tiny"),
+        Line(3, "rather long line of text"),
+        Line(4, "also long enough"),
+    ];
+    assert_eq!(
+        "no | text \n\
+         ---+------\n\
+         1  | short\n\
+         2  | tiny \n\
+         … (+2 rows)",
+        &format!("{:#}", lines.as_table().limit(2))
+    );
+    assert_eq!(
+        "no | text            \n\
+         ---+-----------------\n\
+         4  | also long enough\n\
+         3  | rather long lin…\n\
+         … (+2 rows)",
+        &format!(
+            "{:#}",
+            lines
+                .as_table()
+                .sorted_by(0, Order::Descending)
+                .limit(2)
+                .max_widths(&[2, 16])
+        )
+    );
+    assert_eq!(
+        "no | text \n---+------\n1  | short\n… (+1 row)",
+        &format!("{:#}", lines[..2].as_table().limit(1))
+    );
+    assert_eq!(
+        "no | text \n---+------\n1  | short\n2  | tiny ",
+        &format!("{:#}", lines[..2].as_table().limit(5))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_row_separated() {
+    use core::fmt::Formatter;
+
+    struct Step(u8, &'static str);
+
+    impl Row for Step {
+        const KEYS: &'static [&'static str] = &["step", "action"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let steps = vec![Step(1, "fetch"), Step(2, "build"), Step(3, "test")];
+    assert_eq!(
+        "step | action\n\
+         -----+-------\n\
+         1    | fetch \n\
+         -----+-------\n\
+         2    | build \n\
+         -----+-------\n\
+         3    | test  ",
+        &format!("{:#}", steps.as_table().row_separated())
+    );
+    assert_eq!(
+        "┌──────┬────────┐\n\
+         │ step │ action │\n\
+         ├──────┼────────┤\n\
+         │ 1    │ fetch  │\n\
+         ├──────┼────────┤\n\
+         │ 2    │ build  │\n\
+         └──────┴────────┘",
+        &format!(
+            "{:#}",
+            steps[..2]
+                .as_table()
+                .row_separated()
+                .styled(TableStyle::BOXED)
+        )
+    );
+}
+
+#[cfg(all(feature = "table", feature = "color"))]
+#[test]
+fn table_colored() {
+    use core::fmt::Formatter;
+
+    struct Status(&'static str, &'static str);
+
+    impl Row for Status {
+        const KEYS: &'static [&'static str] = &["service", "state"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let statuses = vec![
+        Status("db", "\x1b[32mup\x1b[0m"),
+        Status("cache", "\x1b[31mdown\x1b[0m"),
+        Status("queue", "\x1b[32mup\x1b[0m"),
+    ];
+    assert_eq!(
+        "\x1b[1mservice | state\x1b[0m\n\
+         \x1b[2m--------+------\x1b[0m\n\
+         db      | \x1b[32mup\x1b[0m   \n\
+         \x1b[48;5;236mcache   | \x1b[31mdown\x1b[0m \x1b[0m\n\
+         queue   | \x1b[32mup\x1b[0m   ",
+        &format!("{:#}", statuses.as_table().colored(TableColors::DEFAULT))
+    );
+    let colors = TableColors {
+        header: None,
+        rule: Some("34"),
+        stripe: None,
+    };
+    assert_eq!(
+        "\x1b[34m+---------+-------+\x1b[0m\n\
+         | service | state |\n\
+         \x1b[34m+---------+-------+\x1b[0m\n\
+         | db      | \x1b[32mup\x1b[0m    |\n\
+         \x1b[34m+---------+-------+\x1b[0m",
+        &format!(
+            "{:#}",
+            statuses[..1]
+                .as_table()
+                .styled(TableStyle::BOXED_ASCII)
+                .colored(colors)
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_streamed() {
+    use core::fmt::Formatter;
+
+    struct Reading(u32, f32);
+
+    impl Row for Reading {
+        const KEYS: &'static [&'static str] = &["sensor", "value"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Left, Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let readings = RowStream::new(vec![Reading(1, 0.5), Reading(22, 12.25), Reading(333, 7.0)]);
+    assert_eq!(
+        "sensor | value\n\
+         -------+------\n\
+         1      |   0.5\n\
+         22     | 12.25\n\
+         333    |     7",
+        &format!("{:#}", readings.table(ColumnWidths::Given(&[])))
+    );
+    let mut output = String::new();
+    assert!(core::fmt::Write::write_fmt(
+        &mut output,
+        format_args!("{:#}", readings.table(ColumnWidths::Given(&[])))
+    )
+    .is_err());
+    let readings = RowStream::new(vec![Reading(1, 0.5)]);
+    assert_eq!(
+        "sensor | value\n-------+------\n1      |   0.5",
+        &format!("{:#}", Table::new(&readings))
+    );
+    let readings = RowStream::new(vec![Reading(1, 0.5), Reading(22, 12.25)]);
+    assert!(core::fmt::Write::write_fmt(
+        &mut output,
+        format_args!("{:#}", Table::new(&readings).limit(1))
+    )
+    .is_err());
+    let readings = RowStream::new(vec![Reading(1, 0.5)]);
+    assert_eq!("1 row × 2 cols", &format!("{}", Table::new(&readings)));
+    let readings = RowStream::new((1..=5).map(|n| Reading(n, n as f32 * 1.5)));
+    assert_eq!(
+        "| sensor   |    value |\n\
+         |----------|---------:|\n\
+         | 1        |      1.5 |\n\
+         | 2        |        3 |\n\
+         … (+3 rows)",
+        &format!(
+            "{:#}",
+            readings
+                .table(ColumnWidths::Budget(23))
+                .styled(TableStyle::MARKDOWN)
+                .limit(2)
+        )
+    );
+    let readings = vec![Reading(4444, 123.5)];
+    assert_eq!(
+        "sens… |   value\n\
+         ------+--------\n\
+         4444  |   123.5",
+        &format!(
+            "{:#}",
+            readings
+                .as_table()
+                .streamed(ColumnWidths::Given(&[5, 7]))
+                .max_width(7)
+        )
+    );
+    assert_eq!(
+        "1 row × 2 cols",
+        &format!("{}", readings.as_table().streamed(ColumnWidths::Budget(0)))
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_multiline_cells() {
+    use core::fmt::Formatter;
+
+    struct Note(&'static str, &'static str);
+
+    impl Row for Note {
+        const KEYS: &'static [&'static str] = &["title", "text"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let notes = vec![Note("todo", "buy\nmilk"), Note("done", "call\r\nmom")];
+    assert_eq!(
+        "title | text       \n\
+         ------+------------\n\
+         todo  | buy\\nmilk  \n\
+         done  | call\\r\\nmom",
+        &format!("{:#}", notes.as_table())
+    );
+    assert_eq!(
+        "title | text  \n\
+         ------+-------\n\
+         todo  | buy\\nm\n\
+         \x20     | ilk   \n\
+         done  | call\\r\n\
+         \x20     | \\nmom ",
+        &format!("{:#}", notes.as_table().max_width(6).wrapped())
+    );
+    assert_eq!(
+        "title,text\ntodo,\"buy\nmilk\"\ndone,\"call\r\nmom\"",
+        &format!("{}", notes.as_table().to_csv())
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_measured() {
+    use core::{cell::Cell, fmt::Formatter};
+
+    struct Job {
+        name: &'static str,
+        done: Cell<u32>,
+        outputs: Cell<usize>,
+    }
+
+    impl Row for Job {
+        const KEYS: &'static [&'static str] = &["job", "done"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            self.outputs.set(self.outputs.get() + 1);
+            match index {
+                0 => Display::fmt(&self.name, f),
+                _ => f.write_fmt(format_args!("{}%", self.done.get())),
+            }
+        }
+    }
+
+    let jobs = vec![
+        Job {
+            name: "backup",
+            done: Cell::new(5),
+            outputs: Cell::new(0),
+        },
+        Job {
+            name: "sync",
+            done: Cell::new(40),
+            outputs: Cell::new(0),
+        },
+    ];
+    let table = jobs.as_table().measured();
+    let outputs = jobs[0].outputs.get();
+    assert_eq!(
+        "job    | done\n-------+-----\nbackup | 5%  \nsync   | 40% ",
+        &format!("{:#}", table)
+    );
+    // Cell formats every value once, and columns widths are not measured anew.
+    assert_eq!(outputs + 2, jobs[0].outputs.get());
+    jobs[0].done.set(100);
+    jobs[1].done.set(100);
+    assert_eq!(
+        "job    | done\n-------+-----\nbackup | 100%\nsync   | 100%",
+        &format!("{:#}", table)
+    );
+    let outputs = jobs[0].outputs.get();
+    assert_eq!(
+        "job    | done\n-------+-----\nbackup | 100%\nsync   | 100%",
+        &format!("{:#}", jobs.as_table())
+    );
+    // Rows measured anew are buffered, so every value is still formatted once.
+    assert_eq!(outputs + 2, jobs[0].outputs.get());
+    jobs[0].done.set(1000);
+    assert_eq!(
+        "job    | done\n-------+-----\nbackup | 100…\nsync   | 100%",
+        &format!("{:#}", table)
+    );
+    let jobs = vec![
+        Job {
+            name: "sync",
+            done: Cell::new(5),
+            outputs: Cell::new(0),
+        },
+        Job {
+            name: "backup",
+            done: Cell::new(40),
+            outputs: Cell::new(0),
+        },
+        Job {
+            name: "cleanup",
+            done: Cell::new(100),
+            outputs: Cell::new(0),
+        },
+    ];
+    // Rows are measured after being sorted and limited, just like they are outputted.
+    let table = jobs
+        .as_table()
+        .sorted_by(0, Order::Descending)
+        .limit(1)
+        .numeric_aligned()
+        .measured();
+    let outputs = jobs[0].outputs.get();
+    assert_eq!(
+        "job  | done\n-----+-----\nsync |   5%\n… (+2 rows)",
+        &format!("{:#}", table)
+    );
+    // Sorting takes the first cell, and numeric columns are not detected anew.
+    assert_eq!(outputs + 3, jobs[0].outputs.get());
+    let table = jobs.as_table().filter(|job| job.name.len() < 7).measured();
+    assert_eq!(
+        "job    | done\n-------+-----\nsync   | 5%  \nbackup | 40% ",
+        &format!("{:#}", table)
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_numeric_aligned() {
+    use core::fmt::Formatter;
+
+    struct Metric(&'static str, &'static str, &'static str, &'static str);
+
+    impl Row for Metric {
+        const KEYS: &'static [&'static str] = &["metric", "value", "change", "note"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                1 => Display::fmt(&self.1, f),
+                2 => Display::fmt(&self.2, f),
+                _ => Display::fmt(&self.3, f),
+            }
+        }
+    }
+
+    let metrics = vec![
+        Metric("requests", "1,024", "+12%", "42"),
+        Metric("errors", "3", "-0.5%", ""),
+        Metric("latency", "12.75", "", "p99"),
+    ];
+    assert_eq!(
+        "metric   | value | change | note\n\
+         ---------+-------+--------+-----\n\
+         requests | 1,024 |   +12% | 42  \n\
+         errors   |     3 |  -0.5% |     \n\
+         latency  | 12.75 |        | p99 ",
+        &format!("{:#}", metrics.as_table().numeric_aligned())
+    );
+    assert_eq!(
+        "| metric   | value | change | note |\n\
+         |----------|------:|-------:|-----:|\n\
+         | requests | 1,024 |   +12% |   42 |",
+        &format!(
+            "{:#}",
+            metrics[..1]
+                .as_table()
+                .numeric_aligned()
+                .styled(TableStyle::MARKDOWN)
+        )
+    );
+
+    struct Version(&'static str, u32);
+
+    impl Row for Version {
+        const KEYS: &'static [&'static str] = &["version", "downloads"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Center];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let versions = vec![Version("1", 250), Version("2", 7)];
+    assert_eq!(
+        "version | downloads\n\
+         --------+----------\n\
+         \x20  1    |       250\n\
+         \x20  2    |         7",
+        &format!("{:#}", versions.as_table().numeric_aligned())
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_multiline() {
+    use core::fmt::Formatter;
+
+    struct Item(&'static str, u32);
+
+    impl Row for Item {
+        const KEYS: &'static [&'static str] = &["item", "qty"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    struct Order(u32, Vec<Item>);
+
+    impl Row for Order {
+        const KEYS: &'static [&'static str] = &["order", "items"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => f.write_fmt(format_args!("{:#}", self.1.as_table().headless())),
+            }
+        }
+    }
+
+    let orders = vec![
+        Order(7, vec![Item("apple", 3), Item("pear", 12)]),
+        Order(12, vec![Item("plum", 1)]),
+    ];
+    assert_eq!(
+        "order | items      \n\
+         ------+------------\n\
+         \x20   7 | apple | 3  \n\
+         \x20     | pear  | 12 \n\
+         \x20  12 | plum | 1   ",
+        &format!("{:#}", orders.as_table().multiline())
+    );
+    assert_eq!(
+        "┌───────┬──────────┐\n\
+         │ order │ items    │\n\
+         ├───────┼──────────┤\n\
+         │     7 │ apple |  │\n\
+         │       │ 3        │\n\
+         │       │ pear  |  │\n\
+         │       │ 12       │\n\
+         └───────┴──────────┘",
+        &format!(
+            "{:#}",
+            orders[..1]
+                .as_table()
+                .multiline()
+                .wrapped()
+                .max_width(8)
+                .styled(TableStyle::BOXED)
+        )
+    );
+    assert_eq!(
+        "| order | items                      |\n\
+         |------:|----------------------------|\n\
+         |     7 | apple \\| 3  \\npear  \\| 12  |",
+        &format!(
+            "{:#}",
+            orders[..1]
+                .as_table()
+                .multiline()
+                .styled(TableStyle::MARKDOWN)
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_style_custom() {
+    use core::fmt::Formatter;
+
+    struct Host(&'static str, u16);
+
+    impl Row for Host {
+        const KEYS: &'static [&'static str] = &["host", "port"];
+        const ALIGNMENTS: &'static [Align] = &[Align::Left, Align::Right];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let hosts = vec![Host("alpha", 80), Host("beta", 8080)];
+    assert_eq!(
+        "host   port\n\
+         -----------\n\
+         alpha    80\n\
+         beta   8080",
+        &format!("{:#}", hosts.as_table().styled(TableStyle::COMPACT))
+    );
+    assert_eq!(
+        "host \t|\tport\n\
+         =====\t+\t====\n\
+         alpha\t|\t  80",
+        &format!(
+            "{:#}",
+            hosts[..1].as_table().styled(
+                TableStyle::PLAIN
+                    .with_padding(0)
+                    .with_separator("\t|\t")
+                    .with_rule(RuleLine::new("", '=', "\t+\t", ""))
+            )
+        )
+    );
+    assert_eq!(
+        "┌─────────┬────────┐\n\
+         │  host   │  port  │\n\
+         ╞═════════╪════════╡\n\
+         │  alpha  │    80  │\n\
+         └─────────┴────────┘",
+        &format!(
+            "{:#}",
+            hosts[..1].as_table().styled(
+                TableStyle::BOXED
+                    .with_padding(2)
+                    .with_rule(RuleLine::new("╞", '═', "╪", "╡"))
+            )
+        )
+    );
+}
+
+#[cfg(all(
+    feature = "unicode",
+    feature = "table",
+    feature = "wrap",
+    feature = "custom"
+))]
+#[test]
+fn unicode_width() {
+    use core::fmt::Formatter;
+
+    struct City(&'static str, &'static str);
+
+    impl Row for City {
+        const KEYS: &'static [&'static str] = &["city", "mood"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&self.1, f),
+            }
+        }
+    }
+
+    let cities = vec![
+        City("東京", "🙂"),
+        City("Cafe\u{301}", "ok"),
+        City("Oslo", ""),
+    ];
+    assert_eq!(
+        "city | mood\n\
+         -----+-----\n\
+         東京 | 🙂  \n\
+         Cafe\u{301} | ok  \n\
+         Oslo |     ",
+        &format!("{:#}", cities.as_table())
+    );
+    assert_eq!(
+        "ci… | mood\n\
+         ----+-----\n\
+         東… | 🙂  ",
+        &format!("{:#}", cities[..1].as_table().max_widths(&[3]))
+    );
+    assert_eq!(
+        "cit | mood\n\
+         y   |     \n\
+         ----+-----\n\
+         東  | 🙂  \n\
+         京  |     ",
+        &format!("{:#}", cities[..1].as_table().max_widths(&[3]).wrapped())
+    );
+    assert_eq!("東京…", &format!("{}", Truncated("東京都庁", 5)));
+    assert_eq!("東京…", &format!("{}", Truncated("東京都庁", 6)));
+    assert_eq!("東…庁", &format!("{}", TruncateMiddle("東京都庁", 5)));
+    assert_eq!(
+        "東****",
+        &format!("{}", Custom::new(&"東京都", Masked::new(1, 0)))
+    );
+    assert_eq!(
+        "中******c",
+        &format!("{}", Custom::new(&"中中中abc", Masked::new(1, 1)))
+    );
+    assert_eq!("東京 |", &format!("{}|", Padded::left("東京", 5)));
+    assert_eq!("東京: 1", &format!("{}", Field::new("東京", &1).aligned(4)));
+}
+
+#[cfg(feature = "tree")]
+#[test]
+fn tree() {
+    struct Crate(&'static str, &'static [Crate]);
+
+    impl Crate {
+        fn show(&self, tree: &mut TreeShow) {
+            tree.node(&self.0, |tree| {
+                for dependency in self.1 {
+                    dependency.show(tree);
+                }
+            });
+        }
+    }
+
+    struct Dependencies(&'static [Crate], TreeGlyphs);
+
+    impl Display for Dependencies {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut tree = TreeShow::inherit(f);
+            tree.glyphs(self.1);
+            for root in self.0 {
+                root.show(&mut tree);
+            }
+            tree.finish()
+        }
+    }
+
+    const APP: Crate = Crate(
+        "app",
+        &[
+            Crate("log", &[Crate("cfg-if", &[])]),
+            Crate("serde", &[Crate("serde_derive", &[Crate("syn", &[])])]),
+            Crate("tiny", &[]),
+        ],
+    );
+    assert_eq!(
+        "app\n\
+         ├── log\n\
+         │   └── cfg-if\n\
+         ├── serde\n\
+         │   └── serde_derive\n\
+         │       └── syn\n\
+         └── tiny\n\
+         tool\n\
+         └── log",
+        &format!(
+            "{:#}",
+            Dependencies(
+                &[APP, Crate("tool", &[Crate("log", &[])])],
+                TreeGlyphs::UNICODE
+            )
+        )
+    );
+    assert_eq!(
+        "app\n\
+         |-- log\n\
+         |   `-- cfg-if\n\
+         |-- serde\n\
+         |   `-- serde_derive\n\
+         |       `-- syn\n\
+         `-- tiny",
+        &format!("{:#}", Dependencies(&[APP], TreeGlyphs::ASCII))
+    );
+    assert_eq!(
+        "app [log [cfg-if], serde [serde_derive [syn]], tiny], tool",
+        &format!(
+            "{}",
+            Dependencies(&[APP, Crate("tool", &[])], TreeGlyphs::UNICODE)
+        )
+    );
+
+    struct Notes;
+
+    impl Display for Notes {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            TreeShow::new(f, Alternate::Pretty)
+                .node(&"notes", |tree| {
+                    tree.node(&"first line\nsecond line", |tree| {
+                        tree.leaves_from_iter(1..3);
+                    })
+                    .leaf(&"last\nentry");
+                })
+                .finish()
+        }
+    }
+
+    assert_eq!(
+        "notes\n\
+         ├── first line\n\
+         │   second line\n\
+         │   ├── 1\n\
+         │   └── 2\n\
+         └── last\n\
+         \x20   entry",
+        &format!("{}", Notes)
+    );
+
+    struct Chain(usize);
+
+    impl Chain {
+        fn show(depth: usize, max: usize, tree: &mut TreeShow) {
+            tree.node(&depth, |tree| {
+                if depth < max {
+                    Self::show(depth + 1, max, tree);
+                    tree.leaf(&"end");
+                }
+            });
+        }
+    }
+
+    impl Display for Chain {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut tree = TreeShow::inherit(f);
+            Self::show(0, self.0, &mut tree);
+            tree.finish()
+        }
+    }
+
+    let chain = format!("{:#}", Chain(140));
+    let lines: Vec<&str> = chain.lines().collect();
+    assert_eq!(281, lines.len());
+    assert_eq!(format!("{}├── 128", "│   ".repeat(127)), lines[128]);
+    assert_eq!(format!("{}├── 129", "│   ".repeat(128)), lines[129]);
+    assert_eq!(format!("{}…   ├── 140", "│   ".repeat(128)), lines[140]);
+    assert_eq!(format!("{}…   └── end", "│   ".repeat(128)), lines[141]);
+    assert_eq!("└── end", lines[280]);
+}
+
+#[cfg(all(feature = "embed", feature = "tree"))]
+#[test]
+fn embed_tree() {
+    struct Dir {
+        name: &'static str,
+        entries: Vec<Dir>,
+    }
+
+    impl Dir {
+        fn new(name: &'static str, entries: Vec<Dir>) -> Self {
+            Self { name, entries }
+        }
+    }
+
+    impl EmbedTree for Dir {
+        fn label(&self) -> impl Display {
+            self.name
+        }
+
+        fn children(&self) -> impl Iterator<Item = &Self> {
+            self.entries.iter()
+        }
+    }
+
+    impl Display for Dir {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_tree_from_embed(self, f, Alternate::Inherit)
+        }
+    }
+
+    let root = Dir::new(
+        "root",
+        vec![
+            Dir::new("src", vec![Dir::new("lib.rs", vec![])]),
+            Dir::new("README.md", vec![]),
+        ],
+    );
+    assert_eq!(
+        "root\n├── src\n│   └── lib.rs\n└── README.md",
+        &format!("{:#}", root)
+    );
+    assert_eq!("root [src [lib.rs], README.md]", &format!("{}", root));
+}
+
+#[test]
+fn indent_guides() {
+    struct Guided<'a>(&'a [(&'a dyn Display, &'a dyn Display)]);
+
+    impl<'a> Display for Guided<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.indent(Indent::Guides).fields(self.0);
+            show.finish()
+        }
+    }
+
+    struct Items<'a>(&'a [&'a dyn Display]);
+
+    impl<'a> Display for Items<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::inherit(f);
+            show.indent(Indent::Guides).items(self.0);
+            show.finish()
+        }
+    }
+
+    let point = Guided(&[(&"x", &1), (&"y", &2)]);
+    let items = Items(&[&point, &"plain"]);
+    let spaced = Complex::new(3, 4);
+    let value = Guided(&[(&"items", &items), (&"spaced", &spaced)]);
+    assert_eq!(
+        "{\n\
+         │   items: [\n\
+         │   │   {\n\
+         │   │   │   x: 1,\n\
+         │   │   │   y: 2,\n\
+         │   │   },\n\
+         │   │   plain,\n\
+         │   ],\n\
+         │   spaced: {\n\
+         │       r: Integer value '3',\n\
+         │       i: Integer value '4',\n\
+         │   },\n\
+         }",
+        &format!("{:#}", value)
+    );
+    assert_eq!(
+        "{items: [{x: 1, y: 2}, plain], spaced: {r: '3', i: '4'}}",
+        &format!("{}", value)
+    );
+    assert_eq!(Indent::Spaces, Indent::global());
+}
+
+#[test]
+fn max_depth() {
+    struct Nested(usize);
+
+    impl Display for Nested {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            match self.0 {
+                0 => display_list(f, &[&0]),
+                n => display_struct(f, &[(&"level", &n), (&"inner", &Nested(n - 1))]),
+            }
+        }
+    }
+
+    struct Limited(usize, Nested);
+
+    impl Display for Limited {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.max_depth(self.0).field(&"top", &self.1);
+            show.finish()
+        }
+    }
+
+    assert_eq!(
+        "{top: {level: 2, inner: {level: 1, inner: [0]}}}",
+        &format!("{}", Limited(3, Nested(2)))
+    );
+    assert_eq!(
+        "{top: {level: 2, inner: …}}",
+        &format!("{}", Limited(1, Nested(2)))
+    );
+    assert_eq!("{top: …}", &format!("{}", Limited(0, Nested(2))));
+    assert_eq!(
+        "{\n    top: {\n        level: 2,\n        inner: …,\n    },\n}",
+        &format!("{:#}", Limited(1, Nested(2)))
+    );
+    assert_eq!(
+        "{level: 2, inner: {level: 1, inner: [0]}}",
+        &format!("{}", Nested(2))
+    );
+}
+
+#[test]
+fn alternate_auto() {
+    struct Auto<'a>(usize, &'a [(&'a dyn Display, &'a dyn Display)]);
+
+    impl<'a> Display for Auto<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::new(f, Alternate::Auto { max_width: self.0 });
+            show.fields(self.1);
+            show.finish()
+        }
+    }
+
+    let short = Complex::new(1, 2);
+    let long = Complex::new(1000, 2000);
+    let fields: &[(&dyn Display, &dyn Display)] = &[(&"short", &short), (&"long", &long)];
+    // Whole one-line output fits the budget, so the builder stays in one line regardless of the formatter.
+    assert_eq!(
+        "{short: {r: '1', i: '2'}, long: {r: '1000', i: '2000'}}",
+        &format!("{:#}", Auto(55, fields))
+    );
+    // Otherwise the builder puts its entries on their own lines, keeping in one line the values fitting the budget.
+    assert_eq!(
+        "{\n    short: {r: '1', i: '2'},\n    long: {r: '1000', i: '2000'},\n}",
+        &format!("{}", Auto(54, fields))
+    );
+    assert_eq!(
+        "{\n    short: {r: '1', i: '2'},\n    long: {\n        r: Integer value '1000',\n        i: Integer value '2000',\n    },\n}",
+        &format!("{}", Auto(18, fields))
+    );
+
+    struct Overridden<'a>(&'a [&'a dyn Display]);
+
+    impl<'a> Display for Overridden<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::new(f, Alternate::Pretty);
+            for item in self.0 {
+                show.item_override(item, Alternate::Auto { max_width: 5 });
+            }
+            show.finish()
+        }
+    }
+
+    assert_eq!(
+        "['1', Integer value '12345']",
+        &format!("{}", Overridden(&[&Integer(1), &Integer(12345)]))
+    );
+}
+
+#[test]
+fn layout_fill() {
+    struct Point(i32, i32);
+
+    impl Display for Point {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(f, &[(&"x", &self.0), (&"y", &self.1)])
+        }
+    }
+
+    struct Points<'a>(&'a [Point]);
+
+    impl<'a> Display for Points<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_list_from_iter(f, self.0.iter())
+        }
+    }
+
+    struct Shape<'a>(usize, &'a [Point]);
+
+    impl<'a> Display for Shape<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.layout(Layout::Fill { width: self.0 })
+                .field(&"name", &"polyline")
+                .field(&"points", &Points(self.1));
+            show.finish()
+        }
+    }
+
+    let points = [Point(1, 2), Point(3, 4), Point(5, 6)];
+    assert_eq!(
+        "{\n    name: polyline,\n    points: [{x: 1, y: 2}, {x: 3, y: 4}, {x: 5, y: 6}],\n}",
+        &format!("{:#}", Shape(80, &points))
+    );
+    assert_eq!(
+        "{\n    name: polyline,\n    points: [\n        {x: 1, y: 2},\n        {x: 3, y: 4},\n        {x: 5, y: 6},\n    ],\n}",
+        &format!("{:#}", Shape(40, &points))
+    );
+    assert_eq!(
+        "{\n    name: polyline,\n    points: [\n        {\n            x: 1,\n            y: 2,\n        },\n    ],\n}",
+        &format!("{:#}", Shape(20, &points[..1]))
+    );
+    assert_eq!(
+        "{name: polyline, points: [{x: 1, y: 2}]}",
+        &format!("{}", Shape(20, &points[..1]))
+    );
+}
+
+#[test]
+fn alternate_policy() {
+    struct Level(usize);
+
+    impl Display for Level {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            match self.0 {
+                0 => Display::fmt(&Integer(0), f),
+                n => display_struct(f, &[(&"n", &Integer(n as isize)), (&"next", &Level(n - 1))]),
+            }
+        }
+    }
+
+    struct Top(AlternatePolicy);
+
+    impl Display for Top {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::new(f, self.0);
+            show.field(&"top", &Level(2));
+            show.finish()
+        }
+    }
+
+    assert_eq!(
+        "{top: {\n    n: Integer value '2',\n    next: {\n        n: '1',\n        next: '0',\n    },\n}}",
+        &format!("{}", Top(AlternatePolicy::pretty_top(2)))
+    );
+    assert_eq!(
+        "{top: {\n    n: '2',\n    next: {n: '1', next: '0'},\n}}",
+        &format!("{}", Top(AlternatePolicy::pretty_top(1)))
+    );
+    assert_eq!(
+        "{top: {n: '2', next: {n: '1', next: '0'}}}",
+        &format!("{}", Top(AlternatePolicy::pretty_top(0)))
+    );
+    assert_eq!(
+        "{top: {\n    n: Integer value '2',\n    next: {\n        n: Integer value '1',\n        next: Integer value '0',\n    },\n}}",
+        &format!("{}", Top(Alternate::Pretty.into()))
+    );
+    assert_eq!(Alternate::OneLine, AlternatePolicy::pretty_top(1).at(1));
+}
+
+// Serializes the tests changing the global color choice.
+#[cfg(feature = "color")]
+static COLOR_CHOICE: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(all(feature = "color", feature = "table"))]
+#[test]
+fn color_choice() {
+    let _guard = COLOR_CHOICE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    ColorChoice::set_global(ColorChoice::Always);
+    assert!(ColorChoice::enabled());
+    assert_eq!(TableColors::DEFAULT, TableColors::DEFAULT.resolved());
+    ColorChoice::set_global(ColorChoice::Never);
+    assert_eq!(ColorChoice::Never, ColorChoice::global());
+    assert_eq!(TableColors::PLAIN, TableColors::DEFAULT.resolved());
+    ColorChoice::set_global(ColorChoice::Auto);
+    assert_eq!(ColorChoice::Auto, ColorChoice::global());
+}
+
+#[cfg(all(feature = "color", feature = "tree"))]
+#[test]
+fn theme() {
+    struct Plain;
+
+    impl Theme for Plain {
+        fn style(&self, _: Element) -> Option<&'static str> {
+            None
+        }
+
+        fn paint(
+            &self,
+            w: &mut dyn core::fmt::Write,
+            element: Element,
+            write: &mut dyn FnMut(&mut dyn core::fmt::Write) -> FmtResult,
+        ) -> FmtResult {
+            let tag = match element {
+                Element::Key => "k",
+                Element::Value => "v",
+                Element::Bracket => "b",
+            };
+            w.write_fmt(format_args!("<{}>", tag))?;
+            write(w)?;
+            w.write_fmt(format_args!("</{}>", tag))
+        }
+    }
+
+    struct Themed(&'static dyn Theme);
+
+    impl Display for Themed {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.theme(self.0).field(&"point", &Complex::new(1, 2));
+            show.finish()
+        }
+    }
+
+    struct Tree(&'static dyn Theme);
+
+    impl Display for Tree {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            TreeShow::inherit(f)
+                .theme(self.0)
+                .node(&"root", |tree| {
+                    tree.leaves(&[&"a", &"b"]);
+                })
+                .finish()
+        }
+    }
+
+    let _guard = COLOR_CHOICE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    ColorChoice::set_global(ColorChoice::Always);
+    assert_eq!(
+        "<b>{</b><k>point</k>: <v><b>{</b><k>r</k>: <v>'1'</v><b>,</b> <k>i</k>: <v>'2'</v><b>}</b></v><b>}</b>",
+        &format!("{}", Themed(&Plain))
+    );
+    assert_eq!(
+        "<b>{</b>\n    <k>point</k>: <v><b>{</b>\n        <k>r</k>: <v>Integer value '1'</v><b>,</b>\n        \
+         <k>i</k>: <v>Integer value '2'</v><b>,</b>\n    <b>}</b></v><b>,</b>\n<b>}</b>",
+        &format!("{:#}", Themed(&Plain))
+    );
+    assert_eq!(
+        "<v>root</v> <b>[</b><v>a</v><b>,</b> <v>b</v><b>]</b>",
+        &format!("{}", Tree(&Plain))
+    );
+    assert_eq!(
+        "<v>root</v>\n<b>├── </b><v>a</v>\n<b>└── </b><v>b</v>",
+        &format!("{:#}", Tree(&Plain))
+    );
+    assert_eq!(
+        "\x1b[2m{\x1b[0m\x1b[1mpoint\x1b[0m: \x1b[2m{\x1b[0m\x1b[1mr\x1b[0m: '1'\x1b[2m,\x1b[0m \
+         \x1b[1mi\x1b[0m: '2'\x1b[2m}\x1b[0m\x1b[2m}\x1b[0m",
+        &format!("{}", Themed(&AnsiTheme::MONOCHROME))
+    );
+    ColorChoice::set_global(ColorChoice::Never);
+    assert_eq!("{point: {r: '1', i: '2'}}", &format!("{}", Themed(&Plain)));
+    ColorChoice::set_global(ColorChoice::Auto);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize() {
+    use serde::{ser::Error, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    enum Shape {
+        Empty,
+        Circle(u32),
+        Segment(i32, i32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Serialize)]
+    struct Scene {
+        name: &'static str,
+        shapes: Vec<Shape>,
+        tags: BTreeMap<&'static str, Option<bool>>,
+    }
+
+    struct Broken;
+
+    impl Serialize for Broken {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(S::Error::custom("broken"))
+        }
+    }
+
+    let scene = Scene {
+        name: "demo",
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle(3),
+            Shape::Segment(-1, 1),
+            Shape::Rect {
+                width: 2,
+                height: 4,
+            },
+        ],
+        tags: maplit::btreemap! {"hidden" => Some(false), "locked" => None},
+    };
+    assert_eq!(
+        "{name: demo, shapes: [Empty, Circle(3), Segment [-1, 1], Rect {width: 2, height: 4}], \
+         tags: {hidden: false, locked: None}}",
+        &to_string(&scene, Alternate::OneLine).unwrap()
+    );
+    assert_eq!(
+        "{\n    name: demo,\n    shapes: [\n        Empty,\n        Circle(3),\n        Segment [\n            -1,\n            \
+         1,\n        ],\n        Rect {\n            width: 2,\n            height: 4,\n        },\n    ],\n    \
+         tags: {\n        hidden: false,\n        locked: None,\n    },\n}",
+        &to_string(&scene, Alternate::Pretty).unwrap()
+    );
+    assert_eq!("[(), 1.5]", &format!("{}", Serialized::inherit(&((), 1.5))));
+    assert_eq!(
+        Err(SerializeError::Custom("broken".into())),
+        to_string(&Broken, Alternate::OneLine)
+    );
+    assert_eq!(
+        Err(SerializeError::Format),
+        to_string(&[Broken], Alternate::OneLine)
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json() {
+    use serde_json::{json, Map, Value};
+
+    let mut map = Map::new();
+    map.insert("b".into(), json!([true, 1.5, "text"]));
+    map.insert("a".into(), json!({"d": null, "c": {}}));
+    let value = Value::Object(map);
+    assert_eq!(
+        "{a: {c: {}, d: null}, b: [true, 1.5, text]}",
+        &format!("{}", Json::new(&value, Alternate::OneLine).sorted())
+    );
+    assert_eq!(
+        "{\n    a: {\n        c: {},\n        d: null,\n    },\n    b: [\n        true,\n        1.5,\n        text,\n    ],\n}",
+        &format!("{:#}", Json::new(&value, Alternate::Pretty).sorted())
+    );
+    assert_eq!("[[], -3]", &format!("{}", Json::inherit(&json!([[], -3]))));
+}
+
+#[test]
+fn via_debug() {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Opaque {
+        id: u8,
+        tags: [&'static str; 2],
+    }
+
+    struct Listed<'a>(&'a Opaque);
+
+    impl<'a> Display for Listed<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_list(f, &[&ViaDebug(self.0)])
+        }
+    }
+
+    let opaque = Opaque {
+        id: 7,
+        tags: ["a", "b"],
+    };
+    assert_eq!(
+        "opaque: Opaque { id: 7, tags: [\"a\", \"b\"] }, name: \"x\"",
+        &format!(
+            "{}",
+            Fields::new(&[(&"opaque", &ViaDebug(&opaque)), (&"name", &ViaDebug("x"))])
+        )
+    );
+    assert_eq!(
+        "[\n    Opaque {\n        id: 7,\n        tags: [\n            \"a\",\n            \"b\",\n        ],\n    },\n]",
+        &format!("{:#}", Listed(&opaque))
+    );
+    assert_eq!("  1.50", &format!("{:6.2}", ViaDebug(1.5)));
+}
+
+#[test]
+fn via_display() {
+    let complex = Complex::new(3, -4);
+    assert_eq!("{r: '3', i: '-4'}", &format!("{:?}", ViaDisplay(&complex)));
+    assert_eq!(
+        "{\n    r: Integer value '3',\n    i: Integer value '-4',\n}",
+        &format!("{:#?}", ViaDisplay(&complex))
+    );
+    assert_eq!(Some(ViaDisplay("text")), Some(ViaDisplay("text")));
+    assert_eq!(
+        "[text, 'x']",
+        &format!("{:?}", [ViaDisplay("text"), ViaDisplay("'x'")])
+    );
+}
+
+#[test]
+fn error_chain() {
+    use std::error::Error;
+
+    #[derive(Debug)]
+    struct Layer(&'static str, Option<Box<Layer>>);
+
+    impl Display for Layer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str(self.0)
+        }
+    }
+
+    impl Error for Layer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.1
+                .as_deref()
+                .map(|layer| layer as &(dyn Error + 'static))
+        }
+    }
+
+    struct Report(Layer);
+
+    impl Display for Report {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct(
+                f,
+                &[(&"status", &"failed"), (&"error", &ErrorChain(&self.0))],
+            )
+        }
+    }
+
+    let error = Layer(
+        "request failed",
+        Some(Box::new(Layer(
+            "connection reset",
+            Some(Box::new(Layer("timeout", None))),
+        ))),
+    );
+    assert_eq!(3, ErrorChain(&error).iter().count());
+    assert_eq!(
+        "request failed: connection reset: timeout",
+        &format!("{}", ErrorChain(&error))
+    );
+    assert_eq!(
+        "[\n    timeout,\n]",
+        &format!("{:#}", ErrorChain(&Layer("timeout", None)))
+    );
+    let report = Report(error);
+    assert_eq!(
+        "{status: failed, error: request failed: connection reset: timeout}",
+        &format!("{}", report)
+    );
+    assert_eq!(
+        "{\n    status: failed,\n    error: [\n        request failed,\n        connection reset,\n        timeout,\n    ],\n}",
+        &format!("{:#}", report)
+    );
+}
+
+#[cfg(all(feature = "anyhow", feature = "eyre"))]
+#[test]
+fn error_embed() {
+    use anyhow::Context;
+
+    struct Status<'a, E: EmbedStruct> {
+        code: u16,
+        error: &'a E,
+    }
+
+    impl<'a, E: EmbedStruct> Display for Status<'a, E> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field(&"code", &self.code)
+                .field(&"error", &EmbedShow(self.error));
+            show.finish()
+        }
+    }
+
+    struct EmbedShow<'a, E: EmbedStruct>(&'a E);
+
+    impl<'a, E: EmbedStruct> Display for EmbedShow<'a, E> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct_from_embed(self.0, f, Alternate::Inherit)
+        }
+    }
+
+    let error = Err::<(), _>(anyhow::anyhow!("timeout"))
+        .context("connection reset")
+        .context("request failed")
+        .unwrap_err();
+    assert_eq!(
+        "request failed: connection reset: timeout",
+        &format!("{}", ErrorChain::from(&error))
+    );
+    let status = Status {
+        code: 503,
+        error: &error,
+    };
+    assert_eq!(
+        "{code: 503, error: {message: request failed, causes: [connection reset, timeout]}}",
+        &format!("{}", status)
+    );
+    assert_eq!(
+        "{\n    code: 503,\n    error: {\n        message: request failed,\n        causes: [\n            \
+         connection reset,\n            timeout,\n        ],\n    },\n}",
+        &format!("{:#}", status)
+    );
+
+    let report = eyre::eyre!("disk full").wrap_err("write failed");
+    assert_eq!(
+        "write failed: disk full",
+        &format!("{}", ErrorChain::from(&report))
+    );
+    assert_eq!(
+        "{code: 507, error: {message: write failed, causes: [disk full]}}",
+        &format!(
+            "{}",
+            Status {
+                code: 507,
+                error: &report,
+            }
+        )
+    );
+}
+
+#[cfg(all(feature = "chrono", feature = "custom"))]
+#[test]
+fn custom_chrono() {
+    use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+    struct Event {
+        at: DateTime<FixedOffset>,
+        seen: DateTime<Utc>,
+        now: DateTime<Utc>,
+    }
+
+    impl Display for Event {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field(&"at", &Custom::new(&self.at, ChronoFormat::RFC3339))
+                .field(
+                    &"date",
+                    &Custom::new(&self.at, ChronoFormat::Pattern("%d.%m.%Y")),
+                )
+                .field(
+                    &"seen",
+                    &Custom::new(&self.seen, ChronoFormat::RelativeTo(self.now)),
+                );
+            show.finish()
+        }
+    }
+
+    let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    let at = FixedOffset::east_opt(2 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 5, 1, 12, 30, 0)
+        .unwrap()
+        + Duration::milliseconds(250);
+    let event = Event {
+        at,
+        seen: now - Duration::minutes(5),
+        now,
+    };
+    assert_eq!(
+        "{at: 2024-05-01T12:30:00.250+02:00, date: 01.05.2024, seen: 5 minutes ago}",
+        &format!("{}", event)
+    );
+    assert_eq!(
+        "2024-05-01T12:30:00+02:00",
+        &format!("{}", Custom::new(&at, ChronoFormat::RFC3339_SECONDS))
+    );
+
+    let relative =
+        |moment: DateTime<Utc>| format!("{}", Custom::new(&moment, ChronoFormat::RelativeTo(now)));
+    assert_eq!("just now", &relative(now));
+    assert_eq!("1 second ago", &relative(now - Duration::seconds(1)));
+    assert_eq!("in 2 hours", &relative(now + Duration::minutes(150)));
+    assert_eq!("1 day ago", &relative(now - Duration::hours(47)));
+    assert_eq!("in 3 months", &relative(now + Duration::days(100)));
+    assert_eq!("2 years ago", &relative(now - Duration::days(800)));
+
+    let naive = NaiveDate::from_ymd_opt(2024, 5, 1)
+        .unwrap()
+        .and_hms_opt(11, 0, 0)
+        .unwrap();
+    assert_eq!(
+        "2024-05-01 11:00:00",
+        &format!("{}", Custom::new(&naive, ChronoFormat::NAIVE))
+    );
+    assert_eq!(
+        "1 hour ago",
+        &format!("{}", Custom::new(&naive, ChronoFormat::RelativeTo(now)))
+    );
+}
+
+#[cfg(all(feature = "time", feature = "custom"))]
+#[test]
+fn custom_time() {
+    use time::{
+        macros::{datetime, format_description},
+        Duration,
+    };
+
+    let now = datetime!(2024-05-01 12:00 UTC);
+    let at = datetime!(2024-05-01 12:30:00.25 +02:00);
+    assert_eq!(
+        "2024-05-01T12:30:00.25+02:00",
+        &format!("{}", Custom::new(&at, TimeFormat::Rfc3339))
+    );
+    assert_eq!(
+        "Wed, 01 May 2024 12:30:00 +0200",
+        &format!("{}", Custom::new(&at, TimeFormat::Rfc2822))
+    );
+    assert_eq!(
+        "01.05.2024 12:30",
+        &format!(
+            "{}",
+            Custom::new(
+                &at,
+                TimeFormat::Description(format_description!(
+                    "[day].[month].[year] [hour]:[minute]"
+                ))
+            )
+        )
+    );
+    assert_eq!(
+        "seen: 5 minutes ago, next: in 1 day, took: 2 hours ago, timeout: 1s500ms",
+        &format!(
+            "{}",
+            Fields::new(&[
+                (
+                    &"seen",
+                    &Custom::new(&(now - Duration::minutes(5)), TimeFormat::RelativeTo(now))
+                ),
+                (
+                    &"next",
+                    &Custom::new(&(now + Duration::hours(30)), TimeFormat::RelativeTo(now))
+                ),
+                (
+                    &"took",
+                    &Custom::new(&Duration::minutes(150), TimeFormat::RelativeTo(now))
+                ),
+                (
+                    &"timeout",
+                    &Custom::new(&Duration::milliseconds(1500), TimeFormat::Rfc3339)
+                ),
+            ])
+        )
+    );
+}
+
+#[cfg(all(feature = "uuid", feature = "custom"))]
+#[test]
+fn custom_uuid() {
+    use uuid::Uuid;
+
+    struct Order {
+        id: Uuid,
+        user: Uuid,
+    }
+
+    impl Display for Order {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field(&"id", &Custom::new(&self.id, UuidFormat::SHORT))
+                .field(&"user", &Custom::new(&self.user, UuidFormat::Simple));
+            show.finish()
+        }
+    }
+
+    let id = Uuid::from_u128(0xa1b2c3d4_e5f6_4789_abcd_ef0123456789);
+    let order = Order {
+        id,
+        user: Uuid::from_u128(0x0123456789abcdef0123456789abcdef),
+    };
+    assert_eq!(
+        "{id: a1b2c3d4…, user: 0123456789abcdef0123456789abcdef}",
+        &format!("{}", order)
+    );
+    assert_eq!(
+        "a1b2c3d4-e5f6-4789-abcd-ef0123456789",
+        &format!("{}", Custom::new(&id, UuidFormat::Full))
+    );
+    assert_eq!(
+        "a1…",
+        &format!("{}", Custom::new(&id, UuidFormat::Short(2)))
+    );
+    assert_eq!(
+        "a1b2c3d4e5f64789abcdef0123456789",
+        &format!("{}", Custom::new(&id, UuidFormat::Short(40)))
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_fields() {
+    use std::sync::{Arc, Mutex};
+    use tracing::{subscriber::with_default, Subscriber};
+    use tracing_subscriber::{layer::Context, prelude::*, registry, Layer};
+
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for Capture {
+        fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+            let mut lines = self.0.lock().unwrap();
+            lines.push(format!("{}", EventShow(event)));
+            lines.push(format!("{:#}", EventShow(event)));
+        }
+    }
+
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = registry().with(Capture(lines.clone()));
+    let complex = Complex::new(1, 2);
+    with_default(subscriber, || {
+        tracing::info!(
+            point = %Recorded::one_line(&complex),
+            pretty = %Recorded::pretty(&complex),
+            name = "demo",
+            count = 3,
+            flag = ?Some(true),
+            "moved"
+        );
+    });
+    let lines = lines.lock().unwrap();
+    assert_eq!(
+        "{message: moved, point: {r: '1', i: '2'}, pretty: {\n    r: Integer value '1',\n    i: Integer value '2',\n}, \
+         name: demo, count: 3, flag: Some(true)}",
+        &lines[0]
+    );
+    assert_eq!(
+        "{\n    message: moved,\n    point: {r: '1', i: '2'},\n    pretty: {\n        r: Integer value '1',\n        \
+         i: Integer value '2',\n    },\n    name: demo,\n    count: 3,\n    flag: Some(\n        true,\n    ),\n}",
+        &lines[1]
+    );
+    assert_eq!(
+        "{r: '1', i: '2'}",
+        &format!(
+            "{:#}",
+            Recorded::new(&complex, Alternate::Auto { max_width: 20 })
+        )
+    );
+}
+
+#[cfg(feature = "tracing-subscriber")]
+#[test]
+fn tracing_format() {
+    use std::{
+        io::{Result as IoResult, Write},
+        sync::{Arc, Mutex},
+    };
+    use tracing::{level_filters::LevelFilter, subscriber::with_default};
+
+    #[derive(Clone)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    let output = |format: StructFormat| {
+        let buffer = Buffer(Arc::new(Mutex::new(Vec::new())));
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .event_format(format)
+            .fmt_fields(StructFields)
+            .with_writer(move || writer.clone())
+            .finish();
+        with_default(subscriber, || {
+            let span = tracing::info_span!("conn", id = 42, user = "admin");
+            let _entered = span.enter();
+            tracing::info!(bytes = 512, "sent");
+            tracing::warn!(retry = true, "slow");
+            tracing::error!(code = 7, "failed");
+        });
+        let bytes = buffer.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    };
+
+    assert_eq!(
+        "INFO conn{id: 42, user: admin}: cubob::tests: {message: sent, bytes: 512}\n\
+         WARN conn{id: 42, user: admin}: cubob::tests: {message: slow, retry: true}\n\
+         ERROR conn{id: 42, user: admin}: cubob::tests: {\n    message: failed,\n    code: 7,\n}\n",
+        &output(StructFormat::new())
+    );
+    assert_eq!(
+        "conn{id: 42, user: admin}: {message: sent, bytes: 512}\n\
+         conn{id: 42, user: admin}: {\n    message: slow,\n    retry: true,\n}\n\
+         conn{id: 42, user: admin}: {\n    message: failed,\n    code: 7,\n}\n",
+        &output(
+            StructFormat::new()
+                .pretty_from(LevelFilter::WARN)
+                .with_level(false)
+                .with_target(false)
+        )
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn display_to_string() {
+    let complex = Complex::new(5, 6);
+    assert_eq!(
+        "{name: demo, value: {r: '5', i: '6'}}",
+        &display_struct_to_string(
+            &[(&"name", &"demo"), (&"value", &complex)],
+            Alternate::OneLine
+        )
+    );
+    assert_eq!(
+        "{\n    name: demo,\n    value: {\n        r: Integer value '5',\n        i: Integer value '6',\n    },\n}",
+        &display_struct_to_string(&[(&"name", &"demo"), (&"value", &complex)], Alternate::Pretty)
+    );
+    assert_eq!(
+        "[1, {r: '5', i: '6'}]",
+        &display_list_to_string(&[&1, &complex], Alternate::Inherit)
+    );
+    assert_eq!(
+        "[\n    1,\n    {\n        r: Integer value '5',\n        i: Integer value '6',\n    },\n]",
+        &display_list_to_string(&[&1, &complex], Alternate::Pretty)
+    );
+    assert_eq!("[]", &display_list_to_string(&[], Alternate::Pretty));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_write() {
+    use std::io::{Error as IoError, ErrorKind, Result as IoResult, Write};
+
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _: &[u8]) -> IoResult<usize> {
+            Err(IoError::new(ErrorKind::StorageFull, "no space left"))
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    let complex = Complex::new(1, 2);
+    let mut output = Vec::new();
+    write_struct(&mut output, &[(&"value", &complex)], Alternate::OneLine).unwrap();
+    output.push(b'\n');
+    write_list(&mut output, &[&1, &complex], Alternate::Pretty).unwrap();
+    assert_eq!(
+        "{value: {r: '1', i: '2'}}\n[\n    1,\n    {\n        r: Integer value '1',\n        i: Integer value '2',\n    },\n]",
+        std::str::from_utf8(&output).unwrap()
+    );
+
+    let error = write_list(Full, &[&1], Alternate::OneLine).unwrap_err();
+    assert_eq!(ErrorKind::StorageFull, error.kind());
+    assert_eq!("no space left", &error.to_string());
+
+    let mut writer = IoWriter::new(Vec::new());
+    writer
+        .write_display(&Fields::new(&[(&"a", &1)]), Alternate::Inherit)
+        .unwrap();
+    assert!(writer.take_error().is_none());
+    assert_eq!(b"a: 1", writer.into_inner().as_slice());
+}
+
+#[test]
+fn builder_modes() {
+    struct Samples<'a>(&'a [u32], Alternate);
+
+    impl<'a> Display for Samples<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::new(f, self.1);
+            show.field(&"count", &self.0.len());
+            for (index, sample) in self.0.iter().enumerate() {
+                show.field(&index, sample);
+            }
+            show.field(&"items", &SamplesList(self.0, self.1));
+            show.finish()
+        }
+    }
+
+    struct SamplesList<'a>(&'a [u32], Alternate);
+
+    impl<'a> Display for SamplesList<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::new(f, self.1);
+            self.0.iter().for_each(|sample| {
+                show.item(sample);
+            });
+            show.item(&"end");
+            show.finish()
+        }
+    }
+
+    let samples = [10, 2000];
+    assert_eq!(
+        "{count: 2, 0: 10, 1: 2000, items: [10, 2000, end]}",
+        &format!("{}", Samples(&samples, Alternate::OneLine))
+    );
+    assert_eq!(
+        "{\n    count: 2,\n    0: 10,\n    1: 2000,\n    items: [\n        10,\n        2000,\n        end,\n    ],\n}",
+        &format!("{:#}", Samples(&samples, Alternate::Pretty))
+    );
+    assert_eq!(
+        "[10, 2000, end]",
+        &format!(
+            "{}",
+            SamplesList(&samples, Alternate::Auto { max_width: 15 })
+        )
+    );
+    assert_eq!(
+        "[\n    10,\n    2000,\n    end,\n]",
+        &format!(
+            "{}",
+            SamplesList(&samples, Alternate::Auto { max_width: 14 })
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn table_many_columns() {
+    use core::fmt::Formatter;
+
+    struct Digits(String);
+
+    impl Row for Digits {
+        const KEYS: &'static [&'static str] = &["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                9 => Display::fmt(&self.0, f),
+                index => Display::fmt(&index, f),
+            }
+        }
+    }
+
+    let long = "0123456789".repeat(7);
+    let rows = [Digits("x".to_string()), Digits(long.clone())];
+    assert_eq!(
+        vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 70],
+        sizes_list(&rows, &WidthLimits::default())
+    );
+    assert_eq!(
+        format!(
+            "a | b | c | d | e | f | g | h | i | j{}\n--+---+---+---+---+---+---+---+---+-{}\n0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | x{}\n0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | {}",
+            " ".repeat(69),
+            "-".repeat(70),
+            " ".repeat(69),
+            long
+        ),
+        format!("{:#}", rows.as_table())
+    );
+    assert_eq!(
+        "a | b | c | d | e | f | g | h | i | j   \n--+---+---+---+---+---+---+---+---+-----\n0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | x   \n0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 012…",
+        &format!("{:#}", rows.as_table().max_width(4))
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn table_parallel_sizes() {
+    use core::fmt::Formatter;
+
+    struct Square(u64);
+
+    impl Row for Square {
+        const KEYS: &'static [&'static str] = &["number", "square"];
+
+        fn cell(&self, index: usize, f: &mut Formatter<'_>) -> FmtResult {
+            match index {
+                0 => Display::fmt(&self.0, f),
+                _ => Display::fmt(&(self.0 * self.0), f),
+            }
+        }
+    }
+
+    let squares: Vec<Square> = (0..10_000).map(Square).collect();
+    let limits = WidthLimits::default();
+    assert_eq!(vec![6, 8], par_sizes_list(&squares, &limits));
+    assert_eq!(
+        sizes_list(&squares, &limits),
+        par_sizes_list(&squares, &limits)
+    );
+    let limits = WidthLimits {
+        columns: &[3],
+        all: None,
+    };
+    assert_eq!(vec![3, 8], par_sizes_list(&squares, &limits));
+    let empty: Vec<Square> = Vec::new();
+    assert_eq!(vec![6, 6], par_sizes_list(&empty, &WidthLimits::default()));
+    let table = squares.as_table().par_measured();
+    assert_eq!(format!("{:#}", squares.as_table()), format!("{:#}", table));
+    assert!(format!("{:#}", table).ends_with("\n9999   | 99980001"));
+    let table = squares.as_table().max_width(5).par_measured();
+    assert_eq!(
+        format!("{:#}", squares.as_table().max_width(5)),
+        format!("{:#}", table)
+    );
+    assert!(format!("{:#}", table).ends_with("\n9999  | 9998…"));
+    let table = squares.as_table().limit(3).par_measured();
+    assert_eq!(
+        "number | square\n-------+-------\n0      | 0     \n1      | 1     \n2      | 4     \n… (+9997 rows)",
+        &format!("{:#}", table)
+    );
+}
+
+#[test]
+fn display_streamed() {
+    use core::cell::Cell;
+
+    struct Squares<'a>(&'a Cell<u32>);
+
+    impl<'a> Display for Squares<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let taken = self.0;
+            display_list_streamed(
+                f,
+                (1..)
+                    .map(|n| n * n)
+                    .take(4)
+                    .inspect(|_| taken.set(taken.get() + 1)),
+            )
+        }
+    }
+
+    struct Counters<'a>(&'a Cell<u32>);
+
+    impl<'a> Display for Counters<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let taken = self.0;
+            display_struct_streamed(
+                f,
+                ["hits", "misses"]
+                    .iter()
+                    .zip(1..)
+                    .inspect(|_| taken.set(taken.get() + 1)),
+            )
+        }
+    }
+
+    // Fails right after the specified amount of characters is written.
+    struct Limited(usize);
+
+    impl core::fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> FmtResult {
+            self.0 = self.0.checked_sub(s.len()).ok_or(core::fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let taken = Cell::new(0);
+    assert_eq!("[1, 4, 9, 16]", &format!("{}", Squares(&taken)));
+    assert_eq!(
+        "{\n    hits: 1,\n    misses: 2,\n}",
+        &format!("{:#}", Counters(&taken))
+    );
+    assert_eq!(6, taken.get());
+
+    // Items are taken till the output fails, so only the ones outputted before the failure are taken.
+    let streamed = |args: core::fmt::Arguments<'_>| {
+        let mut output = Limited(3);
+        assert!(core::fmt::Write::write_fmt(&mut output, args).is_err());
+    };
+    let taken = Cell::new(0);
+    streamed(format_args!("{}", Squares(&taken)));
+    assert_eq!(2, taken.get());
+    // Output aligned to the left is not postponed, while the other is only until it exceeds the width.
+    let taken = Cell::new(0);
+    streamed(format_args!("{:<20}", Squares(&taken)));
+    assert_eq!(2, taken.get());
+    let taken = Cell::new(0);
+    streamed(format_args!("{:>5}", Squares(&taken)));
+    assert_eq!(3, taken.get());
+    // Neither automatic alternate mode nor snapshot one makes the items rendered ahead.
+    #[cfg(feature = "std")]
+    {
+        let _guard = Overrides {
+            alternate: Some(Alternate::Auto { max_width: 100 }),
+            indent: None,
+        }
+        .apply();
+        let taken = Cell::new(0);
+        streamed(format_args!("{}", Squares(&taken)));
+        assert_eq!(2, taken.get());
+    }
+    #[cfg(feature = "snapshot")]
+    {
+        let _guard = Snapshot::new().apply();
+        let taken = Cell::new(0);
+        streamed(format_args!("{}", Squares(&taken)));
+        assert_eq!(2, taken.get());
+        assert_eq!(
+            "{hits: 1, misses: [volatile]}",
+            &Snapshot::new()
+                .with_volatile(&["misses"])
+                .render(&Counters(&taken), Alternate::OneLine)
+        );
+    }
+}
+
+#[test]
+fn try_entries() {
+    use core::cell::Cell;
+
+    struct Failing<'a>(&'a Cell<u32>);
+
+    impl<'a> Display for Failing<'a> {
+        fn fmt(&self, _: &mut Formatter<'_>) -> FmtResult {
+            self.0.set(self.0.get() + 1);
+            Err(core::fmt::Error)
+        }
+    }
+
+    struct Record<'a>(&'a Cell<u32>, &'a Cell<&'static str>);
+
+    impl<'a> Display for Record<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            for (key, val) in [
+                ("a", &1 as &dyn Display),
+                ("b", &Failing(self.0)),
+                ("c", &Failing(self.0)),
+            ] {
+                self.1.set(key);
+                show.try_field(&key, val)?;
+            }
+            show.finish()
+        }
+    }
+
+    struct Items<'a>(&'a Cell<u32>);
+
+    impl<'a> Display for Items<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::inherit(f);
+            show.try_item(&1)?
+                .try_item(&Failing(self.0))?
+                .try_item(&Failing(self.0))?;
+            show.finish()
+        }
+    }
+
+    let outputs = Cell::new(0);
+    let failed = Cell::new("");
+    let mut output = String::new();
+    assert!(core::fmt::Write::write_fmt(
+        &mut output,
+        format_args!("{}", Record(&outputs, &failed))
+    )
+    .is_err());
+    assert_eq!("b", failed.get());
+    assert_eq!(1, outputs.get());
+    assert_eq!("{a: 1, b: ", &output);
+
+    let mut output = String::new();
+    assert!(
+        core::fmt::Write::write_fmt(&mut output, format_args!("{:#}", Items(&outputs))).is_err()
+    );
+    assert_eq!(2, outputs.get());
+    assert_eq!("[\n    1,\n", &output);
+}
+
+#[test]
+fn resolved_alternate() {
+    struct Resolved(Alternate);
+
+    impl Display for Resolved {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_fmt(format_args!("{:?}", self.0.resolve(f)))
+        }
+    }
+
+    struct Modes(AlternatePolicy);
+
+    impl Display for Modes {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::new(f, self.0);
+            let resolved = show.resolved_alternate();
+            show.field(&"struct", &format_args!("{:?}", resolved));
+            show.field(&"list", &ListModes);
+            show.finish()
+        }
+    }
+
+    struct ListModes;
+
+    impl Display for ListModes {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = ListShow::inherit(f);
+            let resolved = show.resolved_alternate();
+            show.item(&format_args!("{:?}", resolved));
+            show.finish()
+        }
+    }
+
+    assert_eq!("OneLine", &format!("{}", Resolved(Alternate::Inherit)));
+    assert_eq!("Pretty", &format!("{:#}", Resolved(Alternate::Inherit)));
+    assert_eq!("OneLine", &format!("{:#}", Resolved(Alternate::OneLine)));
+    assert_eq!(
+        "Auto { max_width: 3 }",
+        &format!("{}", Resolved(Alternate::Auto { max_width: 3 }))
+    );
+    assert_eq!(
+        "{struct: OneLine, list: [OneLine]}",
+        &format!("{}", Modes(Alternate::Inherit.into()))
+    );
+    assert_eq!(
+        "{struct: Pretty, list: [\n    Pretty,\n]}",
+        &format!("{}", Modes(Alternate::Pretty.into()))
+    );
+    assert_eq!(
+        "{\n    struct: Pretty,\n    list: [\n        OneLine,\n    ],\n}",
+        &format!("{:#}", Modes(AlternatePolicy::pretty_top(1)))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn overrides() {
+    let complex = Complex::new(1, 2);
+    let pairs: [(&dyn Display, &dyn Display); 1] = [(&"value", &complex)];
+    let fields = Fields::new(&pairs);
+    {
+        let _pretty = Overrides {
+            alternate: Some(Alternate::Pretty),
+            ..Overrides::NONE
+        }
+        .apply();
+        assert_eq!(
+            "{\n    r: Integer value '1',\n    i: Integer value '2',\n}",
+            &format!("{}", complex)
+        );
+        {
+            let _one_line = Overrides {
+                alternate: Some(Alternate::OneLine),
+                indent: Some(Indent::Guides),
+            }
+            .apply();
+            assert_eq!("{r: '1', i: '2'}", &format!("{:#}", complex));
+            assert_eq!(Some(Indent::Guides), Overrides::current().indent);
+        }
+        {
+            let _guides = Overrides {
+                indent: Some(Indent::Guides),
+                ..Overrides::NONE
+            }
+            .apply();
+            assert_eq!(
+                "value: {\n│   r: Integer value '1',\n│   i: Integer value '2',\n}",
+                &format!("{}", fields)
+            );
+        }
+        assert_eq!(
+            Overrides {
+                alternate: Some(Alternate::Pretty),
+                indent: None
+            },
+            Overrides::current()
+        );
+    }
+    assert_eq!(Overrides::NONE, Overrides::current());
+    assert_eq!("{r: '1', i: '2'}", &format!("{}", complex));
+}
+
+// Value failing its output when asked to, embedded as one field or one item.
+#[cfg(feature = "diff")]
+struct Broken(bool);
+
+#[cfg(feature = "diff")]
+impl Display for Broken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0 {
+            true => Err(core::fmt::Error),
+            false => f.write_str("fine"),
+        }
+    }
+}
+
+#[cfg(feature = "diff")]
+impl EmbedStruct for Broken {
+    fn embed(&self, show: &mut StructShow) {
+        show.field(&"state", self);
+    }
+}
+
+#[cfg(feature = "diff")]
+impl EmbedList for Broken {
+    fn embed(&self, show: &mut ListShow) {
+        show.item(self);
+    }
+}
+
+#[cfg(feature = "diff")]
+#[test]
+fn diff_struct() {
+    struct Config {
+        name: &'static str,
+        retries: u8,
+        point: Complex,
+        proxy: Option<&'static str>,
+        timeout: Option<u32>,
+    }
+
+    impl EmbedStruct for Config {
+        fn embed(&self, show: &mut StructShow) {
+            show.field(&"name", &self.name)
+                .field(&"retries", &self.retries)
+                .field(&"point", &self.point)
+                .field_opt(&"proxy", &self.proxy)
+                .field_opt(&"timeout", &self.timeout);
+        }
+    }
+
+    struct Report<'a>(&'a Config, &'a Config);
+
+    impl<'a> Display for Report<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_diff(f, self.0, self.1)
+        }
+    }
+
+    let old = Config {
+        name: "main",
+        retries: 3,
+        point: Complex::new(1, 2),
+        proxy: Some("local"),
+        timeout: None,
+    };
+    let new = Config {
+        name: "main",
+        retries: 5,
+        point: Complex::new(1, 3),
+        proxy: None,
+        timeout: Some(30),
+    };
+    assert_eq!(
+        "{retries: 3 -> 5, point: {r: '1', i: '2'} -> {r: '1', i: '3'}, timeout: ∅ -> 30, proxy: local -> ∅}",
+        &format!("{}", Report(&old, &new))
+    );
+    assert_eq!(
+        "{\n    name: main,\n    retries: 3 -> 5,\n    point: {r: '1', i: '2'} -> {r: '1', i: '3'},\n    timeout: ∅ -> 30,\n    proxy: local -> ∅,\n}",
+        &format!("{:#}", DiffShow::new(&old, &new).with_unchanged(true))
+    );
+    assert_eq!("{}", &format!("{:#}", DiffShow::new(&old, &old)));
+
+    let mut output = String::new();
+    let mut write =
+        |val: &dyn Display| core::fmt::Write::write_fmt(&mut output, format_args!("{}", val));
+    assert!(write(&DiffShow::new(&Broken(false), &Broken(true))).is_err());
+    assert!(write(&DiffShow::new(&Broken(true), &Broken(false))).is_err());
+}
+
+#[cfg(feature = "diff")]
+#[test]
+fn diff_list() {
+    struct Queue(Vec<u32>);
+
+    impl EmbedList for Queue {
+        fn embed(&self, show: &mut ListShow) {
+            show.items_from_iter(self.0.iter());
+        }
+    }
+
+    struct Report<'a>(&'a Queue, &'a Queue);
+
+    impl<'a> Display for Report<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_list_diff(f, self.0, self.1)
+        }
+    }
+
+    let old = Queue(vec![1, 2, 3, 4, 5]);
+    let new = Queue(vec![1, 3, 4, 6, 5, 7]);
+    assert_eq!(
+        "[…, - 2, …, + 6, …, + 7]",
+        &format!("{}", Report(&old, &new))
+    );
+    assert_eq!(
+        "[\n      1,\n    - 2,\n      3,\n      4,\n    + 6,\n      5,\n    + 7,\n]",
+        &format!("{:#}", ListDiffShow::new(&old, &new).with_unchanged(true))
+    );
+    assert_eq!(
+        "[\n    - a,\n    + b,\n    …,\n]",
+        &format!("{:#}", ListDiffShow::from_iters(["a", "c"], ["b", "c"]))
+    );
+    assert_eq!("[…]", &format!("{}", ListDiffShow::new(&old, &old)));
+
+    let mut output = String::new();
+    let mut write =
+        |val: &dyn Display| core::fmt::Write::write_fmt(&mut output, format_args!("{}", val));
+    assert!(write(&ListDiffShow::new(&Broken(false), &Broken(true))).is_err());
+    assert!(write(&ListDiffShow::from_iters([Broken(true)], [Broken(false)])).is_err());
+}
+
+#[cfg(feature = "test-support")]
+#[test]
+fn display_assertions() {
+    let point = Complex::new(1, 2);
+    crate::assert_display_eq!(point, "{r: '1', i: '2'}  \n");
+    crate::assert_pretty_eq!(
+        point,
+        "{\n    r: Integer value '1',   \n    i: Integer value '2',\n}"
+    );
+    crate::assert_display_eq!(
+        point,
+        "{r: '1', i: '2'}",
+        "{\n    r: Integer value '1',\n    i: Integer value '2',\n}\n"
+    );
+
+    let mismatch = compare_display(
+        &point,
+        "{\n    r: Integer value '1',\n    i: Integer value '3',\n}",
+        Alternate::Pretty,
+    )
+    .unwrap_err();
+    assert_eq!(Alternate::Pretty, mismatch.alternate());
+    assert_eq!(
+        "{\n    mode: pretty,\n    diff: [\n          {,\n              r: Integer value '1',,\n        -     i: Integer value '3',,\n        +     i: Integer value '2',,\n          },\n    ],\n}",
+        &format!("{:#}", mismatch)
+    );
+    let failed = std::panic::catch_unwind(|| crate::assert_display_eq!(point, "{r: '1'}"));
+    assert!(failed.is_err());
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn snapshot_mode() {
+    struct Event {
+        id: u64,
+        at: &'static str,
+        ratio: f64,
+        tags: std::collections::HashMap<&'static str, f32>,
+        steps: Vec<f64>,
+    }
+
+    impl Display for Event {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            let mut show = StructShow::inherit(f);
+            show.field(&"id", &self.id)
+                .field(&"at", &self.at)
+                .field(&"ratio", &self.ratio)
+                .field(&"tags", &StructFromIter(&self.tags))
+                .field(&"steps", &ListFromIter(&self.steps));
+            show.finish()
+        }
+    }
+
+    struct StructFromIter<'a>(&'a std::collections::HashMap<&'static str, f32>);
+
+    impl<'a> Display for StructFromIter<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_struct_from_iter(f, self.0.iter())
+        }
+    }
+
+    struct ListFromIter<'a>(&'a [f64]);
+
+    impl<'a> Display for ListFromIter<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            display_list_from_iter(f, self.0.iter())
+        }
+    }
+
+    let event = Event {
+        id: 73_419,
+        at: "2024-05-01T12:30:00Z",
+        ratio: 0.1 + 0.2,
+        tags: maplit::hashmap! {"zeta" => 1.5, "alpha" => 0.25, "mid" => 2.0},
+        steps: vec![3.0, 1.126, 2e-3],
+    };
+    let snapshot = Snapshot::new()
+        .with_precision(Some(2))
+        .with_volatile(&["id", "at"]);
+    assert_eq!(
+        "{at: [volatile], id: [volatile], ratio: 0.30, steps: [3, 1.13, 0.00], tags: {alpha: 0.25, mid: 2, zeta: 1.50}}",
+        &snapshot.render(&event, Alternate::OneLine)
+    );
+    assert_eq!(
+        "{\n    at: <at>,\n    id: <at>,\n    ratio: 0.30000000000000004,\n    steps: [\n        3,\n        1.126,\n        0.002,\n    ],\n    tags: {\n        alpha: 0.25,\n        mid: 2,\n        zeta: 1.5,\n    },\n}",
+        &Snapshot::new()
+            .with_precision(None)
+            .with_volatile(&["id", "at"])
+            .with_placeholder("<at>")
+            .render(&event, Alternate::Pretty)
+    );
+    {
+        let _guard = snapshot.with_sorted(false).apply();
+        assert_eq!(Some(snapshot.with_sorted(false)), Snapshot::current());
+        assert_eq!("{r: '1', i: '2'}", &format!("{}", Complex::new(1, 2)));
+    }
+    assert_eq!(None, Snapshot::current());
+    assert_eq!(
+        "{i: '2', r: '1'}",
+        &snapshot.render(&Complex::new(1, 2), Alternate::OneLine)
+    );
+}
+
+#[cfg(feature = "histogram")]
+#[test]
+fn histogram() {
+    let latencies =
+        maplit::btreemap! {"<1ms" => 40usize, "1-10ms" => 25, "10-100ms" => 0, ">100ms" => 1};
+    assert_eq!(
+        "1-10ms   ████████████▌ 25\n10-100ms  0\n<1ms     ████████████████████ 40\n>100ms   ▌ 1",
+        &format!("{:#}", Histogram::new(&latencies).max_width(32))
+    );
+    assert_eq!(
+        "1-10ms   ▋ 25\n10-100ms  0\n<1ms     █ 40\n>100ms    1",
+        &format!("{:#}", Histogram::new(&latencies).max_width(5))
+    );
+    assert_eq!(
+        "{1-10ms: 25, 10-100ms: 0, <1ms: 40, >100ms: 1}",
+        &format!("{}", Histogram::new(&latencies))
+    );
+    let empty: [(&str, usize); 0] = [];
+    assert_eq!("", &format!("{:#}", Histogram::new(empty)));
+    assert_eq!("{}", &format!("{}", Histogram::new(empty)));
+    assert_eq!("idle  0", &format!("{:#}", Histogram::new([("idle", 0)])));
+    assert_eq!(
+        format!("half ▍ {}\nfull █ {}", usize::MAX / 2, usize::MAX),
+        format!(
+            "{:#}",
+            Histogram::new([("half", usize::MAX / 2), ("full", usize::MAX)]).max_width(0)
+        )
+    );
+}
+
+#[cfg(feature = "gauge")]
+#[test]
+fn gauge() {
+    assert_eq!("[#####-----] 50%", &format!("{:#}", Gauge::new(5, 10)));
+    assert_eq!("50%", &format!("{}", Gauge::new(5, 10)));
+    assert_eq!(
+        "[##--] 66%",
+        &format!("{:#}", Gauge::new(2, 3).with_width(4))
+    );
+    assert_eq!(
+        "[----] 0%",
+        &format!("{:#}", Gauge::new(0, 3).with_width(4))
+    );
+    assert_eq!(
+        "[####] 100%",
+        &format!("{:#}", Gauge::new(7, 3).with_width(4))
+    );
+    assert_eq!("100%", &format!("{}", Gauge::new(0, 0)));
+    assert_eq!("99%", &format!("{}", Gauge::new(u64::MAX - 1, u64::MAX)));
+    assert_eq!(
+        "job: backup, progress: 25%",
+        &format!(
+            "{}",
+            Fields::new(&[(&"job", &"backup"), (&"progress", &Gauge::new(1, 4))])
+        )
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn grid() {
+    let matrix = vec![vec![1.5, -2.25], vec![0.0, 10.0, 3.125]];
+    assert_eq!(
+        "1.5 -2.25      \n  0    10 3.125",
+        &format!("{:#}", Grid::from_rows(&matrix))
+    );
+    assert_eq!(
+        "1.50 -2.25     \n0.00 10.00 3.12",
+        &format!(
+            "{:#}",
+            Grid::from_rows(&matrix)
+                .formatted_with(|val, f| f.write_fmt(format_args!("{:.2}", val)))
+                .aligned(Align::Left)
+        )
+    );
+    let distances = Grid::from_cells(vec![
+        (0, 1, "5 km"),
+        (1, 0, "5 km"),
+        (2, 2, "–"),
+        (0, 1, "6 km"),
+    ])
+    .row_headers(&[&"home", &"work", &"gym"])
+    .column_headers(&[&"home", &"work", &"gym"])
+    .aligned(Align::Center);
+    assert_eq!(
+        "     home work gym\nhome      6 km    \nwork 5 km         \ngym             – ",
+        &format!("{:#}", distances)
+    );
+    assert_eq!("3 rows × 3 cols", &format!("{}", distances));
+    assert_eq!(
+        "0 rows × 0 cols",
+        &format!("{}", Grid::<u8>::from_cells(None))
+    );
+    assert_eq!("", &format!("{:#}", Grid::<u8>::from_cells(None)));
+}
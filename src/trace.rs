@@ -0,0 +1,239 @@
+//! Structured data logged with `tracing` is either split into separate fields or recorded with its [Debug] output.
+//! This module contains [Recorded] adapter letting to record the whole cubob-formatted struct or list as a single
+//! field value, and [StructVisitor] with [EventShow] outputting the fields of tracing events and spans as struct.
+//! With `tracing-subscriber` feature enabled, [StructFormat] and [StructFields] let the `fmt` subscriber
+//! output whole log lines that way.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{display_struct, Recorded};
+//!
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl Display for Point {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         display_struct(f, &[(&"x", &self.x), (&"y", &self.y)])
+//!     }
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//! tracing::info!(point = %Recorded::one_line(&point), "moved");
+//! tracing::Span::current().record("point", Recorded::pretty(&point).value());
+//! ```
+
+use crate::{measure::measure, Alternate, StructShow, ViaDebug};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    format_args,
+};
+use tracing::{
+    field::{display, DisplayValue, Field, Visit},
+    Event,
+};
+
+/// Lets to record value as single tracing field, outputted in the specified alternate mode regardless
+/// of the way the subscriber outputs the fields. Can be recorded with `%` sigil or via [Recorded::value].
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+#[derive(Clone, Copy, Debug)]
+pub struct Recorded<T> {
+    val: T,
+    alternate: Alternate,
+}
+
+impl<T: Display> Recorded<T> {
+    /// Creates one [Recorded] examplar outputting the specified value in the specified alternate mode.
+    pub fn new(val: T, alternate: Alternate) -> Self {
+        Self { val, alternate }
+    }
+
+    /// Creates one [Recorded] examplar outputting the specified value in one line.
+    pub fn one_line(val: T) -> Self {
+        Self::new(val, Alternate::OneLine)
+    }
+
+    /// Creates one [Recorded] examplar outputting the specified value in prettified format.
+    pub fn pretty(val: T) -> Self {
+        Self::new(val, Alternate::Pretty)
+    }
+
+    /// Returns current [Recorded] examplar as tracing field value, e.g. to record it into span.
+    pub fn value(self) -> DisplayValue<Self> {
+        display(self)
+    }
+}
+
+impl<T: Display> Display for Recorded<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let pretty = match self.alternate {
+            Alternate::OneLine => false,
+            Alternate::Pretty => true,
+            Alternate::Inherit => f.alternate(),
+            Alternate::Auto { max_width } => measure(&self.val, false) > max_width,
+        };
+        match pretty {
+            true => f.write_fmt(format_args!("{:#}", self.val)),
+            false => f.write_fmt(format_args!("{}", self.val)),
+        }
+    }
+}
+
+/// [Visit] implementation adding every visited field into the specified [StructShow] output.
+/// String values and values recorded with `%` sigil are outputted with their [Display] implementation,
+/// the rest are outputted with their [Debug] implementation.
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub struct StructVisitor<'s, 'a, 'b> {
+    show: &'s mut StructShow<'a, 'b>,
+}
+
+impl<'s, 'a, 'b> StructVisitor<'s, 'a, 'b> {
+    /// Creates one [StructVisitor] examplar adding fields into the specified output.
+    pub fn new(show: &'s mut StructShow<'a, 'b>) -> Self {
+        Self { show }
+    }
+}
+
+impl<'s, 'a, 'b> Visit for StructVisitor<'s, 'a, 'b> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.show.field(&field.name(), &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.show.field(&field.name(), &ViaDebug(value));
+    }
+}
+
+/// Lets to output all the fields of tracing event (including the message) as struct,
+/// regarding the propagated value of output alternativeness.
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+#[derive(Clone, Copy)]
+pub struct EventShow<'a>(pub &'a Event<'a>);
+
+impl<'a> Display for EventShow<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut show = StructShow::inherit(f);
+        self.0.record(&mut StructVisitor::new(&mut show));
+        show.finish()
+    }
+}
+
+// Lets to output any set of the tracing fields as struct.
+#[cfg(feature = "tracing-subscriber")]
+struct FieldsShow<R>(R);
+
+#[cfg(feature = "tracing-subscriber")]
+impl<R: tracing_subscriber::field::RecordFields> Display for FieldsShow<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut show = StructShow::inherit(f);
+        self.0.record(&mut StructVisitor::new(&mut show));
+        show.finish()
+    }
+}
+
+/// [FormatFields][tracing_subscriber::fmt::FormatFields] implementation outputting the span fields
+/// as one-line struct, like `{id: 42, user: admin}`.
+#[cfg(feature = "tracing-subscriber")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing-subscriber")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StructFields;
+
+#[cfg(feature = "tracing-subscriber")]
+impl<'w> tracing_subscriber::fmt::FormatFields<'w> for StructFields {
+    fn format_fields<R: tracing_subscriber::field::RecordFields>(
+        &self,
+        mut writer: tracing_subscriber::fmt::format::Writer<'w>,
+        fields: R,
+    ) -> FmtResult {
+        writer.write_fmt(format_args!("{}", FieldsShow(fields)))
+    }
+}
+
+/// [FormatEvent][tracing_subscriber::fmt::FormatEvent] implementation outputting every event as its level,
+/// the spans it is in, its target and its fields outputted as struct, like `INFO conn{id: 42}: app: {message: done}`.
+/// Fields of the events with ERROR level are outputted in prettified format, the rest are outputted in one line.
+#[cfg(feature = "tracing-subscriber")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing-subscriber")))]
+#[derive(Clone, Copy, Debug)]
+pub struct StructFormat {
+    pretty: tracing::level_filters::LevelFilter,
+    level: bool,
+    target: bool,
+}
+
+#[cfg(feature = "tracing-subscriber")]
+impl StructFormat {
+    /// Creates one [StructFormat] examplar with default settings.
+    pub fn new() -> Self {
+        Self {
+            pretty: tracing::level_filters::LevelFilter::ERROR,
+            level: true,
+            target: true,
+        }
+    }
+
+    /// Makes current [StructFormat] examplar output fields in prettified format for the events
+    /// enabled by the specified filter: e.g. [LevelFilter::WARN][tracing::level_filters::LevelFilter::WARN]
+    /// means ERROR and WARN events, and [LevelFilter::OFF][tracing::level_filters::LevelFilter::OFF] means none.
+    pub fn pretty_from(self, pretty: tracing::level_filters::LevelFilter) -> Self {
+        Self { pretty, ..self }
+    }
+
+    /// Makes current [StructFormat] examplar output or skip the event level.
+    pub fn with_level(self, level: bool) -> Self {
+        Self { level, ..self }
+    }
+
+    /// Makes current [StructFormat] examplar output or skip the event target.
+    pub fn with_target(self, target: bool) -> Self {
+        Self { target, ..self }
+    }
+}
+
+#[cfg(feature = "tracing-subscriber")]
+impl Default for StructFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tracing-subscriber")]
+impl<S, N> tracing_subscriber::fmt::FormatEvent<S, N> for StructFormat
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    N: for<'w> tracing_subscriber::fmt::FormatFields<'w> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &tracing_subscriber::fmt::FmtContext<'_, S, N>,
+        mut writer: tracing_subscriber::fmt::format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> FmtResult {
+        let metadata = event.metadata();
+        if self.level {
+            writer.write_fmt(format_args!("{} ", metadata.level()))?;
+        }
+        for span in ctx
+            .event_scope()
+            .into_iter()
+            .flat_map(|scope| scope.from_root())
+        {
+            writer.write_str(span.name())?;
+            let extensions = span.extensions();
+            if let Some(fields) = extensions.get::<tracing_subscriber::fmt::FormattedFields<N>>() {
+                writer.write_str(fields)?;
+            }
+            writer.write_str(": ")?;
+        }
+        if self.target {
+            writer.write_fmt(format_args!("{}: ", metadata.target()))?;
+        }
+        match *metadata.level() <= self.pretty {
+            true => writer.write_fmt(format_args!("{:#}", EventShow(event)))?,
+            false => writer.write_fmt(format_args!("{}", EventShow(event)))?,
+        }
+        writer.write_char('\n')
+    }
+}
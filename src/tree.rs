@@ -0,0 +1,357 @@
+//! Directory-like and dependency-like data is better outputted as tree, with every node on its own line
+//! and branches drawn with glyphs. This module contains [TreeShow] builder doing so in alternate mode,
+//! or outputting the tree in one line with children in brackets otherwise.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{Alternate, TreeShow};
+//!
+//! struct Project;
+//!
+//! impl Display for Project {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         TreeShow::new(f, Alternate::Inherit)
+//!             .node(&"project", |tree| {
+//!                 tree.node(&"src", |tree| {
+//!                     tree.leaves(&[&"lib.rs", &"tests.rs"]);
+//!                 })
+//!                 .leaf(&"Cargo.toml");
+//!             })
+//!             .finish()
+//!     }
+//! }
+//!
+//! assert_eq!(
+//!     "project\n├── src\n│   ├── lib.rs\n│   └── tests.rs\n└── Cargo.toml",
+//!     &format!("{:#}", Project)
+//! );
+//! assert_eq!("project [src [lib.rs, tests.rs], Cargo.toml]", &format!("{}", Project));
+//! ```
+
+use crate::Alternate;
+#[cfg(feature = "color")]
+use crate::{ColorChoice, Element, Theme};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+// Amount of the ancestor levels the branches are tracked for, one bit per level.
+const TRACKED_LEVELS: usize = u128::BITS as usize;
+
+/// Glyphs the branches of the tree are drawn with, each one put before the node label or continuing the branch.
+#[cfg_attr(docsrs, doc(cfg(feature = "tree")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeGlyphs {
+    /// Put before every child except the last one.
+    pub branch: &'static str,
+    /// Put before the last child.
+    pub last: &'static str,
+    /// Put under the child which has siblings after it, continuing the branch.
+    pub pipe: &'static str,
+    /// Put under the last child, where the branch is already ended.
+    pub space: &'static str,
+    /// Put once instead of the branches of all the ancestors nested deeper than 128 levels,
+    /// which are not tracked, so the deeper nodes are not indented any further.
+    pub deeper: &'static str,
+}
+
+impl TreeGlyphs {
+    /// Glyphs drawn with Unicode box-drawing characters, like `├── `, `└── ` and `│   `.
+    pub const UNICODE: Self = Self {
+        branch: "├── ",
+        last: "└── ",
+        pipe: "│   ",
+        space: "    ",
+        deeper: "…   ",
+    };
+    /// Glyphs drawn with ASCII characters, like `|-- `, `` `-- `` and `|   `, for terminals without Unicode support.
+    pub const ASCII: Self = Self {
+        branch: "|-- ",
+        last: "`-- ",
+        pipe: "|   ",
+        space: "    ",
+        deeper: "... ",
+    };
+}
+
+impl Default for TreeGlyphs {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
+
+/// Lets to output some tree structure regarding the propagated value of output alternativeness:
+/// every node on its own line with branches drawn in alternate mode, or in one line like `root [a [b, c], d]` otherwise.
+/// Children of the node are added by the closure, which is called twice: once to count them and once to output them,
+/// so the last child is known beforehand and gets its own glyph.
+#[cfg_attr(docsrs, doc(cfg(feature = "tree")))]
+pub struct TreeShow<'a, 'b> {
+    formatter: &'a mut Formatter<'b>,
+    pretty: bool,
+    glyphs: TreeGlyphs,
+    painter: Painter,
+    // Depth of the nodes being added now, top-level nodes have zero depth.
+    depth: usize,
+    // Bit flags telling whether the ancestor node at the every depth (starting from 1) is the last one among its siblings.
+    lasts: u128,
+    // Amount of the nodes expected at the current depth, or counted so far while counting.
+    count: usize,
+    // Index of the next node at the current depth.
+    index: usize,
+    counting: bool,
+    result: FmtResult,
+}
+
+impl<'a, 'b> TreeShow<'a, 'b> {
+    /// Creates one [TreeShow] examplar starting its output. Since the tree is not known beforehand,
+    /// it can not be measured, so [Alternate::Auto] is treated like [Alternate::Inherit].
+    pub fn new(formatter: &'a mut Formatter<'b>, alternate: Alternate) -> Self {
+        let pretty = match alternate {
+            Alternate::OneLine => false,
+            Alternate::Pretty => true,
+            Alternate::Inherit | Alternate::Auto { .. } => formatter.alternate(),
+        };
+        Self {
+            formatter,
+            pretty,
+            glyphs: TreeGlyphs::UNICODE,
+            painter: Painter {
+                #[cfg(feature = "color")]
+                theme: None,
+            },
+            depth: 0,
+            lasts: 0,
+            count: 0,
+            index: 0,
+            counting: false,
+            result: Ok(()),
+        }
+    }
+
+    /// Creates one [TreeShow] examplar with [Alternate::Inherit] setting and starts its output.
+    pub fn inherit(formatter: &'a mut Formatter<'b>) -> Self {
+        Self::new(formatter, Alternate::Inherit)
+    }
+
+    /// Makes current [TreeShow] examplar draw branches with the specified glyphs instead of the Unicode ones.
+    pub fn glyphs(&mut self, glyphs: TreeGlyphs) -> &mut Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Makes current [TreeShow] examplar style its labels and branches with the specified theme,
+    /// unless the colored output is not allowed according to [ColorChoice].
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "tree", feature = "color"))))]
+    pub fn theme(&mut self, theme: &'static dyn Theme) -> &mut Self {
+        self.painter.theme = match ColorChoice::enabled() {
+            true => Some(theme),
+            false => None,
+        };
+        self
+    }
+
+    /// Adds one node without children to the tree output.
+    pub fn leaf(&mut self, label: &dyn Display) -> &mut Self {
+        self.node(label, |_| {})
+    }
+
+    /// Adds several nodes without children to the tree output from slice.
+    pub fn leaves(&mut self, labels: &[&dyn Display]) -> &mut Self {
+        self.leaves_from_iter(labels.iter())
+    }
+
+    /// Adds several nodes without children to the tree output from iterator.
+    pub fn leaves_from_iter<T, I>(&mut self, labels: I) -> &mut Self
+    where
+        T: Display,
+        I: Iterator<Item = T>,
+    {
+        labels.for_each(|label| {
+            self.leaf(&label);
+        });
+        self
+    }
+
+    /// Adds one node to the tree output, with its children added by the specified closure.
+    pub fn node<F>(&mut self, label: &dyn Display, children: F) -> &mut Self
+    where
+        F: Fn(&mut Self),
+    {
+        if self.counting {
+            self.count += 1;
+            return self;
+        }
+        if self.result.is_err() {
+            return self;
+        }
+        let last = self.index + 1 == self.count;
+        self.result = self.write_node(label, last);
+        let (count, index, lasts) = (self.count, self.index + 1, self.lasts);
+        self.counting = true;
+        self.count = 0;
+        children(self);
+        self.counting = false;
+        if self.count > 0 {
+            if self.depth > 0 && self.depth <= TRACKED_LEVELS {
+                self.lasts = match last {
+                    true => self.lasts | (1 << (self.depth - 1)),
+                    false => self.lasts & !(1 << (self.depth - 1)),
+                };
+            }
+            self.depth += 1;
+            self.index = 0;
+            self.open();
+            children(self);
+            self.close();
+            self.depth -= 1;
+        }
+        self.count = count;
+        self.index = index;
+        self.lasts = lasts;
+        self
+    }
+
+    /// Finishes the tree output, returning the result.
+    pub fn finish(&mut self) -> FmtResult {
+        self.result
+    }
+
+    /// Returns whether the tree is outputted in prettified format.
+    pub fn alternate(&self) -> bool {
+        self.pretty
+    }
+}
+
+impl<'a, 'b> TreeShow<'a, 'b> {
+    // Outputs the node label preceded with the separator from the previous node or with the branch glyphs.
+    fn write_node(&mut self, label: &dyn Display, last: bool) -> FmtResult {
+        let painter = self.painter;
+        match (self.pretty, self.depth, self.index) {
+            (false, _, 0) => (),
+            (false, _, _) => {
+                painter.write_glyph(self.formatter, ",")?;
+                self.formatter.write_char(' ')?;
+            }
+            (true, 0, 0) => (),
+            (true, _, _) => self.formatter.write_char('\n')?,
+        }
+        if !self.pretty {
+            let mut writer = LabelWriter {
+                inner: self.formatter,
+                painter,
+            };
+            return writer.write_fmt(format_args!("{}", label));
+        }
+        if self.depth > 0 {
+            self.write_prefix(self.depth - 1)?;
+            let glyph = match last {
+                true => self.glyphs.last,
+                false => self.glyphs.branch,
+            };
+            painter.write_glyph(self.formatter, glyph)?;
+        }
+        let continuation = match (self.depth, last) {
+            (0, _) => "",
+            (_, true) => self.glyphs.space,
+            (_, false) => self.glyphs.pipe,
+        };
+        let mut writer = PrefixingWriter {
+            tree: self,
+            continuation,
+        };
+        writer.write_fmt(format_args!("{}", label))
+    }
+
+    // Outputs the branches of the specified amount of ancestor levels, the untracked ones replaced with single marker.
+    fn write_prefix(&mut self, levels: usize) -> FmtResult {
+        for level in 1..=levels.min(TRACKED_LEVELS) {
+            let glyph = match self.lasts & (1 << (level - 1)) != 0 {
+                true => self.glyphs.space,
+                false => self.glyphs.pipe,
+            };
+            self.painter.write_glyph(self.formatter, glyph)?;
+        }
+        match levels > TRACKED_LEVELS {
+            true => self.painter.write_glyph(self.formatter, self.glyphs.deeper),
+            false => Ok(()),
+        }
+    }
+
+    // Starts the one-line output of the node children.
+    fn open(&mut self) {
+        if !self.pretty && self.result.is_ok() {
+            self.result = self
+                .formatter
+                .write_char(' ')
+                .and_then(|_| self.painter.write_glyph(self.formatter, "["));
+        }
+    }
+
+    // Finishes the one-line output of the node children.
+    fn close(&mut self) {
+        if !self.pretty && self.result.is_ok() {
+            self.result = self.painter.write_glyph(self.formatter, "]");
+        }
+    }
+}
+
+// Continues the branches after every line break inside the node label, so multi-line labels stay within the tree.
+struct PrefixingWriter<'a, 'b, 'c> {
+    tree: &'a mut TreeShow<'b, 'c>,
+    continuation: &'static str,
+}
+
+impl<'a, 'b, 'c> Write for PrefixingWriter<'a, 'b, 'c> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for (index, line) in s.split('\n').enumerate() {
+            if index > 0 {
+                self.tree.formatter.write_char('\n')?;
+                self.tree.write_prefix(self.tree.depth.saturating_sub(1))?;
+                let painter = self.tree.painter;
+                painter.write_glyph(self.tree.formatter, self.continuation)?;
+            }
+            self.tree.painter.write_label(self.tree.formatter, line)?;
+        }
+        Ok(())
+    }
+}
+
+// Outputs the node label in one line, styled according to the theme.
+struct LabelWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    painter: Painter,
+}
+
+impl<'a, 'b> Write for LabelWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.painter.write_label(self.inner, s)
+    }
+}
+
+// Styles the labels and branches according to the theme, if any.
+#[derive(Clone, Copy)]
+struct Painter {
+    #[cfg(feature = "color")]
+    theme: Option<&'static dyn Theme>,
+}
+
+impl Painter {
+    fn write_glyph(self, w: &mut dyn Write, s: &str) -> FmtResult {
+        #[cfg(feature = "color")]
+        if let (Some(theme), false) = (self.theme, s.is_empty()) {
+            return theme.paint(w, Element::Bracket, &mut |w| w.write_str(s));
+        }
+        w.write_str(s)
+    }
+
+    fn write_label(self, w: &mut dyn Write, s: &str) -> FmtResult {
+        #[cfg(feature = "color")]
+        if let (Some(theme), false) = (self.theme, s.is_empty()) {
+            return theme.paint(w, Element::Value, &mut |w| w.write_str(s));
+        }
+        w.write_str(s)
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt::Display;
+
+/// Trait used to generalize over triples of displayable types and references onto such triples,
+/// mostly to output measured quantities in 'key: value unit' form.
+/// Trait is not sealed and can be implementd for any other needed type.
+#[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
+pub trait DisplayTriple {
+    /// Type of the left-side variable in corresponding 'left: middle right' construction.
+    type Left: Display;
+    /// Type of the middle variable in corresponding 'left: middle right' construction.
+    type Middle: Display;
+    /// Type of the right-side variable in corresponding 'left: middle right' construction.
+    type Right: Display;
+
+    /// Return a reference onto the left-side variable in corresponding 'left: middle right' construction.
+    fn left(&self) -> &Self::Left;
+
+    /// Return a reference onto the middle variable in corresponding 'left: middle right' construction.
+    fn middle(&self) -> &Self::Middle;
+
+    /// Return a reference onto the right-side variable in corresponding 'left: middle right' construction.
+    fn right(&self) -> &Self::Right;
+}
+
+impl<L: Display, M: Display, R: Display> DisplayTriple for (L, M, R) {
+    type Left = L;
+    type Middle = M;
+    type Right = R;
+
+    fn left(&self) -> &Self::Left {
+        &self.0
+    }
+
+    fn middle(&self) -> &Self::Middle {
+        &self.1
+    }
+
+    fn right(&self) -> &Self::Right {
+        &self.2
+    }
+}
+
+impl<L: Display, M: Display, R: Display> DisplayTriple for &(L, M, R) {
+    type Left = L;
+    type Middle = M;
+    type Right = R;
+
+    fn left(&self) -> &Self::Left {
+        &self.0
+    }
+
+    fn middle(&self) -> &Self::Middle {
+        &self.1
+    }
+
+    fn right(&self) -> &Self::Right {
+        &self.2
+    }
+}
@@ -0,0 +1,69 @@
+//! Types coming from third-party crates often implement [Debug] only, so they can not be passed
+//! to the builders directly. This module contains [ViaDebug] wrapper outputting such values
+//! in display mode with their debug output, and the inverse [ViaDisplay] wrapper outputting values
+//! formatted by cubob in debug mode, where some API demands [Debug] implementation.
+//!
+//! Usage example:
+//! ```
+//! use cubob::{display_struct, ViaDebug, ViaDisplay};
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//!
+//! #[derive(Debug)]
+//! enum Mode {
+//!     Read,
+//!     Write,
+//! }
+//!
+//! struct Access {
+//!     path: &'static str,
+//!     mode: Mode,
+//! }
+//!
+//! impl Display for Access {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         display_struct(f, &[(&"path", &self.path), (&"mode", &ViaDebug(&self.mode))])
+//!     }
+//! }
+//!
+//! let access = Access { path: "/tmp", mode: Mode::Read };
+//! assert_eq!("{path: /tmp, mode: Read}", &format!("{}", access));
+//! assert_eq!("{path: /tmp, mode: Read}", &format!("{:?}", ViaDisplay(&access)));
+//! ```
+
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+/// Lets to output value in display mode as it is outputted in debug mode. Alternate mode,
+/// as well as width, fill, alignment and precision, are propagated to the debug output,
+/// so `{:#}` results in `{:#?}` output of the wrapped value.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ViaDebug<T: ?Sized>(pub T);
+
+impl<T: Debug + ?Sized> Display for ViaDebug<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T: Debug + ?Sized> Debug for ViaDebug<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+/// Lets to output value in debug mode as it is outputted in display mode. Alternate mode,
+/// as well as width, fill, alignment and precision, are propagated to the display output,
+/// so `{:#?}` results in `{:#}` output of the wrapped value.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ViaDisplay<T: ?Sized>(pub T);
+
+impl<T: Display + ?Sized> Debug for ViaDisplay<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: Display + ?Sized> Display for ViaDisplay<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
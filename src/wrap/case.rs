@@ -0,0 +1,89 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Wrapper outputting the inner value with all its letters converted to upper case.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Upper<T>(pub T);
+
+/// Wrapper outputting the inner value with all its letters converted to lower case.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lower<T>(pub T);
+
+/// Wrapper outputting the inner value with first letter of every word converted to upper case and others to lower case.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TitleCase<T>(pub T);
+
+#[derive(Clone, Copy)]
+enum Case {
+    Upper,
+    Lower,
+    Title,
+}
+
+fn display_cased(val: &dyn Display, case: Case, f: &mut Formatter<'_>) -> FmtResult {
+    let alternate = f.alternate();
+    let mut writer = CaseWriter {
+        inner: f,
+        case,
+        word_start: true,
+    };
+    match alternate {
+        true => writer.write_fmt(format_args!("{:#}", val)),
+        false => writer.write_fmt(format_args!("{}", val)),
+    }
+}
+
+impl<T: Display> Display for Upper<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        display_cased(&self.0, Case::Upper, f)
+    }
+}
+
+impl<T: Display> Display for Lower<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        display_cased(&self.0, Case::Lower, f)
+    }
+}
+
+impl<T: Display> Display for TitleCase<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        display_cased(&self.0, Case::Title, f)
+    }
+}
+
+struct CaseWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    case: Case,
+    word_start: bool,
+}
+
+impl<'a, 'b> Write for CaseWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            let upper = match self.case {
+                Case::Upper => true,
+                Case::Lower => false,
+                Case::Title => self.word_start,
+            };
+            // Apostrophes are parts of the words they are in, like in `don't`, so they neither end nor start ones.
+            self.word_start = match c {
+                '\'' | '’' => self.word_start,
+                c => !c.is_alphanumeric(),
+            };
+            match upper {
+                true => c
+                    .to_uppercase()
+                    .try_for_each(|c| self.inner.write_char(c))?,
+                false => c
+                    .to_lowercase()
+                    .try_for_each(|c| self.inner.write_char(c))?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// Wrapper outputting the inner value surrounded with the specified opening and closing delimiters,
+/// like `<value>` or `«value»`. Can be combined with any other wrappers.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Delimited<T> {
+    /// Value to output.
+    pub val: T,
+    /// Delimiter to put before the value.
+    pub open: &'static str,
+    /// Delimiter to put after the value.
+    pub close: &'static str,
+}
+
+impl<T> Delimited<T> {
+    /// Creates one [Delimited] examplar using the specified delimiters.
+    pub fn new(val: T, open: &'static str, close: &'static str) -> Self {
+        Self { val, open, close }
+    }
+
+    /// Creates one [Delimited] examplar using parentheses: `(value)`.
+    pub fn parens(val: T) -> Self {
+        Self::new(val, "(", ")")
+    }
+
+    /// Creates one [Delimited] examplar using square brackets: `[value]`.
+    pub fn square(val: T) -> Self {
+        Self::new(val, "[", "]")
+    }
+
+    /// Creates one [Delimited] examplar using angle brackets: `<value>`.
+    pub fn angle(val: T) -> Self {
+        Self::new(val, "<", ">")
+    }
+
+    /// Creates one [Delimited] examplar using guillemets: `«value»`.
+    pub fn guillemets(val: T) -> Self {
+        Self::new(val, "«", "»")
+    }
+}
+
+impl<T: Display> Display for Delimited<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match f.alternate() {
+            true => f.write_fmt(format_args!("{}{:#}{}", self.open, self.val, self.close)),
+            false => f.write_fmt(format_args!("{}{}{}", self.open, self.val, self.close)),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Wrapper outputting the inner value with control characters escaped: `\n`, `\r`, `\t` and `\0`
+/// are replaced with their usual escape sequences, and other ones are outputted like `\u{1b}`.
+/// Backslashes are escaped too, so the escaped output never matches the one of different value.
+/// Lets to keep multi-line values from corrupting one-line records.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Escaped<T>(pub T);
+
+impl<T: Display> Display for Escaped<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let mut writer = EscapingWriter { inner: f };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", self.0)),
+            false => writer.write_fmt(format_args!("{}", self.0)),
+        }
+    }
+}
+
+struct EscapingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+}
+
+impl<'a, 'b> Write for EscapingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let mut rest = s;
+        while let Some(index) = rest.find(|c: char| c == '\\' || c.is_control()) {
+            self.inner.write_str(&rest[..index])?;
+            let c = rest[index..].chars().next().unwrap_or_default();
+            match c {
+                '\\' => self.inner.write_str("\\\\")?,
+                '\n' => self.inner.write_str("\\n")?,
+                '\r' => self.inner.write_str("\\r")?,
+                '\t' => self.inner.write_str("\\t")?,
+                '\0' => self.inner.write_str("\\0")?,
+                _ => self
+                    .inner
+                    .write_fmt(format_args!("{}", c.escape_unicode()))?,
+            }
+            rest = &rest[index + c.len_utf8()..];
+        }
+        self.inner.write_str(rest)
+    }
+}
+
+/// Wrapper outputting the inner value as a valid JSON string literal: the output is put in double quotes,
+/// quotes and backslashes inside it are escaped, and so are the control characters (using `\uXXXX` form
+/// when there is no short escape sequence for them).
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonEscaped<T>(pub T);
+
+impl<T: Display> Display for JsonEscaped<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        f.write_char('"')?;
+        let mut writer = JsonEscapingWriter { inner: f };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", self.0))?,
+            false => writer.write_fmt(format_args!("{}", self.0))?,
+        }
+        writer.inner.write_char('"')
+    }
+}
+
+struct JsonEscapingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+}
+
+impl<'a, 'b> Write for JsonEscapingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let mut rest = s;
+        while let Some(index) = rest.find(|c: char| c == '"' || c == '\\' || c < ' ') {
+            self.inner.write_str(&rest[..index])?;
+            let c = rest[index..].chars().next().unwrap_or_default();
+            match c {
+                '"' => self.inner.write_str("\\\"")?,
+                '\\' => self.inner.write_str("\\\\")?,
+                '\n' => self.inner.write_str("\\n")?,
+                '\r' => self.inner.write_str("\\r")?,
+                '\t' => self.inner.write_str("\\t")?,
+                '\u{8}' => self.inner.write_str("\\b")?,
+                '\u{c}' => self.inner.write_str("\\f")?,
+                _ => self.inner.write_fmt(format_args!("\\u{:04x}", c as u32))?,
+            }
+            rest = &rest[index + c.len_utf8()..];
+        }
+        self.inner.write_str(rest)
+    }
+}
@@ -0,0 +1,46 @@
+//! Values outputted through [StructShow][crate::StructShow] or [ListShow][crate::ListShow] are passed as `&dyn Display`,
+//! so there is no way to apply format specifiers to them, and any post-processing of their output requires
+//! some additional helpers. This module contains such helpers: wrappers which implement [Display][core::fmt::Display]
+//! by transforming the output of the inner value on the fly, without any intermediate allocations.
+//!
+//! Usage example:
+//! ```
+//! use core::fmt::{Display, Formatter, Result as FmtResult};
+//! use cubob::{StructShow, Truncated};
+//!
+//! struct Message {
+//!     id: u64,
+//!     text: String,
+//! }
+//!
+//! impl Display for Message {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+//!         let mut show = StructShow::inherit(f);
+//!         show
+//!             .field(&"id", &self.id)
+//!             .field(&"text", &Truncated(&self.text, 12));
+//!         show.finish()
+//!     }
+//! }
+//!
+//! let message = Message { id: 1, text: "Some rather long message text".into() };
+//! assert_eq!("{id: 1, text: Some rather…}", &format!("{}", message));
+//! ```
+
+mod case;
+mod delimited;
+mod escape;
+mod padded;
+mod quoted;
+mod radix;
+mod repeated;
+mod truncated;
+
+pub use case::*;
+pub use delimited::*;
+pub use escape::*;
+pub use padded::*;
+pub use quoted::*;
+pub use radix::*;
+pub use repeated::*;
+pub use truncated::*;
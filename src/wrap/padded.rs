@@ -0,0 +1,76 @@
+use crate::{measure::measure, Align};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Wrapper padding the output of the inner value up to the specified width (measured in characters, not bytes)
+/// with the specified alignment and fill character. Output longer than the width is left as is.
+/// Needed since the width specifiers are lost once values are passed into builders as `&dyn Display`.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Padded<T> {
+    /// Value to output.
+    pub val: T,
+    /// Minimal width of the output.
+    pub width: usize,
+    /// Alignment of the value within the output.
+    pub align: Align,
+    /// Character used to fill the lacking width.
+    pub fill: char,
+}
+
+impl<T> Padded<T> {
+    /// Creates one [Padded] examplar with the specified alignment, filling with spaces.
+    pub fn new(val: T, width: usize, align: Align) -> Self {
+        Self {
+            val,
+            width,
+            align,
+            fill: ' ',
+        }
+    }
+
+    /// Creates one [Padded] examplar aligned to the left, filling with spaces.
+    pub fn left(val: T, width: usize) -> Self {
+        Self::new(val, width, Align::Left)
+    }
+
+    /// Creates one [Padded] examplar aligned to the right, filling with spaces.
+    pub fn right(val: T, width: usize) -> Self {
+        Self::new(val, width, Align::Right)
+    }
+
+    /// Creates one [Padded] examplar aligned to the center, filling with spaces.
+    pub fn center(val: T, width: usize) -> Self {
+        Self::new(val, width, Align::Center)
+    }
+
+    /// Makes current [Padded] examplar fill the lacking width with the specified character.
+    pub fn fill(self, fill: char) -> Self {
+        Self { fill, ..self }
+    }
+}
+
+impl<T: Display> Display for Padded<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let lack = self.width.saturating_sub(measure(&self.val, alternate));
+        let (before, after) = match self.align {
+            Align::Left => (0, lack),
+            Align::Right => (lack, 0),
+            Align::Center => (lack / 2, lack - lack / 2),
+        };
+        for _ in 0..before {
+            f.write_char(self.fill)?;
+        }
+        match alternate {
+            true => f.write_fmt(format_args!("{:#}", self.val))?,
+            false => f.write_fmt(format_args!("{}", self.val))?,
+        }
+        for _ in 0..after {
+            f.write_char(self.fill)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Quote characters which can be used by [Quoted] wrapper.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quote {
+    /// Single quote: `'`.
+    Single,
+    /// Double quote: `"`.
+    Double,
+    /// Backtick: `` ` ``.
+    Backtick,
+}
+
+impl Quote {
+    /// Returns the related quote character.
+    pub fn char(self) -> char {
+        match self {
+            Quote::Single => '\'',
+            Quote::Double => '"',
+            Quote::Backtick => '`',
+        }
+    }
+}
+
+/// Wrapper outputting the inner value in quotes of the specified kind; the same quote characters and backslashes
+/// inside the output are escaped with backslash, so the quoted value stays delimited and unambiguous.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quoted<T> {
+    /// Value to output.
+    pub val: T,
+    /// Kind of quotes to use.
+    pub quote: Quote,
+}
+
+impl<T> Quoted<T> {
+    /// Creates one [Quoted] examplar using the specified quotes.
+    pub fn new(val: T, quote: Quote) -> Self {
+        Self { val, quote }
+    }
+
+    /// Creates one [Quoted] examplar using single quotes.
+    pub fn single(val: T) -> Self {
+        Self::new(val, Quote::Single)
+    }
+
+    /// Creates one [Quoted] examplar using double quotes.
+    pub fn double(val: T) -> Self {
+        Self::new(val, Quote::Double)
+    }
+
+    /// Creates one [Quoted] examplar using backticks.
+    pub fn backtick(val: T) -> Self {
+        Self::new(val, Quote::Backtick)
+    }
+}
+
+impl<T: Display> Display for Quoted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let quote = self.quote.char();
+        f.write_char(quote)?;
+        let mut writer = QuoteEscapingWriter { inner: f, quote };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", self.val))?,
+            false => writer.write_fmt(format_args!("{}", self.val))?,
+        }
+        writer.inner.write_char(quote)
+    }
+}
+
+struct QuoteEscapingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    quote: char,
+}
+
+impl<'a, 'b> Write for QuoteEscapingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let mut rest = s;
+        while let Some(index) = rest.find([self.quote, '\\']) {
+            self.inner.write_str(&rest[..index])?;
+            self.inner.write_char('\\')?;
+            // Both escaped characters are one byte long.
+            self.inner.write_str(&rest[index..=index])?;
+            rest = &rest[index + 1..];
+        }
+        self.inner.write_str(rest)
+    }
+}
@@ -0,0 +1,147 @@
+use core::{
+    fmt::{Binary, Display, Formatter, LowerHex, Octal, Result as FmtResult, UpperHex},
+    format_args,
+};
+
+/// Wrapper outputting the inner integer in hexadecimal form, optionally with `0x` prefix and zero-padding.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hex<T> {
+    /// Integer to output.
+    pub val: T,
+    /// Whether `0x` prefix should be outputted.
+    pub prefix: bool,
+    /// Minimal amount of digits (not counting prefix), lacking ones are filled with zeros.
+    pub width: usize,
+    /// Whether upper case digits should be used.
+    pub upper: bool,
+}
+
+impl<T> Hex<T> {
+    /// Creates one [Hex] examplar without prefix and padding, using lower case digits.
+    pub fn new(val: T) -> Self {
+        Self {
+            val,
+            prefix: false,
+            width: 0,
+            upper: false,
+        }
+    }
+
+    /// Makes current [Hex] examplar output `0x` prefix.
+    pub fn prefixed(self) -> Self {
+        Self {
+            prefix: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Hex] examplar pad its output with zeros up to the specified amount of digits.
+    pub fn width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Makes current [Hex] examplar use upper case digits.
+    pub fn upper(self) -> Self {
+        Self {
+            upper: true,
+            ..self
+        }
+    }
+}
+
+impl<T: LowerHex + UpperHex> Display for Hex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let prefix = if self.prefix { "0x" } else { "" };
+        match self.upper {
+            true => f.write_fmt(format_args!("{}{:02$X}", prefix, self.val, self.width)),
+            false => f.write_fmt(format_args!("{}{:02$x}", prefix, self.val, self.width)),
+        }
+    }
+}
+
+/// Wrapper outputting the inner integer in binary form, optionally with `0b` prefix and zero-padding.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bin<T> {
+    /// Integer to output.
+    pub val: T,
+    /// Whether `0b` prefix should be outputted.
+    pub prefix: bool,
+    /// Minimal amount of digits (not counting prefix), lacking ones are filled with zeros.
+    pub width: usize,
+}
+
+impl<T> Bin<T> {
+    /// Creates one [Bin] examplar without prefix and padding.
+    pub fn new(val: T) -> Self {
+        Self {
+            val,
+            prefix: false,
+            width: 0,
+        }
+    }
+
+    /// Makes current [Bin] examplar output `0b` prefix.
+    pub fn prefixed(self) -> Self {
+        Self {
+            prefix: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Bin] examplar pad its output with zeros up to the specified amount of digits.
+    pub fn width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+}
+
+impl<T: Binary> Display for Bin<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let prefix = if self.prefix { "0b" } else { "" };
+        f.write_fmt(format_args!("{}{:02$b}", prefix, self.val, self.width))
+    }
+}
+
+/// Wrapper outputting the inner integer in octal form, optionally with `0o` prefix and zero-padding.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Oct<T> {
+    /// Integer to output.
+    pub val: T,
+    /// Whether `0o` prefix should be outputted.
+    pub prefix: bool,
+    /// Minimal amount of digits (not counting prefix), lacking ones are filled with zeros.
+    pub width: usize,
+}
+
+impl<T> Oct<T> {
+    /// Creates one [Oct] examplar without prefix and padding.
+    pub fn new(val: T) -> Self {
+        Self {
+            val,
+            prefix: false,
+            width: 0,
+        }
+    }
+
+    /// Makes current [Oct] examplar output `0o` prefix.
+    pub fn prefixed(self) -> Self {
+        Self {
+            prefix: true,
+            ..self
+        }
+    }
+
+    /// Makes current [Oct] examplar pad its output with zeros up to the specified amount of digits.
+    pub fn width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+}
+
+impl<T: Octal> Display for Oct<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let prefix = if self.prefix { "0o" } else { "" };
+        f.write_fmt(format_args!("{}{:02$o}", prefix, self.val, self.width))
+    }
+}
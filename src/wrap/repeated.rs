@@ -0,0 +1,49 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    format_args,
+};
+
+/// Wrapper outputting the inner value the specified amount of times, optionally separated,
+/// without any intermediate allocations. Useful for rules, padding runs and so on.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Repeated<T> {
+    /// Value to output.
+    pub val: T,
+    /// Amount of times to output the value.
+    pub count: usize,
+    /// Separator to put between repetitions.
+    pub separator: &'static str,
+}
+
+impl<T> Repeated<T> {
+    /// Creates one [Repeated] examplar outputting the value the specified amount of times with no separator.
+    pub fn new(val: T, count: usize) -> Self {
+        Self::separated(val, count, "")
+    }
+
+    /// Creates one [Repeated] examplar outputting the value the specified amount of times with the specified separator.
+    pub fn separated(val: T, count: usize, separator: &'static str) -> Self {
+        Self {
+            val,
+            count,
+            separator,
+        }
+    }
+}
+
+impl<T: Display> Display for Repeated<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        for index in 0..self.count {
+            if index > 0 {
+                f.write_str(self.separator)?;
+            }
+            match alternate {
+                true => f.write_fmt(format_args!("{:#}", self.val))?,
+                false => f.write_fmt(format_args!("{}", self.val))?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::measure::{char_width, measure};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    format_args,
+};
+
+/// Ellipsis character used to mark truncated output.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+pub const ELLIPSIS: char = '…';
+
+/// Wrapper limiting the output of the inner value to the specified amount of characters (not bytes),
+/// or terminal cells with `unicode` feature enabled. If the output is longer, it is cut and ended with [ELLIPSIS], so the whole output still fits the limit.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Truncated<T>(pub T, pub usize);
+
+impl<T: Display> Display for Truncated<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let mut writer = TruncatingWriter {
+            inner: f,
+            left: self.1,
+            pending: None,
+            cut: false,
+        };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", self.0))?,
+            false => writer.write_fmt(format_args!("{}", self.0))?,
+        }
+        match writer.pending {
+            Some(c) if !writer.cut => writer.inner.write_char(c),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Passes characters through until the limit is reached; the last allowed character is kept pending
+// since it has to be replaced with ellipsis if any other character follows.
+struct TruncatingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    left: usize,
+    pending: Option<char>,
+    cut: bool,
+}
+
+impl<'a, 'b> Write for TruncatingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            if self.cut {
+                break;
+            }
+            let width = char_width(c);
+            match (self.pending, width.cmp(&self.left)) {
+                (Some(_), _) | (None, Ordering::Greater) => {
+                    self.cut = true;
+                    // Ellipsis takes the place of the pending character or of the rest which is too narrow.
+                    if self.pending.is_some() || self.left > 0 {
+                        self.inner.write_char(ELLIPSIS)?;
+                    }
+                }
+                (None, Ordering::Equal) => {
+                    self.left = 0;
+                    self.pending = Some(c);
+                }
+                (None, Ordering::Less) => {
+                    self.left -= width;
+                    self.inner.write_char(c)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Wrapper limiting the output of the inner value to the specified amount of characters (not bytes),
+/// or terminal cells with `unicode` feature enabled, by cutting out its middle part, so both ends of long identifiers (hashes, paths, etc.) are preserved.
+/// If the output is longer than the limit, the cut part is replaced with [ELLIPSIS], like `abcde…vwxyz`.
+#[cfg_attr(docsrs, doc(cfg(feature = "wrap")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TruncateMiddle<T>(pub T, pub usize);
+
+impl<T: Display> Display for TruncateMiddle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let alternate = f.alternate();
+        let total = measure(&self.0, alternate);
+        let (cut_start, cut_end) = match (total > self.1, self.1.checked_sub(1)) {
+            (false, _) => (total, total),
+            (true, None) => (0, total),
+            (true, Some(kept)) => (kept.div_ceil(2), total - kept / 2),
+        };
+        let mut writer = CuttingWriter {
+            inner: f,
+            index: 0,
+            cut_start,
+            cut_end,
+            marked: self.1 == 0,
+        };
+        match alternate {
+            true => writer.write_fmt(format_args!("{:#}", self.0)),
+            false => writer.write_fmt(format_args!("{}", self.0)),
+        }
+    }
+}
+
+// Passes through all the characters except ones in the cut range, which is replaced with ellipsis.
+struct CuttingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    index: usize,
+    cut_start: usize,
+    cut_end: usize,
+    marked: bool,
+}
+
+impl<'a, 'b> Write for CuttingWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            if !(self.cut_start..self.cut_end).contains(&self.index) {
+                self.inner.write_char(c)?;
+            } else if !self.marked {
+                self.marked = true;
+                self.inner.write_char(ELLIPSIS)?;
+            }
+            self.index += char_width(c);
+        }
+        Ok(())
+    }
+}